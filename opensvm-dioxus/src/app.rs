@@ -2,11 +2,16 @@ use dioxus::prelude::*;
 use dioxus_router::prelude::*;
 
 use crate::routes::{
-    account::AccountPage, ai::AIPage, explorer::ExplorerPage, not_found::NotFoundPage,
-    solanow::SolanowPage, transaction::TransactionPage, validators::ValidatorsPage,
+    account::AccountPage, ai::AIPage, block::BlockPage, compare::ComparePage, epoch::EpochPage,
+    explorer::ExplorerPage, not_found::NotFoundPage, solanow::SolanowPage,
+    transaction::TransactionPage, validator::ValidatorPage, validators::ValidatorsPage,
     wallet::WalletPage,
 };
+use crate::components::cluster_switcher::ClusterSwitcher;
+use crate::stores::cluster_store::use_cluster_store_provider;
+use crate::stores::idl_store::use_idl_store_provider;
 use crate::stores::theme_store::{use_theme_store, get_current_theme, Theme};
+use crate::utils::pubsub::use_pubsub_provider;
 
 #[cfg(feature = "web")]
 use web_sys::{window, MediaQueryList};
@@ -14,13 +19,16 @@ use web_sys::{window, MediaQueryList};
 // Define the routes for our app
 #[derive(Routable, Clone)]
 #[rustfmt::skip]
-enum Route {
+pub enum Route {
     #[route("/")]
     Home {},
     
     #[route("/validators")]
     Validators {},
     
+    #[route("/validators/compare/:vote_pubkeys")]
+    Compare { vote_pubkeys: String },
+    
     #[route("/solanow")]
     Solanow {},
     
@@ -36,6 +44,15 @@ enum Route {
     #[route("/account/:address")]
     Account { address: String },
     
+    #[route("/block/:slot")]
+    Block { slot: u64 },
+    
+    #[route("/epoch/:epoch")]
+    Epoch { epoch: u64 },
+    
+    #[route("/validator/:vote_pubkey")]
+    Validator { vote_pubkey: String },
+    
     #[route("/:..route")]
     NotFound { route: Vec<String> },
 }
@@ -51,6 +68,15 @@ fn Validators(cx: Scope) -> Element {
     cx.render(rsx! { ValidatorsPage {} })
 }
 
+#[component]
+fn Compare(cx: Scope, vote_pubkeys: String) -> Element {
+    cx.render(rsx! {
+        ComparePage {
+            vote_pubkeys: vote_pubkeys.clone()
+        }
+    })
+}
+
 #[component]
 fn Solanow(cx: Scope) -> Element {
     cx.render(rsx! { SolanowPage {} })
@@ -84,6 +110,33 @@ fn Account(cx: Scope, address: String) -> Element {
     })
 }
 
+#[component]
+fn Block(cx: Scope, slot: u64) -> Element {
+    cx.render(rsx! {
+        BlockPage {
+            slot: *slot
+        }
+    })
+}
+
+#[component]
+fn Epoch(cx: Scope, epoch: u64) -> Element {
+    cx.render(rsx! {
+        EpochPage {
+            epoch: *epoch
+        }
+    })
+}
+
+#[component]
+fn Validator(cx: Scope, vote_pubkey: String) -> Element {
+    cx.render(rsx! {
+        ValidatorPage {
+            vote_pubkey: vote_pubkey.clone()
+        }
+    })
+}
+
 #[component]
 fn NotFound(cx: Scope, #[allow(unused_variables)] route: Vec<String>) -> Element {
     cx.render(rsx! { NotFoundPage {} })
@@ -93,6 +146,9 @@ fn NotFound(cx: Scope, #[allow(unused_variables)] route: Vec<String>) -> Element
 pub fn App(cx: Scope) -> Element {
     let theme_store = use_theme_store(cx);
     let current_theme = get_current_theme(theme_store);
+    use_cluster_store_provider(cx);
+    use_idl_store_provider(cx);
+    use_pubsub_provider(cx);
     
     // Apply theme to document body
     use_effect(cx, (&current_theme,), |(theme,)| {
@@ -124,6 +180,7 @@ pub fn App(cx: Scope) -> Element {
     
     cx.render(rsx! {
         style { include_str!("./assets/styles.css") }
+        ClusterSwitcher {}
         Router::<Route> {}
     })
 }
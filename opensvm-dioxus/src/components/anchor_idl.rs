@@ -0,0 +1,186 @@
+use dioxus::prelude::*;
+use dioxus_router::prelude::*;
+
+use crate::app::Route;
+use crate::components::instruction_view::DecodedFieldView;
+use crate::stores::cluster_store::{get_rpc_urls, use_cluster_store};
+use crate::stores::idl_store::{
+    import_idl, remove_imported_idl, set_on_chain_idl, use_idl_store, IdlSource,
+};
+use crate::utils::anchor::{idl_address, Idl, IdlError};
+use crate::utils::api::SolanaApiClient;
+use crate::utils::decoder::anchor::AnchorDecoder;
+
+// Props for the program IDL panel
+#[derive(Props, PartialEq)]
+pub struct ProgramIdlProps {
+    #[props(into)]
+    pub program_id: String,
+}
+
+// IDL status of one program: looks up the program's on-chain Anchor IDL the
+// first time the program is shown and lets the user import an IDL JSON file
+#[component]
+pub fn ProgramIdl(cx: Scope<ProgramIdlProps>) -> Element {
+    let idl_store = use_idl_store(cx);
+    let cluster_store = use_cluster_store(cx);
+    let cluster = cluster_store.read().cluster.clone();
+    let rpc_urls = get_rpc_urls(cluster_store);
+    let looking_up = use_state(cx, || false);
+    let import_error = use_state(cx, || None::<String>);
+
+    let program_id = &cx.props.program_id;
+    // Until the store catches up with a cluster switch, its on-chain entries
+    // describe the previous cluster
+    let (loaded, missing, on_cluster) = {
+        let state = idl_store.read();
+        (state.get(program_id).cloned(), state.is_missing(program_id), state.is_on_cluster(&cluster))
+    };
+    let resolved = on_cluster && (loaded.is_some() || missing);
+
+    // The IDL is optional, so a failed lookup only leaves instructions raw
+    use_effect(cx, (program_id, &cluster, &rpc_urls, &resolved), |(program_id, cluster, rpc_urls, resolved)| {
+        let idl_store = idl_store.clone();
+        let looking_up = looking_up.clone();
+
+        async move {
+            if resolved {
+                return;
+            }
+            let Some(address) = idl_address(&program_id) else {
+                return;
+            };
+
+            looking_up.set(true);
+            let client = SolanaApiClient::new(rpc_urls);
+            match client.get_account_info(&address).await {
+                Ok(Some(account)) if account.owner == program_id => {
+                    let idl = account
+                        .data_bytes()
+                        .ok_or_else(|| IdlError::new("Unexpected account data encoding"))
+                        .and_then(|data| Idl::from_account_data(&data));
+                    match idl {
+                        Ok(idl) => set_on_chain_idl(&idl_store, &cluster, &program_id, Some(idl)),
+                        Err(e) => {
+                            log::warn!("Ignoring on-chain IDL of {}: {}", program_id, e);
+                            set_on_chain_idl(&idl_store, &cluster, &program_id, None);
+                        }
+                    }
+                }
+                Ok(_) => set_on_chain_idl(&idl_store, &cluster, &program_id, None),
+                Err(e) => log::warn!("Failed to look up the IDL of {}: {}", program_id, e),
+            }
+            looking_up.set(false);
+        }
+    });
+
+    let on_import = move |evt: FormEvent| {
+        let Some(files) = evt.files.clone() else {
+            return;
+        };
+        let idl_store = idl_store.clone();
+        let import_error = import_error.clone();
+        let program_id = cx.props.program_id.clone();
+
+        cx.spawn(async move {
+            let Some(file) = files.files().into_iter().next() else {
+                return;
+            };
+            match files.read_file_to_string(&file).await {
+                Some(json) => match import_idl(&idl_store, &program_id, &json) {
+                    Ok(()) => import_error.set(None),
+                    Err(e) => import_error.set(Some(e.to_string())),
+                },
+                None => import_error.set(Some(format!("Could not read {}", file))),
+            }
+        });
+    };
+
+    let status = match &loaded {
+        Some(loaded) => {
+            let source = match loaded.source {
+                IdlSource::Imported => "imported",
+                IdlSource::OnChain => "from the on-chain IDL account",
+            };
+            format!(
+                "Anchor IDL \"{}\" {}, {} instructions",
+                loaded.idl.name,
+                source,
+                loaded.idl.instructions.len()
+            )
+        }
+        None if *looking_up.get() => "Looking up on-chain IDL…".to_string(),
+        None if missing => "No on-chain IDL found".to_string(),
+        None => "No IDL loaded".to_string(),
+    };
+    let imported = loaded.as_ref().is_some_and(|loaded| loaded.source == IdlSource::Imported);
+
+    cx.render(rsx! {
+        div { class: "program-idl",
+            div { class: "program-idl-header",
+                Link {
+                    class: "address mono",
+                    to: Route::Account { address: program_id.clone() },
+                    "{program_id}"
+                }
+                span { class: "text-secondary small", "{status}" }
+            }
+            div { class: "program-idl-actions",
+                label { class: "idl-import",
+                    "Import IDL JSON"
+                    input { r#type: "file", accept: ".json,application/json", onchange: on_import }
+                }
+                if imported {
+                    rsx! {
+                        button {
+                            class: "idl-remove",
+                            onclick: move |_| remove_imported_idl(idl_store, &cx.props.program_id),
+                            "Remove imported IDL"
+                        }
+                    }
+                }
+            }
+            if let Some(e) = import_error.get() {
+                rsx! { p { class: "text-tertiary small", "Could not import IDL: {e}" } }
+            }
+        }
+    })
+}
+
+// Props for decoded Anchor account data
+#[derive(Props, PartialEq)]
+pub struct AnchorAccountDataProps {
+    /// Program owning the account
+    #[props(into)]
+    pub owner: String,
+    pub data: Vec<u8>,
+}
+
+// Account data decoded with the owner program's IDL, matched by discriminator
+#[component]
+pub fn AnchorAccountData(cx: Scope<AnchorAccountDataProps>) -> Element {
+    let idl_store = use_idl_store(cx);
+    let decoded = idl_store.read().get(&cx.props.owner).map(|loaded| {
+        AnchorDecoder::new(cx.props.owner.clone(), loaded.idl.clone()).decode_account(&cx.props.data)
+    });
+
+    cx.render(rsx! {
+        div { class: "anchor-account",
+            ProgramIdl { program_id: cx.props.owner.clone() }
+            match decoded {
+                Some(Ok(account)) => rsx! {
+                    h3 { "{account.program_name} · {account.name}" }
+                    div { class: "instruction-details",
+                        for (i, field) in account.fields.into_iter().enumerate() {
+                            DecodedFieldView { key: "{i}", field: field }
+                        }
+                    }
+                },
+                Some(Err(e)) => rsx! {
+                    p { class: "text-tertiary small", "Could not decode account data: {e}" }
+                },
+                None => rsx! { div {} },
+            }
+        }
+    })
+}
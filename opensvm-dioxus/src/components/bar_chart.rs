@@ -0,0 +1,59 @@
+use dioxus::prelude::*;
+
+// Props for the bar chart
+#[derive(Props, PartialEq)]
+pub struct BarChartProps {
+    /// Bar heights, left to right
+    pub values: Vec<f64>,
+    /// Label shown under each bar and in its tooltip
+    pub labels: Vec<String>,
+    #[props(default = 160)]
+    pub height: u32,
+    /// Suffix appended to values in tooltips, e.g. " credits"
+    #[props(default = "")]
+    pub unit: &'static str,
+}
+
+// Simple SVG bar chart that stretches to the width of its container
+#[component]
+pub fn BarChart(cx: Scope<BarChartProps>) -> Element {
+    let values = &cx.props.values;
+    if values.is_empty() {
+        return None;
+    }
+
+    let max = values.iter().copied().fold(0.0_f64, f64::max);
+    let max = if max > 0.0 { max } else { 1.0 };
+    let bar_width = 100.0 / values.len() as f64;
+    let height = cx.props.height;
+    let unit = cx.props.unit;
+
+    cx.render(rsx! {
+        div { class: "bar-chart",
+            svg {
+                width: "100%",
+                height: "{height}",
+                view_box: "0 0 100 100",
+                preserve_aspect_ratio: "none",
+                for (i, value) in values.iter().enumerate() {
+                    rect {
+                        key: "{i}",
+                        x: "{i as f64 * bar_width + bar_width * 0.1}",
+                        y: "{100.0 - value / max * 100.0}",
+                        width: "{bar_width * 0.8}",
+                        height: "{value / max * 100.0}",
+                        fill: "var(--primary)",
+                        title {
+                            "{cx.props.labels.get(i).map(String::as_str).unwrap_or(\"\")}: {value:.0}{unit}"
+                        }
+                    }
+                }
+            }
+            div { class: "bar-chart-labels",
+                for (i, label) in cx.props.labels.iter().enumerate() {
+                    span { key: "{i}", "{label}" }
+                }
+            }
+        }
+    })
+}
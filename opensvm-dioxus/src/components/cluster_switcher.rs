@@ -0,0 +1,85 @@
+use dioxus::prelude::*;
+
+use crate::stores::cluster_store::{set_cluster, use_cluster_store, Cluster};
+
+// Cluster switcher component
+#[component]
+pub fn ClusterSwitcher(cx: Scope) -> Element {
+    let cluster_store = use_cluster_store(cx);
+    let state = cluster_store.read().clone();
+    let custom_url = use_state(cx, || state.custom_url.clone());
+    let editing_custom = use_state(cx, || matches!(state.cluster, Cluster::Custom(_)));
+    let url_error = use_state(cx, || Option::<String>::None);
+
+    let selected_id = if **editing_custom {
+        "custom"
+    } else {
+        state.cluster.id()
+    };
+    let endpoint = state.cluster.rpc_url();
+
+    let apply_custom = move |_| {
+        let url = custom_url.get().trim().to_string();
+        if url.starts_with("http://") || url.starts_with("https://") {
+            url_error.set(None);
+            set_cluster(cluster_store, Cluster::Custom(url));
+        } else {
+            url_error.set(Some("RPC URL must start with http:// or https://".to_string()));
+        }
+    };
+
+    cx.render(rsx! {
+        div { class: "cluster-switcher",
+            span { class: "cluster-label", "Cluster" }
+            select {
+                class: "cluster-select",
+                value: "{selected_id}",
+                onchange: move |evt| {
+                    if evt.value == "custom" {
+                        editing_custom.set(true);
+                    } else if let Some(cluster) = Cluster::PRESETS.iter().find(|c| c.id() == evt.value) {
+                        editing_custom.set(false);
+                        url_error.set(None);
+                        set_cluster(cluster_store, cluster.clone());
+                    }
+                },
+                for cluster in Cluster::PRESETS.iter() {
+                    option {
+                        value: "{cluster.id()}",
+                        selected: cluster.id() == selected_id,
+                        "{cluster.label()}"
+                    }
+                }
+                option {
+                    value: "custom",
+                    selected: selected_id == "custom",
+                    "Custom RPC URL"
+                }
+            }
+
+            if **editing_custom {
+                rsx! {
+                    input {
+                        class: "cluster-custom-input mono",
+                        placeholder: "https://my-rpc.example.com",
+                        value: "{custom_url}",
+                        oninput: move |evt| custom_url.set(evt.value.clone()),
+                    }
+                    button {
+                        class: "cluster-apply-button",
+                        onclick: apply_custom,
+                        "Apply"
+                    }
+                }
+            }
+
+            span { class: "cluster-endpoint mono", "{endpoint}" }
+
+            if let Some(err) = url_error.get() {
+                rsx! {
+                    span { class: "cluster-error", "{err}" }
+                }
+            }
+        }
+    })
+}
@@ -0,0 +1,72 @@
+use dioxus::prelude::*;
+use dioxus_router::prelude::*;
+
+use crate::app::Route;
+use crate::stores::cluster_store::{get_rpc_urls, use_cluster_store};
+use crate::utils::address_utils::{format_number, format_timestamp};
+use crate::utils::api::SolanaApiClient;
+use crate::utils::epoch::{format_duration, EpochClock};
+
+// Props for the epoch card
+#[derive(Props, PartialEq)]
+pub struct EpochCardProps {
+    /// Epoch shown until (or unless) the epoch schedule loads
+    pub epoch: u64,
+    /// Latest slot from a slot subscription, to keep progress live
+    #[props(!optional, default)]
+    pub live_slot: Option<u64>,
+}
+
+// Stat card with the current epoch's progress and the estimated time of the
+// next epoch boundary
+#[component]
+pub fn EpochCard(cx: Scope<EpochCardProps>) -> Element {
+    let clock = use_state(cx, || None::<EpochClock>);
+    let rpc_urls = get_rpc_urls(use_cluster_store(cx));
+
+    // Progress is secondary, so a failure only leaves the epoch number
+    use_effect(cx, (&rpc_urls,), |(rpc_urls,)| {
+        let clock = clock.clone();
+
+        async move {
+            let client = SolanaApiClient::new(rpc_urls);
+            match client.get_epoch_overview().await {
+                Ok(overview) => clock.set(Some(EpochClock::new(&overview))),
+                Err(e) => {
+                    log::warn!("Failed to load epoch schedule: {}", e);
+                    clock.set(None);
+                }
+            }
+        }
+    });
+
+    let clock = clock.get().as_ref().map(|clock| match cx.props.live_slot {
+        Some(slot) => clock.at_slot(slot, chrono::Utc::now().timestamp()),
+        None => clock.clone(),
+    });
+    let epoch = clock.as_ref().map(|clock| clock.epoch).unwrap_or(cx.props.epoch);
+
+    cx.render(rsx! {
+        div { class: "stat-card epoch-card",
+            h3 { "Current Epoch" }
+            p { class: "stat-value",
+                Link { to: Route::Epoch { epoch }, "{epoch}" }
+            }
+            if let Some(clock) = &clock {
+                let (elapsed, total) = clock.slot_progress();
+                let percent = clock.progress() * 100.0;
+                let remaining = format_duration(clock.seconds_to_next_boundary());
+                let boundary = format_timestamp(clock.slot_time(clock.next_boundary_slot()).max(0) as u64);
+                rsx! {
+                    div { class: "epoch-progress",
+                        div { class: "epoch-progress-fill", style: "width: {percent:.1}%" }
+                    }
+                    p { class: "text-secondary small",
+                        "{percent:.1}% · slot {format_number(elapsed)} of {format_number(total)}"
+                    }
+                    p { class: "text-tertiary small", title: "{boundary} UTC", "Next epoch in ~{remaining}" }
+                }
+            }
+        }
+    })
+}
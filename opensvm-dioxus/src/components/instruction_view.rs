@@ -0,0 +1,206 @@
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+use dioxus_router::prelude::*;
+
+use crate::app::Route;
+use crate::stores::idl_store::use_idl_store;
+use crate::utils::api::AccountKey;
+use crate::utils::decoder::{DecodeContext, DecodedField, FieldValue, InstructionNode, RawInstruction};
+
+// Props for the instruction view
+#[derive(Props, PartialEq)]
+pub struct InstructionViewProps {
+    /// Heading, e.g. "Instruction #1"
+    #[props(into)]
+    pub title: String,
+    pub instruction: RawInstruction,
+    /// Token balances and other transaction data decoders may use
+    pub context: Rc<DecodeContext>,
+}
+
+// One instruction, decoded into named fields and account roles when a decoder
+// is registered for its program or an IDL is loaded for it, raw otherwise
+#[component]
+pub fn InstructionView(cx: Scope<InstructionViewProps>) -> Element {
+    let instruction = &cx.props.instruction;
+    let registry = use_idl_store(cx).read().registry();
+    let program_name = registry
+        .program_name(&instruction.program_id)
+        .map(str::to_string);
+    let decoded = registry.decode(instruction, &cx.props.context);
+    let data = instruction.data_base58();
+
+    let heading = match &decoded {
+        Some(Ok(decoded)) => format!("{}: {} · {}", cx.props.title, decoded.program_name, decoded.name),
+        _ => match &program_name {
+            Some(name) => format!("{}: {}", cx.props.title, name),
+            None => cx.props.title.clone(),
+        },
+    };
+
+    cx.render(rsx! {
+        div { class: "instruction-item",
+            h3 { "{heading}" }
+            div { class: "instruction-details",
+                div { class: "info-item",
+                    span { class: "label", "Program:" }
+                    Link {
+                        class: "value mono",
+                        to: Route::Account { address: instruction.program_id.clone() },
+                        "{instruction.program_id}"
+                    }
+                }
+                match decoded {
+                    Some(Ok(decoded)) => rsx! {
+                        for (i, field) in decoded.fields.iter().enumerate() {
+                            DecodedFieldView { key: "{i}", field: field.clone() }
+                        }
+                        div { class: "instruction-accounts",
+                            for (i, role) in decoded.accounts.iter().enumerate() {
+                                InstructionAccount { key: "{i}", role: role.role.clone(), account: role.account.clone() }
+                            }
+                        }
+                    },
+                    Some(Err(e)) => rsx! {
+                        p { class: "text-tertiary small", "Could not decode instruction: {e}" }
+                        RawInstructionDetails { instruction: instruction.clone(), data: data.clone() }
+                    },
+                    None => rsx! {
+                        RawInstructionDetails { instruction: instruction.clone(), data: data.clone() }
+                    },
+                }
+            }
+        }
+    })
+}
+
+// Props for an instruction and the instructions it invoked
+#[derive(Props, PartialEq)]
+pub struct InstructionTreeProps {
+    /// Position in the tree, e.g. "1" or "1.2.1"
+    #[props(into)]
+    pub number: String,
+    pub node: InstructionNode,
+    pub context: Rc<DecodeContext>,
+}
+
+// Instruction with its cross-program invocations nested below it; each
+// level can be collapsed
+#[component]
+pub fn InstructionTree(cx: Scope<InstructionTreeProps>) -> Element {
+    let expanded = use_state(cx, || true);
+    let node = &cx.props.node;
+    let number = &cx.props.number;
+    let title = if node.stack_height > 1 {
+        format!("Inner Instruction #{}", number)
+    } else {
+        format!("Instruction #{}", number)
+    };
+    let invoked = match node.iter().count() - 1 {
+        1 => "1 inner instruction".to_string(),
+        count => format!("{} inner instructions", count),
+    };
+    let toggle = if **expanded { "▾" } else { "▸" };
+
+    cx.render(rsx! {
+        div { class: "instruction-node",
+            if node.stack_height > 1 {
+                rsx! { span { class: "cpi-depth", "Stack height {node.stack_height}" } }
+            }
+            InstructionView { title: title, instruction: node.instruction.clone(), context: cx.props.context.clone() }
+            if !node.children.is_empty() {
+                rsx! {
+                    button {
+                        class: "cpi-toggle",
+                        onclick: move |_| expanded.set(!expanded.get()),
+                        "{toggle} {invoked}"
+                    }
+                    if **expanded {
+                        rsx! {
+                            div { class: "cpi-children",
+                                for (i, child) in node.children.iter().enumerate() {
+                                    InstructionTree {
+                                        key: "{i}",
+                                        number: format!("{}.{}", number, i + 1),
+                                        node: child.clone(),
+                                        context: cx.props.context.clone(),
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    })
+}
+
+#[derive(Props, PartialEq)]
+pub struct DecodedFieldViewProps {
+    pub field: DecodedField,
+}
+
+// Labeled value of a decoded instruction or account; addresses link to their account page
+pub fn DecodedFieldView(cx: Scope<DecodedFieldViewProps>) -> Element {
+    let field = &cx.props.field;
+
+    cx.render(rsx! {
+        div { class: "info-item",
+            span { class: "label", "{field.label}:" }
+            match &field.value {
+                FieldValue::Address(address) => rsx! {
+                    Link { class: "value mono", to: Route::Account { address: address.clone() }, "{address}" }
+                },
+                value => rsx! { span { class: "value", "{value}" } },
+            }
+        }
+    })
+}
+
+#[derive(Props, PartialEq)]
+struct InstructionAccountProps {
+    role: String,
+    account: AccountKey,
+}
+
+fn InstructionAccount(cx: Scope<InstructionAccountProps>) -> Element {
+    let account = &cx.props.account;
+
+    cx.render(rsx! {
+        div { class: "account-item",
+            span { class: "instruction-account-role", "{cx.props.role}" }
+            Link {
+                class: "address mono",
+                to: Route::Account { address: account.pubkey.clone() },
+                "{account.pubkey}"
+            }
+            if account.writable {
+                rsx! { span { class: "account-flag", "Writable" } }
+            }
+            if account.signer {
+                rsx! { span { class: "account-flag", "Signer" } }
+            }
+        }
+    })
+}
+
+#[derive(Props, PartialEq)]
+struct RawInstructionDetailsProps {
+    instruction: RawInstruction,
+    data: String,
+}
+
+fn RawInstructionDetails(cx: Scope<RawInstructionDetailsProps>) -> Element {
+    cx.render(rsx! {
+        div { class: "instruction-accounts",
+            for (i, account) in cx.props.instruction.accounts.iter().enumerate() {
+                InstructionAccount { key: "{i}", role: format!("Account #{}", i + 1), account: account.clone() }
+            }
+        }
+        div { class: "info-item",
+            span { class: "label", "Data:" }
+            span { class: "value mono small", "{cx.props.data}" }
+        }
+    })
+}
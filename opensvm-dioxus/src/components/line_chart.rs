@@ -0,0 +1,128 @@
+use dioxus::prelude::*;
+
+/// One line of a [`LineChart`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChartSeries {
+    pub label: String,
+    /// Points spread evenly along the x axis, left to right
+    pub values: Vec<f64>,
+    /// CSS color of the line
+    pub color: &'static str,
+    pub dashed: bool,
+}
+
+// Props for the line chart
+#[derive(Props, PartialEq)]
+pub struct LineChartProps {
+    pub series: Vec<ChartSeries>,
+    /// Labels under the left and right ends of the x axis
+    pub x_start: String,
+    pub x_end: String,
+    #[props(default = 200)]
+    pub height: u32,
+    /// Multiplier applied to values in the y axis labels, e.g. 100 for percent
+    #[props(default = 1.0)]
+    pub y_scale: f64,
+    /// Suffix of the y axis labels, e.g. "%"
+    #[props(default = "")]
+    pub y_unit: &'static str,
+    #[props(default = 2)]
+    pub y_decimals: usize,
+    /// Start the y axis at zero instead of the smallest value
+    #[props(default)]
+    pub from_zero: bool,
+    /// Index of a point to mark with a vertical line
+    #[props(!optional, default)]
+    pub marker: Option<usize>,
+}
+
+// SVG line chart with a legend and min/max axis labels that stretches to the
+// width of its container
+#[component]
+pub fn LineChart(cx: Scope<LineChartProps>) -> Element {
+    let series = &cx.props.series;
+    let points = series.iter().map(|series| series.values.len()).max().unwrap_or(0);
+    if points < 2 {
+        return None;
+    }
+
+    let values = series.iter().flat_map(|series| series.values.iter().copied());
+    let (min, max) = values.fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), value| {
+        (min.min(value), max.max(value))
+    });
+    let min = if cx.props.from_zero { min.min(0.0) } else { min };
+    let range = if max > min { max - min } else { 1.0 };
+    let step = 100.0 / (points - 1) as f64;
+
+    let polyline = |values: &[f64]| {
+        values
+            .iter()
+            .enumerate()
+            .map(|(i, value)| format!("{:.2},{:.2}", i as f64 * step, 100.0 - (value - min) / range * 100.0))
+            .collect::<Vec<_>>()
+            .join(" ")
+    };
+    let lines: Vec<(String, &ChartSeries)> = series.iter().map(|series| (polyline(&series.values), series)).collect();
+
+    let decimals = cx.props.y_decimals;
+    let y_label = |value: f64| format!("{:.*}{}", decimals, value * cx.props.y_scale, cx.props.y_unit);
+    let (y_max, y_min) = (y_label(max), y_label(min));
+    let marker = cx.props.marker.filter(|index| *index < points).map(|index| index as f64 * step);
+
+    cx.render(rsx! {
+        div { class: "line-chart",
+            div { class: "line-chart-legend",
+                for series in series.iter() {
+                    span { key: "{series.label}", class: "line-chart-legend-item",
+                        span {
+                            class: "line-chart-swatch",
+                            style: "background: {series.color}",
+                        }
+                        "{series.label}"
+                    }
+                }
+            }
+            div { class: "line-chart-body",
+                div { class: "line-chart-y-axis",
+                    span { "{y_max}" }
+                    span { "{y_min}" }
+                }
+                svg {
+                    width: "100%",
+                    height: "{cx.props.height}",
+                    view_box: "0 0 100 100",
+                    preserve_aspect_ratio: "none",
+                    if let Some(x) = marker {
+                        rsx! {
+                            line {
+                                x1: "{x}",
+                                x2: "{x}",
+                                y1: "0",
+                                y2: "100",
+                                stroke: "var(--text-tertiary)",
+                                stroke_width: "1",
+                                stroke_dasharray: "3 3",
+                                "vector-effect": "non-scaling-stroke",
+                            }
+                        }
+                    }
+                    for (points, series) in lines.into_iter() {
+                        polyline {
+                            key: "{series.label}",
+                            points: "{points}",
+                            fill: "none",
+                            stroke: "{series.color}",
+                            stroke_width: "2",
+                            stroke_dasharray: if series.dashed { "6 4" } else { "none" },
+                            "vector-effect": "non-scaling-stroke",
+                        }
+                    }
+                }
+            }
+            div { class: "line-chart-x-axis",
+                span { "{cx.props.x_start}" }
+                span { "{cx.props.x_end}" }
+            }
+        }
+    })
+}
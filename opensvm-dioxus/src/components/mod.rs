@@ -1,3 +1,16 @@
 //! UI components for the application
 
 // Re-export components here as needed
+pub mod anchor_idl;
+pub mod bar_chart;
+pub mod cluster_switcher;
+pub mod epoch_card;
+pub mod instruction_view;
+pub mod line_chart;
+pub mod node_distribution;
+pub mod rpc_error_view;
+pub mod search_bar;
+pub mod signature_list;
+pub mod sparkline;
+pub mod validator_analytics;
+pub mod validator_table;
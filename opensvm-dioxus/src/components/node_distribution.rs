@@ -0,0 +1,126 @@
+use dioxus::prelude::*;
+
+use crate::utils::node_distribution::{NodeDistribution, SoftwareShare};
+
+// Props for the node distribution section
+#[derive(Props, PartialEq)]
+pub struct NodeDistributionViewProps {
+    pub distribution: NodeDistribution,
+}
+
+// Stake share per software version and feature set, and the node role split
+#[component]
+pub fn NodeDistributionView(cx: Scope<NodeDistributionViewProps>) -> Element {
+    let distribution = &cx.props.distribution;
+    let total_nodes = distribution.total_nodes().max(1) as f64;
+    let roles = [
+        ("Voting", distribution.voting_nodes, "voting"),
+        ("RPC", distribution.rpc_nodes, "rpc"),
+        ("Other", distribution.other_nodes, "other"),
+    ];
+    let unseen_stake = distribution.unseen_stake / 1_000_000_000;
+
+    cx.render(rsx! {
+        div { class: "section node-distribution",
+            h2 { "Node Distribution" }
+            p { class: "text-secondary small",
+                "{distribution.total_nodes()} nodes in gossip, weighted by the active stake of their vote accounts."
+            }
+
+            div { class: "role-bar",
+                for (label, count, class) in roles {
+                    if count > 0 {
+                        rsx! {
+                            div {
+                                key: "{label}",
+                                class: "role-bar-segment {class}",
+                                style: "width: {count as f64 / total_nodes * 100.0}%",
+                            }
+                        }
+                    }
+                }
+            }
+            div { class: "role-legend",
+                for (label, count, class) in roles {
+                    span { key: "{label}", class: "role-legend-item",
+                        span { class: "role-swatch {class}" }
+                        "{label}: {count} ({count as f64 / total_nodes * 100.0:.1}%)"
+                    }
+                }
+            }
+
+            if unseen_stake > 0 {
+                rsx! {
+                    p { class: "text-secondary small",
+                        "{unseen_stake} SOL of stake belongs to validators not visible in gossip and is left out of the shares below."
+                    }
+                }
+            }
+
+            div { class: "node-distribution-tables",
+                SoftwareShareTable {
+                    title: "Software Versions",
+                    cumulative_label: "At or Newer (same client)",
+                    shares: distribution.by_version.clone(),
+                }
+                SoftwareShareTable {
+                    title: "Feature Sets",
+                    cumulative_label: "Cumulative",
+                    shares: distribution.by_feature_set.clone(),
+                }
+            }
+        }
+    })
+}
+
+#[derive(Props, PartialEq)]
+struct SoftwareShareTableProps {
+    title: &'static str,
+    cumulative_label: &'static str,
+    shares: Vec<SoftwareShare>,
+}
+
+fn SoftwareShareTable(cx: Scope<SoftwareShareTableProps>) -> Element {
+    cx.render(rsx! {
+        div { class: "software-share",
+            h3 { "{cx.props.title}" }
+            table { class: "validator-table",
+                thead {
+                    tr {
+                        th { "Label" }
+                        th { "Nodes" }
+                        th { "Stake" }
+                        th { "Share" }
+                        th { "{cx.props.cumulative_label}" }
+                    }
+                }
+                tbody {
+                    for share in cx.props.shares.iter() {
+                        tr { key: "{share.label}",
+                            td { class: "mono",
+                                "{share.label}"
+                                if let Some(client) = share.client {
+                                    rsx! { span { class: "text-tertiary small", " {client.name()}" } }
+                                }
+                            }
+                            td { class: "numeric", "{share.nodes}" }
+                            td { class: "numeric", "{share.stake / 1_000_000_000} SOL" }
+                            td {
+                                div { class: "share-cell",
+                                    div { class: "share-bar",
+                                        div {
+                                            class: "share-bar-fill",
+                                            style: "width: {share.stake_share * 100.0}%",
+                                        }
+                                    }
+                                    span { class: "numeric", "{share.stake_share * 100.0:.2}%" }
+                                }
+                            }
+                            td { class: "numeric", "{share.cumulative_share * 100.0:.2}%" }
+                        }
+                    }
+                }
+            }
+        }
+    })
+}
@@ -0,0 +1,50 @@
+use dioxus::prelude::*;
+
+use crate::utils::api::RpcError;
+
+// Props for the RPC error view
+#[derive(Props)]
+pub struct RpcErrorViewProps<'a> {
+    pub error: &'a RpcError,
+    /// Shown above the message, e.g. "Error Loading Account"
+    #[props(optional)]
+    pub context: Option<&'a str>,
+    /// Renders a retry button when the error is retryable
+    #[props(optional)]
+    pub on_retry: Option<EventHandler<'a, ()>>,
+}
+
+// Error card that explains an RPC failure and how to recover from it
+#[component]
+pub fn RpcErrorView<'a>(cx: Scope<'a, RpcErrorViewProps<'a>>) -> Element<'a> {
+    let error = cx.props.error;
+    let heading = cx.props.context.unwrap_or(error.title());
+    let hint = error.retry_hint();
+    let show_retry = cx.props.on_retry.is_some() && error.is_retryable();
+
+    cx.render(rsx! {
+        div { class: "rpc-error",
+            h3 { "{heading}" }
+            if cx.props.context.is_some() {
+                rsx! {
+                    p { class: "rpc-error-kind", "{error.title()}" }
+                }
+            }
+            p { class: "rpc-error-message", "{error}" }
+            p { class: "rpc-error-hint", "{hint}" }
+            if show_retry {
+                rsx! {
+                    button {
+                        class: "retry-button",
+                        onclick: move |_| {
+                            if let Some(on_retry) = &cx.props.on_retry {
+                                on_retry.call(());
+                            }
+                        },
+                        "🔄 Retry"
+                    }
+                }
+            }
+        }
+    })
+}
@@ -1,10 +1,10 @@
 use dioxus::prelude::*;
+use dioxus::html::input_data::keyboard_types::Key;
 use dioxus_router::prelude::*;
-use dioxus_free_icons::icons::lucide_icons::{Search, X, Loader};
+use dioxus_free_icons::icons::fa_solid_icons::{FaMagnifyingGlass, FaXmark};
 use dioxus_free_icons::Icon;
-use wasm_bindgen_futures::spawn_local;
 
-use crate::utils::address_utils::parse_search_input;
+use crate::utils::address_utils::{parse_search_input, SearchInputType};
 use crate::app::Route;
 
 #[derive(Props)]
@@ -16,144 +16,119 @@ pub struct SearchBarProps<'a> {
 }
 
 #[component]
-pub fn SearchBar<'a>(cx: Scope<'a, SearchBarProps<'a>>) -> Element {
+pub fn SearchBar<'a>(cx: Scope<'a, SearchBarProps<'a>>) -> Element<'a> {
     let query = use_state(cx, String::new);
-    let is_searching = use_state(cx, || false);
     let is_focused = use_state(cx, || false);
+    let invalid = use_state(cx, || false);
     let navigator = use_navigator(cx);
-    
+
     let placeholder = cx.props.placeholder.unwrap_or("Search transactions, accounts, blocks...");
-    
-    let handle_submit = move |_| {
+
+    let submit = move || {
         let query_value = query.get().clone();
         if query_value.trim().is_empty() {
             return;
         }
-        
-        is_searching.set(true);
-        
+
         // Parse the search query to determine what type of data it is
         let search_result = parse_search_input(&query_value);
-        
+
         // If on_search prop is provided, call it
         if let Some(on_search) = &cx.props.on_search {
             on_search.call(query_value.clone());
         }
-        
-        // Clone values for the async block
-        let navigator = navigator.clone();
-        let is_searching = is_searching.clone();
-        let search_result = search_result.clone();
-        
-        // Simulate search delay
-        spawn_local(async move {
-            // Simulate network delay
-            gloo::timers::future::TimeoutFuture::new(1000).await;
-            
-            // Navigate based on the type of input
-            if search_result.is_valid {
-                match search_result.type_ {
-                    crate::utils::address_utils::SearchInputType::Transaction => {
-                        navigator.push(Route::Transaction { id: search_result.value });
-                    },
-                    crate::utils::address_utils::SearchInputType::Account => {
-                        navigator.push(Route::Account { id: search_result.value });
-                    },
-                    crate::utils::address_utils::SearchInputType::Block => {
-                        log::info!("Searching for block: {}", search_result.value);
-                        // Not implemented yet
-                    },
-                    _ => {
-                        log::info!("General search for: {}", search_result.value);
+
+        // Navigate based on the type of input
+        invalid.set(!search_result.is_valid);
+        if search_result.is_valid {
+            match search_result.type_ {
+                SearchInputType::Transaction => {
+                    navigator.push(Route::Transaction { id: search_result.value });
+                },
+                SearchInputType::Account => {
+                    navigator.push(Route::Account { address: search_result.value });
+                },
+                SearchInputType::Block => match search_result.value.parse::<u64>() {
+                    Ok(slot) => {
+                        navigator.push(Route::Block { slot });
                     }
+                    Err(_) => invalid.set(true),
+                },
+                SearchInputType::Unknown => {
+                    log::info!("General search for: {}", search_result.value);
                 }
-            } else {
-                log::info!("Invalid search query: {}", query_value);
-                // Could show an error message here
             }
-            
-            is_searching.set(false);
-        });
-    };
-    
-    let handle_clear = move |_| {
-        query.set(String::new());
+        } else {
+            log::info!("Invalid search query: {}", query_value);
+        }
     };
-    
+
     cx.render(rsx! {
         div { class: "p-4 flex flex-col items-center gap-2",
             h1 { class: "text-xl font-bold mono", "OpenSVM Explorer" }
             p { class: "text-secondary mb-4 mono", "The quieter you become, the more you are able to hear." }
-            
+
             div { class: "search-container w-full max-w-[600px]",
-                div { 
-                    class: "flex items-center bg-surface rounded border p-3 gap-2 flex-1",
-                    class: if *is_focused.get() { "border-[var(--primary)]" } else { "" },
-                    
-                    // Search or loading icon
-                    if *is_searching.get() {
-                        rsx! {
-                            div { class: "animate-spin",
-                                Icon { 
-                                    icon: Loader,
-                                    width: 18,
-                                    height: 18,
-                                    fill: "var(--primary)"
-                                }
-                            }
-                        }
-                    } else {
-                        rsx! {
-                            Icon { 
-                                icon: Search,
-                                width: 18,
-                                height: 18,
-                                fill: if *is_focused.get() { "var(--primary)" } else { "var(--text-secondary)" }
-                            }
+                class: if *is_focused.get() { "focused" } else { "" },
+
+                Icon {
+                    icon: FaMagnifyingGlass,
+                    width: 16,
+                    height: 16,
+                    fill: if *is_focused.get() { "var(--primary)" } else { "var(--text-secondary)" }
+                }
+
+                // Search input
+                input {
+                    class: "search-input",
+                    placeholder: "{placeholder}",
+                    value: "{query}",
+                    oninput: move |e| {
+                        invalid.set(false);
+                        query.set(e.value.clone());
+                    },
+                    onkeydown: move |e| {
+                        if e.key() == Key::Enter {
+                            submit();
                         }
-                    }
-                    
-                    // Search input
-                    input {
-                        class: "flex-1 bg-transparent border-none outline-none mono",
-                        placeholder: "{placeholder}",
-                        value: "{query}",
-                        oninput: move |e| query.set(e.value.clone()),
-                        onkeydown: move |e| {
-                            if e.key() == "Enter" {
-                                handle_submit(());
-                            }
-                        },
-                        onfocus: move |_| is_focused.set(true),
-                        onblur: move |_| is_focused.set(false),
-                    }
-                    
-                    // Clear button
-                    if !query.is_empty() {
-                        rsx! {
-                            button {
-                                class: "bg-transparent border-none cursor-pointer",
-                                onclick: handle_clear,
-                                Icon { 
-                                    icon: FaTimes,
-                                    width: 18,
-                                    height: 18,
-                                    fill: "var(--text-secondary)"
-                                }
+                    },
+                    onfocus: move |_| is_focused.set(true),
+                    onblur: move |_| is_focused.set(false),
+                }
+
+                // Clear button
+                if !query.is_empty() {
+                    rsx! {
+                        button {
+                            class: "search-clear",
+                            onclick: move |_| {
+                                invalid.set(false);
+                                query.set(String::new());
+                            },
+                            Icon {
+                                icon: FaXmark,
+                                width: 16,
+                                height: 16,
+                                fill: "var(--text-secondary)"
                             }
                         }
                     }
                 }
-                
+
                 // Search button
                 button {
                     class: "search-button",
-                    class: if *is_searching.get() || query.is_empty() { "opacity-70 cursor-not-allowed" } else { "" },
-                    disabled: *is_searching.get() || query.is_empty(),
-                    onclick: handle_submit,
-                    if *is_searching.get() { "Searching..." } else { "Search" }
+                    disabled: query.trim().is_empty(),
+                    onclick: move |_| submit(),
+                    "Search"
+                }
+            }
+
+            if *invalid.get() {
+                rsx! {
+                    p { class: "search-error", "Enter a transaction signature, account address or slot number" }
                 }
             }
         }
     })
-}
\ No newline at end of file
+}
@@ -0,0 +1,88 @@
+use dioxus::prelude::*;
+use dioxus_router::prelude::*;
+
+use crate::app::Route;
+use crate::utils::address_utils::{format_address, format_number, format_timestamp};
+use crate::utils::api::TransactionSignature;
+
+// Props for the signature list
+#[derive(Props)]
+pub struct SignatureListProps<'a> {
+    pub signatures: &'a [TransactionSignature],
+    /// Whether older signatures may exist beyond the last one shown
+    pub has_more: bool,
+    /// A page is currently being fetched
+    pub loading: bool,
+    pub on_load_more: EventHandler<'a, ()>,
+}
+
+// Transaction history of an account with a "load more" footer
+#[component]
+pub fn SignatureList<'a>(cx: Scope<'a, SignatureListProps<'a>>) -> Element<'a> {
+    cx.render(rsx! {
+        div { class: "signature-list",
+            div { class: "signature-row signature-header",
+                span { "Signature" }
+                span { "Status" }
+                span { "Slot" }
+                span { "Block Time" }
+                span { "Memo" }
+            }
+            for sig in cx.props.signatures.iter() {
+                SignatureRow { key: "{sig.signature}", signature: sig }
+            }
+            if cx.props.has_more {
+                rsx! {
+                    button {
+                        class: "load-more-button",
+                        disabled: cx.props.loading,
+                        onclick: move |_| cx.props.on_load_more.call(()),
+                        if cx.props.loading { "Loading..." } else { "Load more" }
+                    }
+                }
+            } else if !cx.props.signatures.is_empty() {
+                rsx! {
+                    p { class: "signature-list-end text-tertiary", "No older transactions" }
+                }
+            }
+        }
+    })
+}
+
+#[derive(Props)]
+struct SignatureRowProps<'a> {
+    signature: &'a TransactionSignature,
+}
+
+#[component]
+fn SignatureRow<'a>(cx: Scope<'a, SignatureRowProps<'a>>) -> Element<'a> {
+    let sig = cx.props.signature;
+    let (status_class, status_label) = if sig.err.is_some() {
+        ("status-badge failed", "Failed")
+    } else {
+        ("status-badge success", "Success")
+    };
+    let slot = sig
+        .slot
+        .map(format_number)
+        .unwrap_or_else(|| "-".to_string());
+    let block_time = sig
+        .block_time
+        .map(|time| format_timestamp(time.max(0) as u64))
+        .unwrap_or_else(|| "-".to_string());
+    let memo = sig.memo.as_deref().unwrap_or("");
+
+    cx.render(rsx! {
+        div { class: "signature-row",
+            Link {
+                class: "mono",
+                to: Route::Transaction { id: sig.signature.clone() },
+                "{format_address(&sig.signature)}"
+            }
+            span { class: "{status_class}", "{status_label}" }
+            span { class: "mono", "{slot}" }
+            span { "{block_time}" }
+            span { class: "signature-memo", title: "{memo}", "{memo}" }
+        }
+    })
+}
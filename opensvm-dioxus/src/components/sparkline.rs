@@ -0,0 +1,63 @@
+use dioxus::prelude::*;
+
+// Props for the sparkline
+#[derive(Props, PartialEq)]
+pub struct SparklineProps {
+    /// Data points, oldest first
+    pub values: Vec<f64>,
+    #[props(default = 120)]
+    pub width: u32,
+    #[props(default = 32)]
+    pub height: u32,
+    /// Accessible description, also shown as a tooltip
+    #[props(default)]
+    pub label: &'static str,
+}
+
+// Small inline SVG line chart without axes
+#[component]
+pub fn Sparkline(cx: Scope<SparklineProps>) -> Element {
+    let values = &cx.props.values;
+    if values.len() < 2 {
+        return None;
+    }
+
+    let width = cx.props.width as f64;
+    let height = cx.props.height as f64;
+    let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    let range = if max > min { max - min } else { 1.0 };
+    let step = width / (values.len() - 1) as f64;
+
+    // Keep a 1px margin so the stroke is not clipped at the edges
+    let points = values
+        .iter()
+        .enumerate()
+        .map(|(i, value)| {
+            let x = i as f64 * step;
+            let y = 1.0 + (height - 2.0) * (1.0 - (value - min) / range);
+            format!("{:.1},{:.1}", x, y)
+        })
+        .collect::<Vec<_>>()
+        .join(" ");
+    let label = cx.props.label;
+
+    cx.render(rsx! {
+        svg {
+            class: "sparkline",
+            width: "{cx.props.width}",
+            height: "{cx.props.height}",
+            view_box: "0 0 {cx.props.width} {cx.props.height}",
+            preserve_aspect_ratio: "none",
+            role: "img",
+            "aria-label": "{label}",
+            title { "{label}" }
+            polyline {
+                points: "{points}",
+                fill: "none",
+                stroke: "var(--primary)",
+                stroke_width: "1.5",
+            }
+        }
+    })
+}
@@ -1,24 +1,73 @@
 use dioxus::prelude::*;
-use dioxus_free_icons::icons::lucide_icons::{Shield, Globe, Users, Wallet, TrendingUp, ChevronRight};
+use dioxus_free_icons::icons::fa_solid_icons::{
+    FaChartLine, FaCoins, FaPercent, FaScaleBalanced, FaShield, FaUsers, FaWallet,
+};
 use dioxus_free_icons::Icon;
 use dioxus_router::prelude::*;
 
-// Mock data for validator stats
+use crate::app::Route;
+use crate::components::sparkline::Sparkline;
+use crate::utils::address_utils::format_address;
+use crate::utils::api::VoteAccounts;
+use crate::utils::node_distribution::RegionDistribution;
+use crate::utils::stake_metrics::DecentralizationMetrics;
+use crate::utils::staking_yield::YieldModel;
+
+// Aggregate numbers derived from the vote accounts
 #[derive(Clone)]
 struct ValidatorStats {
     total_stake: u64,
     average_stake: u64,
     active_validators: u64,
-    total_delegators: u64,
-    average_apy: f64,
-    regions: Vec<Region>,
+    delinquent_validators: u64,
+    delinquent_stake_percent: f64,
+    weighted_commission: f64,
 }
 
-#[derive(Clone)]
-struct Region {
-    name: String,
-    validators: u64,
-    stake: u64,
+impl ValidatorStats {
+    fn from_vote_accounts(vote_accounts: &VoteAccounts) -> Self {
+        let total_stake = lamports_to_sol(vote_accounts.total_stake());
+        let delinquent_stake: u64 = vote_accounts
+            .delinquent
+            .iter()
+            .map(|account| account.activated_stake)
+            .sum();
+        let current_stake: u64 = vote_accounts
+            .current
+            .iter()
+            .map(|account| account.activated_stake)
+            .sum();
+        let active_validators = vote_accounts.current.len() as u64;
+
+        // Commission weighted by stake, so large validators count more
+        let weighted_commission = if current_stake == 0 {
+            0.0
+        } else {
+            vote_accounts
+                .current
+                .iter()
+                .map(|account| account.commission as f64 * account.activated_stake as f64)
+                .sum::<f64>()
+                / current_stake as f64
+        };
+
+        Self {
+            total_stake,
+            average_stake: lamports_to_sol(current_stake) / active_validators.max(1),
+            active_validators,
+            delinquent_validators: vote_accounts.delinquent.len() as u64,
+            delinquent_stake_percent: if vote_accounts.total_stake() == 0 {
+                0.0
+            } else {
+                delinquent_stake as f64 / vote_accounts.total_stake() as f64 * 100.0
+            },
+            weighted_commission,
+        }
+    }
+}
+
+fn lamports_to_sol(lamports: u64) -> u64 {
+    lamports / 1_000_000_000
 }
 
 // Stats card component
@@ -26,76 +75,22 @@ struct Region {
 pub struct StatsCardProps<'a> {
     pub icon: Element<'a>,
     pub label: &'a str,
-    pub value: &'a str,
-    #[props(optional)]
-    pub subvalue: Option<&'a str>,
+    #[props(into)]
+    pub value: String,
+    #[props(into)]
+    pub subvalue: String,
 }
 
 #[component]
-fn StatsCard<'a>(cx: Scope<'a, StatsCardProps<'a>>) -> Element {
+fn StatsCard<'a>(cx: Scope<'a, StatsCardProps<'a>>) -> Element<'a> {
     cx.render(rsx! {
         div { class: "stats-card",
-            div { class: "flex items-center gap-2 mb-3",
+            div { class: "stats-card-header",
                 &cx.props.icon
                 span { class: "text-secondary", "{cx.props.label}" }
             }
             p { class: "stats-value", "{cx.props.value}" }
-            if let Some(subvalue) = cx.props.subvalue {
-                rsx! {
-                    p { class: "text-sm", style: "color: var(--primary);", "{subvalue}" }
-                }
-            }
-        }
-    })
-}
-
-// Region globe component
-#[component]
-fn RegionGlobe(cx: Scope) -> Element {
-    // Mock data for regions
-    let regions = vec![
-        Region { name: "North America".to_string(), validators: 482, stake: 124893241 },
-        Region { name: "Europe".to_string(), validators: 394, stake: 98234123 },
-        Region { name: "Asia".to_string(), validators: 289, stake: 76234123 },
-        Region { name: "South America".to_string(), validators: 123, stake: 34123412 },
-        Region { name: "Africa".to_string(), validators: 67, stake: 12341234 },
-        Region { name: "Oceania".to_string(), validators: 33, stake: 8923412 },
-    ];
-    
-    // Find the maximum number of validators for scaling
-    let max_validators = regions.iter().map(|r| r.validators).max().unwrap_or(1);
-    
-    cx.render(rsx! {
-        div { class: "card",
-            div { class: "flex justify-between items-center mb-6",
-                h3 { class: "text-lg font-bold", "Global Distribution" }
-                button { class: "flex items-center text-[var(--primary)]",
-                    "View All"
-                    Icon { icon: ChevronRight, width: 16, height: 16, fill: "var(--primary)" }
-                }
-            }
-            
-            div { class: "flex gap-4 overflow-x-auto pb-4",
-                regions.iter().map(|region| {
-                    let percentage = (region.validators as f64 / max_validators as f64) * 100.0;
-                    rsx! {
-                        div { class: "flex flex-col items-center min-w-[100px]",
-                            div { 
-                                class: "w-full relative h-[200px] flex items-end",
-                                div {
-                                    class: "w-full bg-[var(--primary-light)] rounded-t-lg",
-                                    style: "height: {percentage}%; position: absolute; bottom: 0;",
-                                }
-                                p { 
-                                    class: "z-10 w-full text-center font-bold mb-2",
-                                    "{region.validators}"
-                                }
-                            }
-                            p { class: "text-sm text-secondary text-center", "{region.name}" }
-                        }
-                    }
-                })
-            }
+            p { class: "stats-subvalue", "{cx.props.subvalue}" }
         }
     })
 }
@@ -113,59 +108,266 @@ fn format_number(num: u64) -> String {
     }
 }
 
+// Props for the validator analytics
+#[derive(Props)]
+pub struct ValidatorAnalyticsProps<'a> {
+    pub vote_accounts: &'a VoteAccounts,
+    /// `None` while inflation data is unavailable
+    #[props(!optional)]
+    pub yield_model: Option<YieldModel>,
+}
+
 // Main validator analytics component
 #[component]
-pub fn ValidatorAnalytics(cx: Scope) -> Element {
-    // Mock data
-    let stats = ValidatorStats {
-        total_stake: 354829481,
-        average_stake: 258234,
-        active_validators: 1388,
-        total_delegators: 892341,
-        average_apy: 6.8,
-        regions: vec![], // We'll use the regions in the RegionGlobe component
-    };
-    
+pub fn ValidatorAnalytics<'a>(cx: Scope<'a, ValidatorAnalyticsProps<'a>>) -> Element<'a> {
+    let stats = ValidatorStats::from_vote_accounts(cx.props.vote_accounts);
+    let metrics = DecentralizationMetrics::from_vote_accounts(cx.props.vote_accounts);
+    let average_apy = cx
+        .props
+        .yield_model
+        .as_ref()
+        .and_then(|model| model.network_average(cx.props.vote_accounts));
+
     cx.render(rsx! {
-        div { class: "p-4",
+        div { class: "validator-analytics",
             div { class: "stats-grid",
                 StatsCard {
                     icon: cx.render(rsx! {
                         Icon { icon: FaShield, width: 18, height: 18, fill: "var(--primary)" }
                     }),
                     label: "Active Validators",
-                    value: &format_number(stats.active_validators),
-                    subvalue: Some("+12% this month"),
+                    value: format_number(stats.active_validators),
+                    subvalue: format!("{} delinquent", stats.delinquent_validators),
                 }
-                
+
                 StatsCard {
                     icon: cx.render(rsx! {
-                        Icon { icon: Wallet, width: 18, height: 18, fill: "var(--primary)" }
+                        Icon { icon: FaWallet, width: 18, height: 18, fill: "var(--primary)" }
                     }),
                     label: "Total Stake",
-                    value: &format!("{} SOL", format_number(stats.total_stake)),
-                    subvalue: Some(&format!("≈ ${}", format_number(stats.total_stake * 108))),
+                    value: format!("{} SOL", format_number(stats.total_stake)),
+                    subvalue: format!("{:.2}% delinquent", stats.delinquent_stake_percent),
+                }
+
+                StatsCard {
+                    icon: cx.render(rsx! {
+                        Icon { icon: FaScaleBalanced, width: 18, height: 18, fill: "var(--primary)" }
+                    }),
+                    label: "Average Stake",
+                    value: format!("{} SOL", format_number(stats.average_stake)),
+                    subvalue: "Per active validator",
+                }
+
+                StatsCard {
+                    icon: cx.render(rsx! {
+                        Icon { icon: FaPercent, width: 18, height: 18, fill: "var(--primary)" }
+                    }),
+                    label: "Average Commission",
+                    value: format!("{:.2}%", stats.weighted_commission),
+                    subvalue: "Stake-weighted",
+                }
+
+                StatsCard {
+                    icon: cx.render(rsx! {
+                        Icon { icon: FaCoins, width: 18, height: 18, fill: "var(--primary)" }
+                    }),
+                    label: "Average APY",
+                    value: average_apy
+                        .map(|apy| format!("{:.2}%", apy * 100.0))
+                        .unwrap_or_else(|| "-".to_string()),
+                    subvalue: "Stake-weighted estimate",
                 }
-                
+
                 StatsCard {
                     icon: cx.render(rsx! {
                         Icon { icon: FaUsers, width: 18, height: 18, fill: "var(--primary)" }
                     }),
-                    label: "Total Delegators",
-                    value: &format_number(stats.total_delegators),
+                    label: "Nakamoto Coefficient",
+                    value: metrics.nakamoto_coefficient.to_string(),
+                    subvalue: format!(
+                        "Validators holding {:.1}% of stake",
+                        metrics.superminority_stake * 100.0
+                    ),
                 }
-                
+
                 StatsCard {
                     icon: cx.render(rsx! {
                         Icon { icon: FaChartLine, width: 18, height: 18, fill: "var(--primary)" }
                     }),
-                    label: "Average APY",
-                    value: &format!("{}%", stats.average_apy),
-                    subvalue: Some("Last 30 days"),
+                    label: "Stake Gini Coefficient",
+                    value: format!("{:.3}", metrics.gini),
+                    subvalue: "0 = even, 1 = concentrated",
                 }
             }
-            
-            RegionGlobe {}
+
+            StakeDistribution { metrics: metrics }
         }
     })
-}
\ No newline at end of file
+}
+
+// Props for the stake distribution section
+#[derive(Props, PartialEq)]
+struct StakeDistributionProps {
+    metrics: DecentralizationMetrics,
+}
+
+// Cumulative stake curve and the superminority validator set
+#[component]
+fn StakeDistribution(cx: Scope<StakeDistributionProps>) -> Element {
+    let metrics = &cx.props.metrics;
+    let show_superminority = use_state(cx, || false);
+    let total_stake: u64 = metrics
+        .superminority
+        .iter()
+        .map(|account| account.activated_stake)
+        .sum::<u64>()
+        .max(1);
+    let superminority_total = metrics.superminority_stake;
+
+    cx.render(rsx! {
+        div { class: "section stake-distribution",
+            h2 { "Stake Distribution" }
+            p { class: "text-secondary small",
+                "Cumulative share of stake held by the largest {metrics.staked_validators} validators, largest first. "
+                "The dashed line marks the 1/3 superminority threshold."
+            }
+            div { class: "stake-curve",
+                // Start at zero so the chart spans the full 0-100% range
+                Sparkline {
+                    values: std::iter::once(0.0).chain(metrics.cumulative_curve.iter().copied()).collect(),
+                    width: 600,
+                    height: 160,
+                    label: "Cumulative stake share by validator rank",
+                }
+                div { class: "stake-curve-threshold" }
+            }
+
+            button {
+                class: "link-button",
+                onclick: move |_| show_superminority.set(!*show_superminority.get()),
+                if *show_superminority.get() {
+                    "Hide superminority"
+                } else {
+                    "Show superminority ({metrics.superminority.len()} validators)"
+                }
+            }
+
+            if *show_superminority.get() {
+                let mut cumulative = 0u64;
+                let rows = metrics.superminority.iter().enumerate().map(move |(index, account)| {
+                    cumulative += account.activated_stake;
+                    let share = cumulative as f64 / total_stake as f64 * superminority_total * 100.0;
+                    rsx! {
+                        tr { key: "{account.vote_pubkey}",
+                            td { class: "text-tertiary", "{index + 1}" }
+                            td {
+                                Link {
+                                    class: "mono",
+                                    to: Route::Validator { vote_pubkey: account.vote_pubkey.clone() },
+                                    "{format_address(&account.vote_pubkey)}"
+                                }
+                            }
+                            td { class: "numeric", "{format_number(account.activated_stake / 1_000_000_000)} SOL" }
+                            td { class: "numeric", "{share:.2}%" }
+                        }
+                    }
+                });
+
+                rsx! {
+                    table { class: "validator-table",
+                        thead {
+                            tr {
+                                th { "#" }
+                                th { "Vote Account" }
+                                th { "Stake" }
+                                th { "Cumulative Share" }
+                            }
+                        }
+                        tbody { rows }
+                    }
+                }
+            }
+        }
+    })
+}
+
+/// Countries listed under the continent chart
+const TOP_COUNTRIES: usize = 10;
+
+// Props for the region globe
+#[derive(Props, PartialEq)]
+pub struct RegionGlobeProps {
+    pub regions: RegionDistribution,
+}
+
+// Validator count and stake per continent, resolved from gossip addresses
+#[component]
+pub fn RegionGlobe(cx: Scope<RegionGlobeProps>) -> Element {
+    let regions = &cx.props.regions;
+    let max_share = regions
+        .by_continent
+        .iter()
+        .map(|region| region.stake_share)
+        .fold(0.0, f64::max);
+
+    cx.render(rsx! {
+        div { class: "section region-globe",
+            h2 { "Global Distribution" }
+            p { class: "text-secondary small",
+                "Gossip addresses resolved with an IP geolocation table. Bars show the share of stake hosted in each region."
+            }
+            p { class: "text-tertiary small", "Accuracy: {regions.source.accuracy()}" }
+
+            div { class: "region-columns",
+                for region in regions.by_continent.iter() {
+                    div { key: "{region.label}", class: "region-column",
+                        div { class: "region-bar-track",
+                            div {
+                                class: "region-bar",
+                                style: "height: {region.stake_share / max_share.max(f64::EPSILON) * 100.0}%",
+                            }
+                        }
+                        p { class: "region-share", "{region.stake_share * 100.0:.1}%" }
+                        p { class: "region-name", "{region.label}" }
+                        p { class: "text-secondary small",
+                            "{region.nodes} nodes · {format_number(region.stake / 1_000_000_000)} SOL"
+                        }
+                    }
+                }
+            }
+
+            if !regions.by_country.is_empty() {
+                rsx! {
+                    table { class: "validator-table",
+                        thead {
+                            tr {
+                                th { "Country" }
+                                th { "Nodes" }
+                                th { "Stake" }
+                                th { "Share" }
+                            }
+                        }
+                        tbody {
+                            for region in regions.by_country.iter().take(TOP_COUNTRIES) {
+                                tr { key: "{region.label}",
+                                    td { class: "mono", "{region.label}" }
+                                    td { class: "numeric", "{region.nodes}" }
+                                    td { class: "numeric", "{format_number(region.stake / 1_000_000_000)} SOL" }
+                                    td { class: "numeric", "{region.stake_share * 100.0:.2}%" }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            if regions.unlocated_nodes > 0 {
+                rsx! {
+                    p { class: "text-secondary small",
+                        "{regions.unlocated_nodes} nodes ({format_number(regions.unlocated_stake / 1_000_000_000)} SOL) could not be located."
+                    }
+                }
+            }
+        }
+    })
+}
@@ -0,0 +1,306 @@
+use dioxus::prelude::*;
+use dioxus_free_icons::icons::fa_solid_icons::{FaSort, FaSortDown, FaSortUp};
+use dioxus_free_icons::Icon;
+use dioxus_router::prelude::*;
+
+use crate::app::Route;
+use crate::routes::compare::{compare_route, MAX_COMPARED};
+use crate::utils::address_utils::{format_address, format_number};
+use crate::utils::api::{VoteAccountInfo, VoteAccounts};
+use crate::utils::staking_yield::YieldModel;
+
+/// Rows rendered before the user asks for more
+const PAGE_SIZE: usize = 100;
+
+// Columns the table can be sorted by
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortColumn {
+    Identity,
+    VoteAccount,
+    Stake,
+    Commission,
+    Apy,
+    LastVote,
+    RootSlot,
+}
+
+impl SortColumn {
+    fn compare(
+        self,
+        a: &VoteAccountInfo,
+        b: &VoteAccountInfo,
+        yield_model: Option<&YieldModel>,
+    ) -> std::cmp::Ordering {
+        match self {
+            SortColumn::Identity => a.node_pubkey.cmp(&b.node_pubkey),
+            SortColumn::VoteAccount => a.vote_pubkey.cmp(&b.vote_pubkey),
+            SortColumn::Stake => a.activated_stake.cmp(&b.activated_stake),
+            SortColumn::Commission => a.commission.cmp(&b.commission),
+            SortColumn::Apy => {
+                // Accounts without an estimate compare below every estimate
+                let apy = |account| yield_model.and_then(|model| model.estimate(account));
+                apy(a).partial_cmp(&apy(b)).unwrap_or(std::cmp::Ordering::Equal)
+            }
+            SortColumn::LastVote => a.last_vote.cmp(&b.last_vote),
+            SortColumn::RootSlot => a.root_slot.cmp(&b.root_slot),
+        }
+    }
+
+    // Numeric columns start with the largest values first
+    fn default_descending(self) -> bool {
+        !matches!(self, SortColumn::Identity | SortColumn::VoteAccount)
+    }
+}
+
+// Which group of vote accounts is shown
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValidatorFilter {
+    Current,
+    Delinquent,
+}
+
+// Props for the validator table
+#[derive(Props)]
+pub struct ValidatorTableProps<'a> {
+    pub vote_accounts: &'a VoteAccounts,
+    /// `None` while inflation data is unavailable
+    #[props(!optional)]
+    pub yield_model: Option<YieldModel>,
+}
+
+// Sortable and filterable table of vote accounts
+#[component]
+pub fn ValidatorTable<'a>(cx: Scope<'a, ValidatorTableProps<'a>>) -> Element<'a> {
+    let search = use_state(cx, String::new);
+    let filter = use_state(cx, || ValidatorFilter::Current);
+    let sort = use_state(cx, || (SortColumn::Stake, true));
+    let visible_rows = use_state(cx, || PAGE_SIZE);
+    // Vote accounts picked for the compare view
+    let compare = use_state(cx, Vec::<String>::new);
+    let navigator = use_navigator(cx);
+
+    let vote_accounts = cx.props.vote_accounts;
+    let (accounts, delinquent) = match *filter.get() {
+        ValidatorFilter::Current => (&vote_accounts.current, false),
+        ValidatorFilter::Delinquent => (&vote_accounts.delinquent, true),
+    };
+
+    let query = search.get().trim().to_lowercase();
+    let mut rows: Vec<&VoteAccountInfo> = accounts
+        .iter()
+        .filter(|account| {
+            query.is_empty()
+                || account.node_pubkey.to_lowercase().contains(&query)
+                || account.vote_pubkey.to_lowercase().contains(&query)
+        })
+        .collect();
+
+    let (sort_column, descending) = *sort.get();
+    let yield_model = cx.props.yield_model.as_ref();
+    // Accounts without an APY estimate stay last in either direction
+    let missing_apy = |account: &VoteAccountInfo| {
+        sort_column == SortColumn::Apy
+            && yield_model.and_then(|model| model.estimate(account)).is_none()
+    };
+    rows.sort_by(|a, b| {
+        missing_apy(a).cmp(&missing_apy(b)).then_with(|| {
+            let ordering = sort_column.compare(a, b, yield_model);
+            if descending {
+                ordering.reverse()
+            } else {
+                ordering
+            }
+        })
+    });
+
+    let total_rows = rows.len();
+    let shown_rows = total_rows.min(*visible_rows.get());
+    let current_count = vote_accounts.current.len();
+    let delinquent_count = vote_accounts.delinquent.len();
+
+    let header = |label: &'static str, column: SortColumn| {
+        let icon = if sort_column != column {
+            rsx! { Icon { icon: FaSort, width: 10, height: 10, fill: "var(--text-tertiary)" } }
+        } else if descending {
+            rsx! { Icon { icon: FaSortDown, width: 10, height: 10, fill: "var(--primary)" } }
+        } else {
+            rsx! { Icon { icon: FaSortUp, width: 10, height: 10, fill: "var(--primary)" } }
+        };
+
+        rsx! {
+            th {
+                class: "sortable",
+                onclick: move |_| {
+                    if sort_column == column {
+                        sort.set((column, !descending));
+                    } else {
+                        sort.set((column, column.default_descending()));
+                    }
+                },
+                "{label} "
+                icon
+            }
+        }
+    };
+
+    cx.render(rsx! {
+        div { class: "validator-table-container",
+            div { class: "validator-table-controls",
+                input {
+                    class: "validator-search",
+                    placeholder: "Search by identity or vote account",
+                    value: "{search}",
+                    oninput: move |evt| {
+                        search.set(evt.value.clone());
+                        visible_rows.set(PAGE_SIZE);
+                    },
+                }
+                div { class: "segmented-control",
+                    button {
+                        class: if *filter.get() == ValidatorFilter::Current { "active" } else { "" },
+                        onclick: move |_| {
+                            filter.set(ValidatorFilter::Current);
+                            visible_rows.set(PAGE_SIZE);
+                        },
+                        "Current ({current_count})"
+                    }
+                    button {
+                        class: if *filter.get() == ValidatorFilter::Delinquent { "active" } else { "" },
+                        onclick: move |_| {
+                            filter.set(ValidatorFilter::Delinquent);
+                            visible_rows.set(PAGE_SIZE);
+                        },
+                        "Delinquent ({delinquent_count})"
+                    }
+                }
+                button {
+                    class: "load-more-button compare-button",
+                    disabled: compare.len() < 2,
+                    onclick: move |_| {
+                        navigator.push(compare_route(compare.get()));
+                    },
+                    "Compare ({compare.len()}/{MAX_COMPARED})"
+                }
+            }
+
+            if rows.is_empty() {
+                rsx! { p { class: "text-secondary", "No validators match your search" } }
+            } else {
+                rsx! {
+                    table { class: "validator-table",
+                        thead {
+                            tr {
+                                th { "" }
+                                th { "#" }
+                                header("Identity", SortColumn::Identity)
+                                header("Vote Account", SortColumn::VoteAccount)
+                                header("Stake", SortColumn::Stake)
+                                header("Commission", SortColumn::Commission)
+                                header("Est. APY", SortColumn::Apy)
+                                header("Last Vote", SortColumn::LastVote)
+                                header("Root Slot", SortColumn::RootSlot)
+                                th { "Status" }
+                            }
+                        }
+                        tbody {
+                            for (index, account) in rows.iter().take(shown_rows).enumerate() {
+                                ValidatorRow {
+                                    key: "{account.vote_pubkey}",
+                                    rank: index + 1,
+                                    account: account,
+                                    apy: yield_model.and_then(|model| model.estimate(account)),
+                                    delinquent: delinquent,
+                                    compared: compare.contains(&account.vote_pubkey),
+                                    can_compare: compare.len() < MAX_COMPARED,
+                                    on_toggle_compare: move |vote_pubkey: String| {
+                                        compare.with_mut(|compare| {
+                                            if let Some(index) = compare.iter().position(|selected| *selected == vote_pubkey) {
+                                                compare.remove(index);
+                                            } else if compare.len() < MAX_COMPARED {
+                                                compare.push(vote_pubkey);
+                                            }
+                                        });
+                                    },
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            if shown_rows < total_rows {
+                rsx! {
+                    button {
+                        class: "load-more-button",
+                        onclick: move |_| visible_rows.set(shown_rows + PAGE_SIZE),
+                        "Show more ({total_rows - shown_rows} remaining)"
+                    }
+                }
+            }
+        }
+    })
+}
+
+#[derive(Props)]
+struct ValidatorRowProps<'a> {
+    rank: usize,
+    account: &'a VoteAccountInfo,
+    #[props(!optional)]
+    apy: Option<f64>,
+    delinquent: bool,
+    compared: bool,
+    can_compare: bool,
+    on_toggle_compare: EventHandler<'a, String>,
+}
+
+#[component]
+fn ValidatorRow<'a>(cx: Scope<'a, ValidatorRowProps<'a>>) -> Element<'a> {
+    let account = cx.props.account;
+    let stake = format_number(account.activated_stake / 1_000_000_000);
+    let apy = cx
+        .props
+        .apy
+        .map(|apy| format!("{:.2}%", apy * 100.0))
+        .unwrap_or_else(|| "-".to_string());
+
+    cx.render(rsx! {
+        tr {
+            td {
+                input {
+                    r#type: "checkbox",
+                    title: "Compare",
+                    checked: cx.props.compared,
+                    disabled: !cx.props.compared && !cx.props.can_compare,
+                    onchange: move |_| cx.props.on_toggle_compare.call(account.vote_pubkey.clone()),
+                }
+            }
+            td { class: "text-tertiary", "{cx.props.rank}" }
+            td {
+                Link {
+                    class: "mono",
+                    to: Route::Account { address: account.node_pubkey.clone() },
+                    "{format_address(&account.node_pubkey)}"
+                }
+            }
+            td {
+                Link {
+                    class: "mono",
+                    to: Route::Validator { vote_pubkey: account.vote_pubkey.clone() },
+                    "{format_address(&account.vote_pubkey)}"
+                }
+            }
+            td { class: "numeric", "{stake} SOL" }
+            td { class: "numeric", "{account.commission}%" }
+            td { class: "numeric", "{apy}" }
+            td { class: "numeric mono", "{format_number(account.last_vote)}" }
+            td { class: "numeric mono", "{format_number(account.root_slot)}" }
+            td {
+                if cx.props.delinquent {
+                    rsx! { span { class: "status-badge failed", "Delinquent" } }
+                } else {
+                    rsx! { span { class: "status-badge success", "Active" } }
+                }
+            }
+        }
+    })
+}
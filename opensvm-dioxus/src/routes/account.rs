@@ -1,11 +1,21 @@
 //! Account page
 
+use crate::app::Route;
+use crate::components::anchor_idl::{AnchorAccountData, ProgramIdl};
+use crate::components::rpc_error_view::RpcErrorView;
+use crate::components::signature_list::SignatureList;
+use crate::stores::cluster_store::{get_rpc_urls, use_cluster_store};
 use crate::utils::api::AccountInfo;
-#[cfg(feature = "desktop")]
-use crate::utils::api::SolanaApiClient;
-#[allow(unused_imports)]
-use crate::utils::api::TransactionSignature;
+use crate::utils::api::{
+    RpcError, RpcResponse, SolanaApiClient, TransactionSignature, VOTE_PROGRAM_ID,
+};
+use crate::utils::anchor::may_have_idl;
+use crate::utils::pubsub::{use_subscription, Subscription};
 use dioxus::prelude::*;
+use dioxus_router::prelude::*;
+
+/// Number of signatures fetched per page of history
+const SIGNATURES_PAGE_SIZE: usize = 25;
 
 #[derive(Props, PartialEq)]
 pub struct AccountPageProps {
@@ -16,13 +26,26 @@ pub struct AccountPageProps {
 pub fn AccountPage(cx: Scope<AccountPageProps>) -> Element {
     let account_info = use_state(cx, || Option::<AccountInfo>::None);
     let transactions = use_state(cx, Vec::<TransactionSignature>::new);
+    let has_more_transactions = use_state(cx, || false);
+    let loading_transactions = use_state(cx, || false);
+    let transactions_error = use_state(cx, || Option::<RpcError>::None);
     let loading = use_state(cx, || true);
-    let error = use_state(cx, || Option::<String>::None);
+    let error = use_state(cx, || Option::<RpcError>::None);
+    let reload = use_state(cx, || 0u32);
+    let rpc_urls = get_rpc_urls(use_cluster_store(cx));
+    let account_update = use_subscription(
+        cx,
+        Some(Subscription::Account {
+            pubkey: cx.props.address.clone(),
+        }),
+    );
 
-    // Load account data on mount or when address changes
-    use_effect(cx, (&cx.props.address,), |(address,)| {
+    // Load account data on mount, when address or cluster changes, or on retry
+    use_effect(cx, (&cx.props.address, &rpc_urls, reload.get()), |(address, rpc_urls, _)| {
         let account_info = account_info.to_owned();
-        let _transactions = transactions.to_owned();
+        let transactions = transactions.to_owned();
+        let has_more_transactions = has_more_transactions.to_owned();
+        let transactions_error = transactions_error.to_owned();
         let loading = loading.to_owned();
         let error = error.to_owned();
         let address = address.clone();
@@ -30,44 +53,114 @@ pub fn AccountPage(cx: Scope<AccountPageProps>) -> Element {
         async move {
             loading.set(true);
             error.set(None);
+            transactions.set(Vec::new());
+            has_more_transactions.set(false);
+            transactions_error.set(None);
 
-            #[cfg(feature = "web")]
-            {
-                match crate::utils::api::web::fetch_account_info(&address).await {
-                    Ok(info) => {
-                        account_info.set(info);
-                        loading.set(false);
-                    }
-                    Err(e) => {
-                        error.set(Some(format!("Failed to load account: {:?}", e)));
-                        loading.set(false);
-                    }
+            let client = SolanaApiClient::new(rpc_urls);
+
+            // Fetch account info
+            match client.get_account_info(&address).await {
+                Ok(info) => {
+                    account_info.set(info);
+                    loading.set(false);
+                }
+                Err(e) => {
+                    error.set(Some(e));
+                    loading.set(false);
+                    return;
                 }
             }
 
-            #[cfg(feature = "desktop")]
+            // Fetch the first page of transaction history
+            match client
+                .get_signatures_for_address(&address, None, None, SIGNATURES_PAGE_SIZE)
+                .await
             {
-                let client = SolanaApiClient::new();
+                Ok(page) => {
+                    has_more_transactions.set(page.len() == SIGNATURES_PAGE_SIZE);
+                    transactions.set(page);
+                }
+                Err(e) => transactions_error.set(Some(e)),
+            }
+        }
+    });
 
-                // Fetch account info
-                match client.get_account_info(&address).await {
-                    Ok(info) => {
-                        account_info.set(info);
+    // Fetch the next (older) page of signatures
+    let load_more_transactions = move |_| {
+        if *loading_transactions.get() {
+            return;
+        }
+        let Some(before) = transactions.last().map(|sig| sig.signature.clone()) else {
+            return;
+        };
 
-                        // Skip fetching transactions for now to avoid compilation issues
-                        // transactions.set(Vec::new());
+        let transactions = transactions.to_owned();
+        let has_more_transactions = has_more_transactions.to_owned();
+        let loading_transactions = loading_transactions.to_owned();
+        let transactions_error = transactions_error.to_owned();
+        let address = cx.props.address.clone();
+        let client = SolanaApiClient::new(rpc_urls.clone());
 
-                        loading.set(false);
-                    }
-                    Err(e) => {
-                        error.set(Some(format!("Failed to load account: {}", e)));
-                        loading.set(false);
-                    }
+        loading_transactions.set(true);
+        transactions_error.set(None);
+        cx.spawn(async move {
+            match client
+                .get_signatures_for_address(&address, Some(&before), None, SIGNATURES_PAGE_SIZE)
+                .await
+            {
+                Ok(page) => {
+                    has_more_transactions.set(page.len() == SIGNATURES_PAGE_SIZE);
+                    transactions.with_mut(|transactions| {
+                        // Only append if no reload replaced the list meanwhile
+                        if transactions.last().map(|sig| sig.signature.as_str())
+                            == Some(before.as_str())
+                        {
+                            transactions.extend(page);
+                        }
+                    });
                 }
+                Err(e) => transactions_error.set(Some(e)),
+            }
+            loading_transactions.set(false);
+        });
+    };
+
+    // Apply live account changes pushed over the WebSocket
+    use_effect(cx, (account_update.get(),), |(update,)| {
+        let account_info = account_info.to_owned();
+        async move {
+            let Some(update) = update else {
+                return;
+            };
+            match serde_json::from_value::<RpcResponse<AccountInfo>>(update) {
+                Ok(response) => account_info.set(Some(response.value)),
+                Err(e) => log::warn!("Ignoring malformed account notification: {}", e),
             }
         }
     });
 
+    let is_vote_account = account_info
+        .get()
+        .as_ref()
+        .is_some_and(|info| info.owner == VOTE_PROGRAM_ID);
+
+    let is_program = account_info
+        .get()
+        .as_ref()
+        .is_some_and(|info| info.executable && may_have_idl(&cx.props.address));
+
+    // Data of accounts owned by programs without a built-in decoder may be
+    // decodable with the owner's Anchor IDL
+    let anchor_data = account_info.get().as_ref().and_then(|info| {
+        if info.executable || !may_have_idl(&info.owner) {
+            return None;
+        }
+        info.data_bytes()
+            .filter(|data| !data.is_empty())
+            .map(|data| (info.owner.clone(), data))
+    });
+
     let sol_balance = account_info
         .get()
         .as_ref()
@@ -104,15 +197,10 @@ pub fn AccountPage(cx: Scope<AccountPageProps>) -> Element {
                 rsx! {
                     div { class: "error-section",
                         div { class: "error-message",
-                            h3 { "Error Loading Account" }
-                            p { "{err}" }
-                            button {
-                                class: "retry-button",
-                                onclick: move |_| {
-                                    // Trigger reload
-                                    loading.set(true);
-                                },
-                                "🔄 Retry"
+                            RpcErrorView {
+                                error: err,
+                                context: "Error Loading Account",
+                                on_retry: move |_| reload.set(reload.get() + 1),
                             }
                         }
                     }
@@ -152,6 +240,30 @@ pub fn AccountPage(cx: Scope<AccountPageProps>) -> Element {
                             }
                         }
 
+                        // Vote accounts belong to a validator with its own page
+                        if is_vote_account {
+                            rsx! {
+                                div { class: "validator-link-section",
+                                    p { "This is a validator vote account." }
+                                    Link {
+                                        class: "action-button",
+                                        to: Route::Validator { vote_pubkey: cx.props.address.clone() },
+                                        "View validator details"
+                                    }
+                                }
+                            }
+                        }
+
+                        // Programs may publish an Anchor IDL or have one imported
+                        if is_program {
+                            rsx! {
+                                div { class: "account-data-section",
+                                    h2 { "Program IDL" }
+                                    ProgramIdl { program_id: cx.props.address.clone() }
+                                }
+                            }
+                        }
+
                         // Account data section
                         if let Some(info) = account_info.get().as_ref() {
                             if !info.data.is_empty() {
@@ -171,6 +283,11 @@ pub fn AccountPage(cx: Scope<AccountPageProps>) -> Element {
                                                 }
                                             }
                                         }
+                                        if let Some((owner, data)) = &anchor_data {
+                                            rsx! {
+                                                AnchorAccountData { owner: owner.clone(), data: data.clone() }
+                                            }
+                                        }
                                     }
                                 }
                             } else {
@@ -186,14 +303,25 @@ pub fn AccountPage(cx: Scope<AccountPageProps>) -> Element {
                         // Recent transactions section
                         div { class: "transactions-section",
                             h2 { "Recent Transactions" }
-                            if transactions.is_empty() {
+                            if transactions.is_empty() && transactions_error.is_none() {
                                 rsx! {
                                     p { class: "no-transactions", "No recent transactions found" }
                                 }
-                            } else {
+                            } else if !transactions.is_empty() {
+                                rsx! {
+                                    SignatureList {
+                                        signatures: transactions.get(),
+                                        has_more: *has_more_transactions.get(),
+                                        loading: *loading_transactions.get(),
+                                        on_load_more: load_more_transactions,
+                                    }
+                                }
+                            }
+                            if let Some(err) = transactions_error.get() {
                                 rsx! {
-                                    div { class: "transactions-list",
-                                        p { "Transaction list functionality temporarily disabled for compilation" }
+                                    RpcErrorView {
+                                        error: err,
+                                        context: "Failed to Load Transactions",
                                     }
                                 }
                             }
@@ -0,0 +1,321 @@
+//! Block page
+
+use dioxus::prelude::*;
+use dioxus_router::prelude::*;
+use futures_util::future::join;
+
+use crate::app::Route;
+use crate::components::rpc_error_view::RpcErrorView;
+use crate::stores::cluster_store::{get_rpc_urls, use_cluster_store};
+use crate::utils::address_utils::{format_address, format_number, format_timestamp};
+use crate::utils::api::{BlockDetails, Reward, RpcError, SolanaApiClient, TransactionDetails};
+
+/// Number of transactions shown per page
+const TRANSACTIONS_PAGE_SIZE: usize = 50;
+
+#[derive(PartialEq, Props)]
+pub struct BlockPageProps {
+    pub slot: u64,
+}
+
+/// Block page component
+pub fn BlockPage(cx: Scope<BlockPageProps>) -> Element {
+    let block = use_state(cx, || None::<BlockDetails>);
+    let leader = use_state(cx, || None::<String>);
+    let loading = use_state(cx, || true);
+    let error = use_state(cx, || None::<RpcError>);
+    let reload = use_state(cx, || 0u32);
+    let hide_votes = use_state(cx, || true);
+    let page = use_state(cx, || 0usize);
+    let rpc_urls = get_rpc_urls(use_cluster_store(cx));
+
+    // Fetch the block and its leader when the slot or cluster changes, or on retry
+    use_effect(cx, (&cx.props.slot, &rpc_urls, reload.get()), |(slot, rpc_urls, _)| {
+        let block = block.clone();
+        let leader = leader.clone();
+        let loading = loading.clone();
+        let error = error.clone();
+        let page = page.clone();
+
+        async move {
+            loading.set(true);
+            error.set(None);
+            page.set(0);
+
+            let client = SolanaApiClient::new(rpc_urls);
+            let (block_result, leader_result) =
+                join(client.get_block(slot), client.get_slot_leader(slot)).await;
+
+            match block_result {
+                Ok(data) => {
+                    // The leader schedule of old epochs is pruned; fall back
+                    // to the identity that collected the block's fees
+                    let block_leader = leader_result
+                        .ok()
+                        .flatten()
+                        .or_else(|| data.fee_recipient().map(str::to_string));
+                    leader.set(block_leader);
+                    block.set(Some(data));
+                }
+                Err(e) => {
+                    block.set(None);
+                    error.set(Some(e));
+                }
+            }
+
+            loading.set(false);
+        }
+    });
+
+    let slot = cx.props.slot;
+
+    cx.render(rsx! {
+        div { class: "block-page",
+            div { class: "block-header",
+                h1 { "Block #{format_number(slot)}" }
+                div { class: "block-nav",
+                    if slot > 0 {
+                        rsx! {
+                            Link { class: "block-nav-link", to: Route::Block { slot: slot - 1 }, "← Previous" }
+                        }
+                    }
+                    if let Some(next) = slot.checked_add(1) {
+                        rsx! {
+                            Link { class: "block-nav-link", to: Route::Block { slot: next }, "Next →" }
+                        }
+                    }
+                }
+            }
+
+            if *loading.get() {
+                rsx! {
+                    div { class: "loading",
+                        p { "Loading block..." }
+                    }
+                }
+            } else if let Some(err) = error.get() {
+                rsx! {
+                    div { class: "error",
+                        RpcErrorView {
+                            error: err,
+                            context: "Error Loading Block",
+                            on_retry: move |_| reload.set(reload.get() + 1),
+                        }
+                    }
+                }
+            } else if let Some(block) = block.get() {
+                let vote_count = block.transactions.iter().filter(|tx| tx.is_vote()).count();
+                let failed_count = block
+                    .transactions
+                    .iter()
+                    .filter(|tx| tx.meta.as_ref().is_some_and(|meta| meta.err.is_some()))
+                    .count();
+                let visible: Vec<&TransactionDetails> = block
+                    .transactions
+                    .iter()
+                    .filter(|tx| !*hide_votes.get() || !tx.is_vote())
+                    .collect();
+                let visible_count = visible.len();
+                let page_count = visible_count.div_ceil(TRANSACTIONS_PAGE_SIZE).max(1);
+                let current_page = (*page.get()).min(page_count - 1);
+                let start = current_page * TRANSACTIONS_PAGE_SIZE;
+                // Rows are keyed by signature so toggling vote transactions or
+                // paging does not reuse rows of other transactions
+                let page_items = visible
+                    .into_iter()
+                    .skip(start)
+                    .take(TRANSACTIONS_PAGE_SIZE)
+                    .map(|tx| (tx.transaction.signatures.first().cloned().unwrap_or_default(), tx));
+                // One account can receive several rewards in a block, so the
+                // pubkey alone is not a unique key
+                let reward_items = block.rewards.iter().enumerate().map(|(index, reward)| {
+                    let kind = reward.reward_type.as_deref().unwrap_or("Unknown");
+                    (format!("{}-{}", index, kind), reward)
+                });
+
+                rsx! {
+                    div { class: "block-details",
+                        div { class: "section",
+                            h2 { "Overview" }
+                            div { class: "info-grid",
+                                div { class: "info-item",
+                                    span { class: "label", "Blockhash:" }
+                                    span { class: "value mono", "{block.blockhash}" }
+                                }
+                                div { class: "info-item",
+                                    span { class: "label", "Previous Blockhash:" }
+                                    span { class: "value mono", "{block.previous_blockhash}" }
+                                }
+                                div { class: "info-item",
+                                    span { class: "label", "Parent Slot:" }
+                                    Link {
+                                        class: "value mono",
+                                        to: Route::Block { slot: block.parent_slot },
+                                        "{format_number(block.parent_slot)}"
+                                    }
+                                }
+                                div { class: "info-item",
+                                    span { class: "label", "Block Time:" }
+                                    span { class: "value",
+                                        block.block_time
+                                            .map(|time| format_timestamp(time.max(0) as u64))
+                                            .unwrap_or_else(|| "Unknown".to_string())
+                                    }
+                                }
+                                div { class: "info-item",
+                                    span { class: "label", "Block Height:" }
+                                    span { class: "value",
+                                        block.block_height
+                                            .map(format_number)
+                                            .unwrap_or_else(|| "Unknown".to_string())
+                                    }
+                                }
+                                div { class: "info-item",
+                                    span { class: "label", "Leader:" }
+                                    if let Some(leader) = leader.get() {
+                                        rsx! {
+                                            Link {
+                                                class: "value mono",
+                                                to: Route::Account { address: leader.clone() },
+                                                "{leader}"
+                                            }
+                                        }
+                                    } else {
+                                        rsx! { span { class: "value", "Unknown" } }
+                                    }
+                                }
+                                div { class: "info-item",
+                                    span { class: "label", "Transactions:" }
+                                    span { class: "value",
+                                        "{block.transactions.len()} ({vote_count} votes, {failed_count} failed)"
+                                    }
+                                }
+                            }
+                        }
+
+                        if !block.rewards.is_empty() {
+                            rsx! {
+                                div { class: "section",
+                                    h2 { "Rewards" }
+                                    div { class: "reward-list",
+                                        for (key, reward) in reward_items {
+                                            RewardRow { key: "{key}", reward: reward }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+
+                        div { class: "section",
+                            div { class: "section-header",
+                                h2 { "Transactions" }
+                                label { class: "toggle",
+                                    input {
+                                        r#type: "checkbox",
+                                        checked: *hide_votes.get(),
+                                        onchange: move |_| {
+                                            hide_votes.set(!*hide_votes.get());
+                                            page.set(0);
+                                        },
+                                    }
+                                    " Hide vote transactions"
+                                }
+                            }
+
+                            if visible_count == 0 {
+                                rsx! { p { class: "text-secondary", "No transactions to show" } }
+                            } else {
+                                rsx! {
+                                    div { class: "block-transaction-list",
+                                        for (signature, tx) in page_items {
+                                            BlockTransactionRow { key: "{signature}", transaction: tx }
+                                        }
+                                    }
+                                }
+                            }
+
+                            if page_count > 1 {
+                                rsx! {
+                                    div { class: "pagination",
+                                        button {
+                                            disabled: current_page == 0,
+                                            onclick: move |_| page.set(current_page.saturating_sub(1)),
+                                            "← Prev"
+                                        }
+                                        span { "Page {current_page + 1} of {page_count}" }
+                                        button {
+                                            disabled: current_page + 1 >= page_count,
+                                            onclick: move |_| page.set(current_page + 1),
+                                            "Next →"
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    })
+}
+
+#[derive(Props)]
+struct RewardRowProps<'a> {
+    reward: &'a Reward,
+}
+
+fn RewardRow<'a>(cx: Scope<'a, RewardRowProps<'a>>) -> Element<'a> {
+    let reward = cx.props.reward;
+    let amount = reward.lamports as f64 / 1_000_000_000.0;
+    let balance = reward.post_balance as f64 / 1_000_000_000.0;
+
+    cx.render(rsx! {
+        div { class: "reward-row",
+            Link {
+                class: "mono",
+                to: Route::Account { address: reward.pubkey.clone() },
+                "{format_address(&reward.pubkey)}"
+            }
+            span { "{reward.reward_type.as_deref().unwrap_or(\"-\")}" }
+            span { "{amount:.9} SOL" }
+            span { class: "text-secondary", "{balance:.4} SOL after" }
+        }
+    })
+}
+
+#[derive(Props)]
+struct BlockTransactionRowProps<'a> {
+    transaction: &'a TransactionDetails,
+}
+
+fn BlockTransactionRow<'a>(cx: Scope<'a, BlockTransactionRowProps<'a>>) -> Element<'a> {
+    let tx = cx.props.transaction;
+    let signature = tx.transaction.signatures.first().cloned().unwrap_or_default();
+    let failed = tx.meta.as_ref().is_some_and(|meta| meta.err.is_some());
+    let fee = tx.meta.as_ref().map(|meta| meta.fee).unwrap_or(0);
+    let message = &tx.transaction.message;
+    let program = message
+        .instructions
+        .first()
+        .and_then(|instruction| message.account_keys.get(instruction.program_id_index as usize))
+        .map(|program_id| format_address(program_id))
+        .unwrap_or_else(|| "-".to_string());
+
+    cx.render(rsx! {
+        div { class: "block-transaction-row",
+            Link {
+                class: "mono",
+                to: Route::Transaction { id: signature.clone() },
+                "{format_address(&signature)}"
+            }
+            if failed {
+                rsx! { span { class: "status-badge failed", "Failed" } }
+            } else {
+                rsx! { span { class: "status-badge success", "Success" } }
+            }
+            span { class: "mono", title: "First program invoked", "{program}" }
+            span { "{message.instructions.len()} ix" }
+            span { "{fee} lamports" }
+        }
+    })
+}
@@ -0,0 +1,681 @@
+//! Side-by-side validator comparison
+
+use std::collections::HashMap;
+
+use dioxus::html::input_data::keyboard_types::Key;
+use dioxus::prelude::*;
+use dioxus_free_icons::icons::fa_solid_icons::FaXmark;
+use dioxus_free_icons::Icon;
+use dioxus_router::prelude::*;
+use futures_util::future::{join, join3, join_all};
+
+use crate::app::Route;
+use crate::components::rpc_error_view::RpcErrorView;
+use crate::stores::cluster_store::{get_rpc_urls, use_cluster_store};
+use crate::utils::address_utils::{format_address, format_number};
+use crate::utils::api::{ClusterNode, RpcError, SolanaApiClient, VoteAccountInfo, VoteAccounts};
+use crate::utils::node_distribution::{compare_versions, ValidatorClient};
+use crate::utils::staking_yield::{earned_credits, YieldModel};
+
+/// Most vote accounts that can be compared at once
+pub const MAX_COMPARED: usize = 4;
+
+/// Completed epochs whose commission and credits are compared
+const HISTORY_EPOCHS: u64 = 4;
+
+/// Credits below this share of the cluster average mark an epoch as weak; a
+/// proxy for missed votes, not the cluster's delinquency status
+const LOW_CREDITS_RATIO: f64 = 0.8;
+
+/// Split the `vote_pubkeys` route segment into at most [`MAX_COMPARED`]
+/// distinct vote accounts
+pub fn parse_vote_pubkeys(vote_pubkeys: &str) -> Vec<String> {
+    let mut parsed: Vec<String> = Vec::new();
+    for pubkey in vote_pubkeys.split(',').map(str::trim).filter(|pubkey| !pubkey.is_empty()) {
+        if parsed.len() < MAX_COMPARED && !parsed.iter().any(|existing| existing == pubkey) {
+            parsed.push(pubkey.to_string());
+        }
+    }
+    parsed
+}
+
+/// Route to the compare view for `vote_pubkeys`, or the validators page if empty
+pub fn compare_route(vote_pubkeys: &[String]) -> Route {
+    if vote_pubkeys.is_empty() {
+        Route::Validators {}
+    } else {
+        Route::Compare {
+            vote_pubkeys: vote_pubkeys.join(","),
+        }
+    }
+}
+
+#[derive(PartialEq, Props)]
+pub struct ComparePageProps {
+    /// Comma-separated vote accounts
+    pub vote_pubkeys: String,
+}
+
+/// Everything compared for one vote account
+#[derive(Debug, Clone, PartialEq)]
+struct ComparedValidator {
+    account: VoteAccountInfo,
+    delinquent: bool,
+    version: Option<String>,
+    /// `None` if block production could not be loaded
+    skip_rate: Option<f64>,
+    apy: Option<f64>,
+    /// Commission paid out at the end of each of `history_epochs`
+    commission_history: Vec<Option<u8>>,
+}
+
+/// Data for the whole comparison, loaded together
+#[derive(Debug, Clone, PartialEq)]
+struct Comparison {
+    validators: Vec<ComparedValidator>,
+    /// Requested vote accounts that have no stake on this cluster
+    missing: Vec<String>,
+    /// Oldest first
+    history_epochs: Vec<u64>,
+    /// Stake-weighted mean credits of the cluster for each of `history_epochs`
+    average_credits: Vec<f64>,
+}
+
+/// Compare page component
+pub fn ComparePage(cx: Scope<ComparePageProps>) -> Element {
+    let comparison = use_state(cx, || None::<Comparison>);
+    let loading = use_state(cx, || true);
+    let error = use_state(cx, || None::<RpcError>);
+    let reload = use_state(cx, || 0u32);
+    let new_pubkey = use_state(cx, String::new);
+    let navigator = use_navigator(cx);
+    let rpc_urls = get_rpc_urls(use_cluster_store(cx));
+
+    let selected = parse_vote_pubkeys(&cx.props.vote_pubkeys);
+
+    // Load the comparison when the selection or cluster changes, or on retry
+    use_effect(cx, (&selected, &rpc_urls, reload.get()), |(selected, rpc_urls, _)| {
+        let comparison = comparison.clone();
+        let loading = loading.clone();
+        let error = error.clone();
+
+        async move {
+            loading.set(true);
+            error.set(None);
+
+            let client = SolanaApiClient::new(rpc_urls);
+            // The same requests the validators page makes, so they are usually cached
+            let (vote_accounts, cluster_nodes, snapshot) = join3(
+                client.get_vote_accounts(),
+                client.get_cluster_nodes(),
+                client.get_inflation_snapshot(),
+            )
+            .await;
+
+            match vote_accounts {
+                Ok(vote_accounts) => {
+                    let cluster_nodes = cluster_nodes.unwrap_or_else(|e| {
+                        log::warn!("Failed to load cluster nodes: {}", e);
+                        Vec::new()
+                    });
+                    let yield_model = match snapshot {
+                        Ok(snapshot) => Some(YieldModel::new(&snapshot, &vote_accounts)),
+                        Err(e) => {
+                            log::warn!("Failed to load inflation data: {}", e);
+                            None
+                        }
+                    };
+                    let data = load_comparison(
+                        &client,
+                        &selected,
+                        &vote_accounts,
+                        &cluster_nodes,
+                        yield_model.as_ref(),
+                    )
+                    .await;
+                    comparison.set(Some(data));
+                }
+                Err(e) => {
+                    comparison.set(None);
+                    error.set(Some(e));
+                }
+            }
+
+            loading.set(false);
+        }
+    });
+
+    let add_pubkey = move || {
+        let pubkey = new_pubkey.get().trim().to_string();
+        if pubkey.is_empty() {
+            return;
+        }
+        let mut vote_pubkeys = parse_vote_pubkeys(&cx.props.vote_pubkeys);
+        vote_pubkeys.push(pubkey);
+        new_pubkey.set(String::new());
+        navigator.push(compare_route(&parse_vote_pubkeys(&vote_pubkeys.join(","))));
+    };
+
+    cx.render(rsx! {
+        div { class: "compare-page",
+            div { class: "compare-header",
+                h1 { "Compare Validators" }
+                Link { class: "block-nav-link", to: Route::Validators {}, "← All validators" }
+            }
+            p { class: "text-secondary",
+                "Up to {MAX_COMPARED} vote accounts side by side. The best value in each row is highlighted."
+            }
+
+            if selected.len() < MAX_COMPARED {
+                rsx! {
+                    div { class: "compare-add",
+                        input {
+                            class: "validator-search",
+                            placeholder: "Add a vote account",
+                            value: "{new_pubkey}",
+                            oninput: move |evt| new_pubkey.set(evt.value.clone()),
+                            onkeydown: move |evt| {
+                                if evt.key() == Key::Enter {
+                                    add_pubkey();
+                                }
+                            },
+                        }
+                        button {
+                            class: "load-more-button",
+                            disabled: new_pubkey.trim().is_empty(),
+                            onclick: move |_| add_pubkey(),
+                            "Add"
+                        }
+                    }
+                }
+            }
+
+            if *loading.get() {
+                rsx! {
+                    div { class: "loading",
+                        p { "Loading validators..." }
+                    }
+                }
+            } else if let Some(err) = error.get() {
+                rsx! {
+                    div { class: "error",
+                        RpcErrorView {
+                            error: err,
+                            context: "Failed to Load Validators",
+                            on_retry: move |_| reload.set(reload.get() + 1),
+                        }
+                    }
+                }
+            } else if let Some(comparison) = comparison.get() {
+                rsx! {
+                    for pubkey in comparison.missing.iter() {
+                        p { key: "{pubkey}", class: "search-error",
+                            "{format_address(pubkey)} is not a staked vote account on this cluster"
+                        }
+                    }
+
+                    if comparison.validators.is_empty() {
+                        rsx! { p { class: "text-secondary", "No validators to compare" } }
+                    } else {
+                        rsx! {
+                            CompareTable {
+                                comparison: comparison.clone(),
+                                on_remove: move |pubkey: String| {
+                                    let remaining: Vec<String> = parse_vote_pubkeys(&cx.props.vote_pubkeys)
+                                        .into_iter()
+                                        .filter(|selected| *selected != pubkey)
+                                        .collect();
+                                    navigator.push(compare_route(&remaining));
+                                },
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    })
+}
+
+/// Join the selected vote accounts with gossip, block production, APY and
+/// commission history. Everything except the vote accounts is optional.
+async fn load_comparison(
+    client: &SolanaApiClient,
+    selected: &[String],
+    vote_accounts: &VoteAccounts,
+    cluster_nodes: &[ClusterNode],
+    yield_model: Option<&YieldModel>,
+) -> Comparison {
+    let mut found = Vec::new();
+    let mut missing = Vec::new();
+    for pubkey in selected {
+        let current = vote_accounts.current.iter().find(|account| account.vote_pubkey == *pubkey);
+        let delinquent = vote_accounts.delinquent.iter().find(|account| account.vote_pubkey == *pubkey);
+        match current.or(delinquent) {
+            Some(account) => found.push((account.clone(), current.is_none())),
+            None => missing.push(pubkey.clone()),
+        }
+    }
+
+    // Completed epochs, taken from the newest credits any selected account has
+    let latest_epoch = found
+        .iter()
+        .filter_map(|(account, _)| account.epoch_credits.last().map(|(epoch, _, _)| *epoch))
+        .max()
+        .unwrap_or(0);
+    let history_epochs: Vec<u64> = (latest_epoch.saturating_sub(HISTORY_EPOCHS)..latest_epoch).collect();
+    let average_credits = history_epochs
+        .iter()
+        .map(|&epoch| average_credits(vote_accounts, epoch))
+        .collect();
+
+    let vote_pubkeys: Vec<String> = found.iter().map(|(account, _)| account.vote_pubkey.clone()).collect();
+    let (production, rewards) = join(
+        join_all(
+            found
+                .iter()
+                .map(|(account, _)| client.get_block_production(&account.node_pubkey)),
+        ),
+        join_all(
+            history_epochs
+                .iter()
+                .map(|&epoch| client.get_inflation_rewards(&vote_pubkeys, Some(epoch))),
+        ),
+    )
+    .await;
+
+    // Epoch -> commission of each selected account, in `found` order
+    let rewards: Vec<Vec<Option<u8>>> = rewards
+        .into_iter()
+        .map(|epoch_rewards| match epoch_rewards {
+            Ok(epoch_rewards) => epoch_rewards
+                .into_iter()
+                .map(|reward| reward.and_then(|reward| reward.commission))
+                .collect(),
+            Err(e) => {
+                log::warn!("Failed to load inflation rewards: {}", e);
+                vec![None; found.len()]
+            }
+        })
+        .collect();
+
+    let versions: HashMap<&str, &str> = cluster_nodes
+        .iter()
+        .filter_map(|node| Some((node.pubkey.as_str(), node.version.as_deref()?)))
+        .collect();
+
+    let validators = found
+        .into_iter()
+        .zip(production)
+        .enumerate()
+        .map(|(index, ((account, delinquent), production))| ComparedValidator {
+            version: versions.get(account.node_pubkey.as_str()).map(|version| version.to_string()),
+            skip_rate: production.ok().and_then(|production| production.skip_rate()),
+            apy: yield_model.and_then(|model| model.estimate(&account)),
+            commission_history: rewards
+                .iter()
+                .map(|epoch| epoch.get(index).copied().flatten())
+                .collect(),
+            account,
+            delinquent,
+        })
+        .collect();
+
+    Comparison {
+        validators,
+        missing,
+        history_epochs,
+        average_credits,
+    }
+}
+
+/// Stake-weighted mean credits earned by all vote accounts in `epoch`
+fn average_credits(vote_accounts: &VoteAccounts, epoch: u64) -> f64 {
+    let (weighted, stake) = vote_accounts
+        .current
+        .iter()
+        .chain(&vote_accounts.delinquent)
+        .fold((0.0, 0.0), |(weighted, stake), account| {
+            let credits = earned_credits(account, epoch).unwrap_or(0) as f64;
+            let account_stake = account.activated_stake as f64;
+            (weighted + credits * account_stake, stake + account_stake)
+        });
+    if stake == 0.0 {
+        0.0
+    } else {
+        weighted / stake
+    }
+}
+
+/// One metric across the compared validators
+#[derive(Debug, Clone, PartialEq)]
+struct CompareRow {
+    label: String,
+    cells: Vec<String>,
+    /// Comparable score per cell, `None` where there is nothing to compare
+    scores: Vec<Option<f64>>,
+    higher_is_better: bool,
+}
+
+impl CompareRow {
+    fn new(label: impl Into<String>, higher_is_better: bool) -> Self {
+        Self {
+            label: label.into(),
+            cells: Vec::new(),
+            scores: Vec::new(),
+            higher_is_better,
+        }
+    }
+
+    fn push(&mut self, cell: String, score: Option<f64>) {
+        self.cells.push(cell);
+        self.scores.push(score);
+    }
+
+    /// Whether the cell at `index` holds the best value; ties all win, and
+    /// nothing is highlighted unless at least two values can be compared
+    fn is_best(&self, index: usize) -> bool {
+        let scores: Vec<f64> = self.scores.iter().flatten().copied().collect();
+        if scores.len() < 2 || scores.iter().all(|score| *score == scores[0]) {
+            return false;
+        }
+
+        let best = if self.higher_is_better {
+            scores.iter().copied().fold(f64::MIN, f64::max)
+        } else {
+            scores.iter().copied().fold(f64::MAX, f64::min)
+        };
+        self.scores[index] == Some(best)
+    }
+}
+
+fn build_rows(comparison: &Comparison) -> Vec<CompareRow> {
+    let validators = &comparison.validators;
+
+    let mut status = CompareRow::new("Status", true);
+    let mut stake = CompareRow::new("Active stake", true);
+    let mut commission = CompareRow::new("Commission", false);
+    let mut commission_history = CompareRow::new(
+        format!("Commission history (max, last {} epochs)", comparison.history_epochs.len()),
+        false,
+    );
+    let mut skip_rate = CompareRow::new("Skip rate (current epoch)", false);
+    let mut apy = CompareRow::new("Estimated APY", true);
+    let mut version = CompareRow::new("Software version", true);
+    let mut low_credit_epochs = CompareRow::new(
+        format!(
+            "Low-credit epochs (credits proxy: under {:.0}% of cluster average)",
+            LOW_CREDITS_RATIO * 100.0
+        ),
+        false,
+    );
+    let mut credits: Vec<CompareRow> = comparison
+        .history_epochs
+        .iter()
+        .map(|epoch| CompareRow::new(format!("Vote credits, epoch {}", epoch), true))
+        .collect();
+
+    // Newer versions score higher, ranked only against versions of the same
+    // client: the newest version of each client scores 1.0
+    let mut known_versions: Vec<&str> = validators.iter().filter_map(|validator| validator.version.as_deref()).collect();
+    known_versions.sort_by(|a, b| {
+        ValidatorClient::from_version(a)
+            .cmp(&ValidatorClient::from_version(b))
+            .then_with(|| compare_versions(a, b))
+    });
+    known_versions.dedup();
+    let version_score = |version: &str| {
+        let client = ValidatorClient::from_version(version);
+        let same_client: Vec<&str> = known_versions
+            .iter()
+            .copied()
+            .filter(|known| ValidatorClient::from_version(known) == client)
+            .collect();
+        let rank = same_client.iter().position(|known| *known == version)?;
+        Some(if same_client.len() > 1 {
+            rank as f64 / (same_client.len() - 1) as f64
+        } else {
+            1.0
+        })
+    };
+
+    for validator in validators {
+        let account = &validator.account;
+
+        if validator.delinquent {
+            status.push("Delinquent".to_string(), Some(0.0));
+        } else {
+            status.push("Active".to_string(), Some(1.0));
+        }
+
+        stake.push(
+            format!("{} SOL", format_number(account.activated_stake / 1_000_000_000)),
+            Some(account.activated_stake as f64),
+        );
+        commission.push(format!("{}%", account.commission), Some(account.commission as f64));
+
+        let history: Vec<String> = validator
+            .commission_history
+            .iter()
+            .map(|commission| commission.map(|c| format!("{}%", c)).unwrap_or_else(|| "-".to_string()))
+            .collect();
+        let max_commission = validator.commission_history.iter().flatten().max();
+        commission_history.push(history.join(" → "), max_commission.map(|c| *c as f64));
+
+        skip_rate.push(
+            validator
+                .skip_rate
+                .map(|rate| format!("{:.2}%", rate * 100.0))
+                .unwrap_or_else(|| "-".to_string()),
+            validator.skip_rate,
+        );
+        apy.push(
+            validator
+                .apy
+                .map(|apy| format!("{:.2}%", apy * 100.0))
+                .unwrap_or_else(|| "-".to_string()),
+            validator.apy,
+        );
+        version.push(
+            validator.version.clone().unwrap_or_else(|| "-".to_string()),
+            validator.version.as_deref().and_then(version_score),
+        );
+
+        let mut low_epochs = 0;
+        for ((row, &epoch), &average) in credits
+            .iter_mut()
+            .zip(&comparison.history_epochs)
+            .zip(&comparison.average_credits)
+        {
+            let earned = earned_credits(account, epoch).unwrap_or(0);
+            if (earned as f64) < average * LOW_CREDITS_RATIO {
+                low_epochs += 1;
+            }
+            row.push(format_number(earned), Some(earned as f64));
+        }
+        low_credit_epochs.push(
+            format!("{} of {}", low_epochs, comparison.history_epochs.len()),
+            Some(low_epochs as f64),
+        );
+    }
+
+    let mut rows = vec![status, stake, commission, commission_history, skip_rate];
+    rows.extend(credits);
+    rows.push(low_credit_epochs);
+    rows.push(apy);
+    rows.push(version);
+    rows
+}
+
+#[derive(Props)]
+struct CompareTableProps<'a> {
+    comparison: Comparison,
+    on_remove: EventHandler<'a, String>,
+}
+
+fn CompareTable<'a>(cx: Scope<'a, CompareTableProps<'a>>) -> Element<'a> {
+    let comparison = &cx.props.comparison;
+    let rows = build_rows(comparison);
+
+    cx.render(rsx! {
+        div { class: "compare-table-container",
+            table { class: "validator-table compare-table",
+                thead {
+                    tr {
+                        th { "Metric" }
+                        for validator in comparison.validators.iter() {
+                            th { key: "{validator.account.vote_pubkey}",
+                                div { class: "compare-column-header",
+                                    Link {
+                                        class: "mono",
+                                        to: Route::Validator { vote_pubkey: validator.account.vote_pubkey.clone() },
+                                        "{format_address(&validator.account.vote_pubkey)}"
+                                    }
+                                    button {
+                                        class: "search-clear",
+                                        onclick: move |_| cx.props.on_remove.call(validator.account.vote_pubkey.clone()),
+                                        Icon { icon: FaXmark, width: 12, height: 12, fill: "var(--text-secondary)" }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+                tbody {
+                    for row in rows.iter() {
+                        tr { key: "{row.label}",
+                            td { class: "text-secondary", "{row.label}" }
+                            for (index, cell) in row.cells.iter().enumerate() {
+                                td {
+                                    class: if row.is_best(index) { "numeric compare-best" } else { "numeric" },
+                                    "{cell}"
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::api::test_vote_account;
+
+    const SOL: u64 = 1_000_000_000;
+
+    fn validator(
+        identity: &str,
+        stake_sol: u64,
+        commission: u8,
+        credits: [u64; 2],
+        version: Option<&str>,
+    ) -> ComparedValidator {
+        let mut account = test_vote_account(identity, stake_sol * SOL);
+        account.commission = commission;
+        account.epoch_credits = vec![(500, credits[0], 0), (501, credits[0] + credits[1], credits[0])];
+        ComparedValidator {
+            account,
+            delinquent: false,
+            version: version.map(str::to_string),
+            skip_rate: None,
+            apy: None,
+            commission_history: vec![Some(commission), Some(commission)],
+        }
+    }
+
+    fn row<'a>(rows: &'a [CompareRow], label: &str) -> &'a CompareRow {
+        rows.iter().find(|row| row.label.starts_with(label)).unwrap()
+    }
+
+    fn best(row: &CompareRow) -> Vec<usize> {
+        (0..row.cells.len()).filter(|index| row.is_best(*index)).collect()
+    }
+
+    #[test]
+    fn pubkeys_are_trimmed_deduplicated_and_capped() {
+        assert_eq!(parse_vote_pubkeys(""), Vec::<String>::new());
+        assert!(matches!(compare_route(&[]), Route::Validators {}));
+        assert_eq!(parse_vote_pubkeys(" a , ,b,a,, b "), vec!["a", "b"]);
+        assert_eq!(parse_vote_pubkeys("a,b,c,d,e,f"), vec!["a", "b", "c", "d"]);
+        assert_eq!(parse_vote_pubkeys("a,b,c,d,e,f").len(), MAX_COMPARED);
+        // Duplicates do not use up a slot
+        assert_eq!(parse_vote_pubkeys("a,a,a,a,b,c,d"), vec!["a", "b", "c", "d"]);
+    }
+
+    #[test]
+    fn invalid_pubkeys_are_kept_and_reported_as_missing_later() {
+        assert_eq!(
+            parse_vote_pubkeys("not-a-pubkey,Vote111111111111111111111111111111111111111"),
+            vec!["not-a-pubkey", "Vote111111111111111111111111111111111111111"]
+        );
+    }
+
+    #[test]
+    fn ties_all_win_and_lower_can_be_better() {
+        let mut commission = CompareRow::new("Commission", false);
+        commission.push("5%".to_string(), Some(5.0));
+        commission.push("10%".to_string(), Some(10.0));
+        commission.push("5%".to_string(), Some(5.0));
+        commission.push("-".to_string(), None);
+        assert_eq!(best(&commission), vec![0, 2]);
+
+        let mut stake = CompareRow::new("Active stake", true);
+        stake.push("5".to_string(), Some(5.0));
+        stake.push("10".to_string(), Some(10.0));
+        assert_eq!(best(&stake), vec![1]);
+    }
+
+    #[test]
+    fn nothing_is_best_without_two_different_values() {
+        let mut equal = CompareRow::new("Commission", false);
+        equal.push("5%".to_string(), Some(5.0));
+        equal.push("5%".to_string(), Some(5.0));
+        assert_eq!(best(&equal), Vec::<usize>::new());
+
+        let mut single = CompareRow::new("Estimated APY", true);
+        single.push("7.00%".to_string(), Some(0.07));
+        single.push("-".to_string(), None);
+        assert_eq!(best(&single), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn rows_compare_each_metric_in_its_own_direction() {
+        let mut a = validator("a", 2_000, 5, [400_000, 400_000], Some("2.0.15"));
+        a.skip_rate = Some(0.01);
+        a.apy = Some(0.07);
+        let mut b = validator("b", 1_000, 5, [100_000, 400_000], Some("2.0.14"));
+        b.delinquent = true;
+        let mut c = validator("c", 1_500, 10, [300_000, 400_000], None);
+        c.skip_rate = Some(0.02);
+        c.commission_history = vec![Some(8), None];
+
+        let comparison = Comparison {
+            validators: vec![a, b, c],
+            missing: Vec::new(),
+            history_epochs: vec![500, 501],
+            average_credits: vec![300_000.0, 300_000.0],
+        };
+        let rows = build_rows(&comparison);
+
+        assert_eq!(best(row(&rows, "Status")), vec![0, 2]);
+        assert_eq!(best(row(&rows, "Active stake")), vec![0]);
+        assert_eq!(best(row(&rows, "Commission")), vec![0, 1]);
+        let history = row(&rows, "Commission history");
+        assert_eq!(history.cells[2], "8% → -");
+        assert_eq!(best(history), vec![0, 1]);
+        assert_eq!(best(row(&rows, "Skip rate")), vec![0]);
+        assert_eq!(best(row(&rows, "Vote credits, epoch 500")), vec![0]);
+        assert_eq!(best(row(&rows, "Vote credits, epoch 501")), Vec::<usize>::new());
+
+        let low_credits = row(&rows, "Low-credit epochs");
+        assert_eq!(low_credits.cells, vec!["0 of 2", "1 of 2", "0 of 2"]);
+        assert_eq!(best(low_credits), vec![0, 2]);
+
+        // A single estimate has nothing to be compared against
+        assert_eq!(row(&rows, "Estimated APY").cells, vec!["7.00%", "-", "-"]);
+        assert_eq!(best(row(&rows, "Estimated APY")), Vec::<usize>::new());
+        assert_eq!(best(row(&rows, "Software version")), vec![0]);
+    }
+}
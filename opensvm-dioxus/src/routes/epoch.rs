@@ -0,0 +1,268 @@
+//! Epoch page
+
+use dioxus::prelude::*;
+use dioxus_router::prelude::*;
+
+use crate::app::Route;
+use crate::components::rpc_error_view::RpcErrorView;
+use crate::stores::cluster_store::{get_rpc_urls, use_cluster_store};
+use crate::utils::address_utils::{format_number, format_timestamp};
+use crate::utils::api::{RpcError, SolanaApiClient};
+use crate::utils::epoch::{format_duration, EpochClock, EpochStatus};
+
+/// Format of the time input, as produced by `<input type="datetime-local">`
+const DATETIME_INPUT_FORMAT: &str = "%Y-%m-%dT%H:%M";
+
+#[derive(PartialEq, Props)]
+pub struct EpochPageProps {
+    pub epoch: u64,
+}
+
+/// Epoch page component
+pub fn EpochPage(cx: Scope<EpochPageProps>) -> Element {
+    let clock = use_state(cx, || None::<EpochClock>);
+    let loading = use_state(cx, || true);
+    let error = use_state(cx, || None::<RpcError>);
+    let reload = use_state(cx, || 0u32);
+    let rpc_urls = get_rpc_urls(use_cluster_store(cx));
+
+    // The epoch schedule and slot time are needed for every value on the
+    // page; reload when the cluster changes or on retry
+    use_effect(cx, (&rpc_urls, reload.get()), |(rpc_urls, _)| {
+        let clock = clock.clone();
+        let loading = loading.clone();
+        let error = error.clone();
+
+        async move {
+            loading.set(true);
+            error.set(None);
+
+            let client = SolanaApiClient::new(rpc_urls);
+            match client.get_epoch_overview().await {
+                Ok(overview) => clock.set(Some(EpochClock::new(&overview))),
+                Err(e) => {
+                    clock.set(None);
+                    error.set(Some(e));
+                }
+            }
+
+            loading.set(false);
+        }
+    });
+
+    let epoch = cx.props.epoch;
+
+    cx.render(rsx! {
+        div { class: "epoch-page",
+            div { class: "block-header",
+                h1 { "Epoch #{format_number(epoch)}" }
+                div { class: "block-nav",
+                    if epoch > 0 {
+                        rsx! {
+                            Link { class: "block-nav-link", to: Route::Epoch { epoch: epoch - 1 }, "← Previous" }
+                        }
+                    }
+                    if let Some(next) = epoch.checked_add(1) {
+                        rsx! {
+                            Link { class: "block-nav-link", to: Route::Epoch { epoch: next }, "Next →" }
+                        }
+                    }
+                }
+            }
+
+            if *loading.get() {
+                rsx! {
+                    div { class: "loading",
+                        p { "Loading epoch schedule..." }
+                    }
+                }
+            } else if let Some(err) = error.get() {
+                rsx! {
+                    div { class: "error",
+                        RpcErrorView {
+                            error: err,
+                            context: "Error Loading Epoch",
+                            on_retry: move |_| reload.set(reload.get() + 1),
+                        }
+                    }
+                }
+            } else if let Some(clock) = clock.get() {
+                rsx! {
+                    EpochOverviewSection { clock: clock.clone(), epoch: epoch }
+                    SlotTimeConverter { clock: clock.clone() }
+                }
+            }
+        }
+    })
+}
+
+#[derive(Props, PartialEq)]
+struct EpochOverviewSectionProps {
+    clock: EpochClock,
+    epoch: u64,
+}
+
+/// Slot range, estimated start and end time and, for the current epoch,
+/// progress towards the next boundary
+fn EpochOverviewSection(cx: Scope<EpochOverviewSectionProps>) -> Element {
+    let clock = &cx.props.clock;
+    let epoch = cx.props.epoch;
+    let schedule = &clock.schedule;
+    let status = clock.status(epoch);
+    let badge = match status {
+        EpochStatus::Current => "status-badge success",
+        EpochStatus::Completed | EpochStatus::Upcoming => "status-badge",
+    };
+
+    // Far enough out, the epoch's slots no longer fit in a u64
+    let range = schedule.first_slot_in_epoch(epoch).zip(schedule.last_slot_in_epoch(epoch));
+    let times = clock.epoch_start_time(epoch).zip(clock.epoch_end_time(epoch));
+    let (Some((first_slot, last_slot)), Some((start, end))) = (range, times) else {
+        return cx.render(rsx! {
+            div { class: "section",
+                h2 { "Overview" }
+                p { class: "text-secondary", "Epoch out of range: its slots are past the last slot number." }
+            }
+        });
+    };
+    let slots = schedule.slots_in_epoch(epoch);
+    let relative = |time: i64| {
+        let offset = time - clock.observed_at;
+        if offset >= 0 {
+            format!("in ~{}", format_duration(offset))
+        } else {
+            format!("~{} ago", format_duration(offset))
+        }
+    };
+    let time = |time: i64| format_timestamp(time.max(0) as u64);
+
+    cx.render(rsx! {
+        div { class: "section",
+            h2 { "Overview" }
+            div { class: "info-grid",
+                div { class: "info-item",
+                    span { class: "label", "Status:" }
+                    span { class: "{badge}", "{status.label()}" }
+                }
+                div { class: "info-item",
+                    span { class: "label", "First Slot:" }
+                    Link { class: "value mono", to: Route::Block { slot: first_slot }, "{format_number(first_slot)}" }
+                }
+                div { class: "info-item",
+                    span { class: "label", "Last Slot:" }
+                    Link { class: "value mono", to: Route::Block { slot: last_slot }, "{format_number(last_slot)}" }
+                }
+                div { class: "info-item",
+                    span { class: "label", "Slots:" }
+                    span { class: "value", "{format_number(slots)}" }
+                }
+                div { class: "info-item",
+                    span { class: "label", "Start (estimated):" }
+                    span { class: "value", "{time(start)} UTC" }
+                    span { class: "text-tertiary small", "{relative(start)}" }
+                }
+                div { class: "info-item",
+                    span { class: "label", "End (estimated):" }
+                    span { class: "value", "{time(end)} UTC" }
+                    span { class: "text-tertiary small", "{relative(end)}" }
+                }
+                div { class: "info-item",
+                    span { class: "label", "Duration (estimated):" }
+                    span { class: "value", "{format_duration(end - start)}" }
+                }
+            }
+
+            if status == EpochStatus::Current {
+                let (elapsed, total) = clock.slot_progress();
+                let percent = clock.progress() * 100.0;
+                rsx! {
+                    div { class: "epoch-progress",
+                        div { class: "epoch-progress-fill", style: "width: {percent:.1}%" }
+                    }
+                    p { class: "text-secondary small",
+                        "{percent:.2}% complete · slot {format_number(elapsed)} of {format_number(total)} · "
+                        "next epoch in ~{format_duration(clock.seconds_to_next_boundary())}"
+                    }
+                }
+            }
+            p { class: "text-tertiary small",
+                "Times are estimated from slot {format_number(clock.current_slot)} at the recent average of "
+                "{clock.slot_seconds:.3}s per slot."
+            }
+        }
+    })
+}
+
+#[derive(Props, PartialEq)]
+struct SlotTimeConverterProps {
+    clock: EpochClock,
+}
+
+/// Estimate the time of a slot, or the slot at a time
+fn SlotTimeConverter(cx: Scope<SlotTimeConverterProps>) -> Element {
+    let clock = &cx.props.clock;
+    let slot_input = use_state(cx, || clock.current_slot.to_string());
+    let time_input = use_state(cx, || {
+        chrono::DateTime::from_timestamp(clock.observed_at, 0)
+            .map(|date| date.format(DATETIME_INPUT_FORMAT).to_string())
+            .unwrap_or_default()
+    });
+
+    let slot_result = match slot_input.get().trim().replace(',', "").parse::<u64>() {
+        Ok(slot) => {
+            let (epoch, slot_index) = clock.schedule.epoch_and_slot_index(slot);
+            let time = format_timestamp(clock.slot_time(slot).max(0) as u64);
+            Some((epoch, format!("{} UTC · slot {} of epoch {}", time, format_number(slot_index), format_number(epoch))))
+        }
+        Err(_) => None,
+    };
+    let time_result = chrono::NaiveDateTime::parse_from_str(time_input.get(), DATETIME_INPUT_FORMAT)
+        .ok()
+        .map(|date| {
+            let slot = clock.slot_at(date.and_utc().timestamp());
+            let (epoch, _) = clock.schedule.epoch_and_slot_index(slot);
+            (slot, epoch)
+        });
+
+    cx.render(rsx! {
+        div { class: "section",
+            h2 { "Slot and Time" }
+            div { class: "info-grid",
+                div { class: "info-item",
+                    label { class: "label", r#for: "slot-input", "Slot:" }
+                    input {
+                        id: "slot-input",
+                        class: "converter-input",
+                        inputmode: "numeric",
+                        value: "{slot_input}",
+                        oninput: move |evt| slot_input.set(evt.value.clone()),
+                    }
+                    match &slot_result {
+                        Some((epoch, text)) => rsx! {
+                            span { class: "value", "{text}" }
+                            Link { class: "text-tertiary small", to: Route::Epoch { epoch: *epoch }, "View epoch" }
+                        },
+                        None => rsx! { span { class: "text-tertiary small", "Enter a slot number" } },
+                    }
+                }
+                div { class: "info-item",
+                    label { class: "label", r#for: "time-input", "Time (UTC):" }
+                    input {
+                        id: "time-input",
+                        class: "converter-input",
+                        r#type: "datetime-local",
+                        value: "{time_input}",
+                        oninput: move |evt| time_input.set(evt.value.clone()),
+                    }
+                    match time_result {
+                        Some((slot, epoch)) => rsx! {
+                            span { class: "value", "Slot {format_number(slot)} · epoch {format_number(epoch)}" }
+                            Link { class: "text-tertiary small", to: Route::Block { slot }, "View block" }
+                        },
+                        None => rsx! { span { class: "text-tertiary small", "Enter a date and time" } },
+                    }
+                }
+            }
+        }
+    })
+}
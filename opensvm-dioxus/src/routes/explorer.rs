@@ -1,64 +1,85 @@
 //! Explorer page
 
-use crate::utils::api::NetworkStats;
-#[cfg(feature = "desktop")]
-#[allow(unused_imports)]
-use crate::utils::api::SolanaApiClient;
-use dioxus::events::MouseData;
+use crate::components::epoch_card::EpochCard;
+use crate::components::rpc_error_view::RpcErrorView;
+use crate::components::search_bar::SearchBar;
+use crate::components::sparkline::Sparkline;
+use crate::stores::cluster_store::{get_rpc_urls, use_cluster_store};
+use crate::utils::api::retry::sleep;
+use crate::utils::api::{NetworkStats, RpcError, SolanaApiClient, PERFORMANCE_SAMPLE_COUNT};
+use crate::utils::pubsub::{use_subscription, SlotInfo, Subscription};
 use dioxus::prelude::*;
+use std::time::Duration;
+
+/// Performance samples are produced once a minute
+const PERFORMANCE_REFRESH_INTERVAL: Duration = Duration::from_secs(60);
 
 /// Explorer page component
 pub fn ExplorerPage(cx: Scope) -> Element {
     let network_stats = use_state(cx, || Option::<NetworkStats>::None);
     let loading = use_state(cx, || true);
-    let error = use_state(cx, || Option::<String>::None);
-    let search_input = use_state(cx, String::new);
+    let error = use_state(cx, || Option::<RpcError>::None);
+    let reload = use_state(cx, || 0u32);
+    let rpc_urls = get_rpc_urls(use_cluster_store(cx));
+    let slot_update = use_subscription(cx, Some(Subscription::Slot));
 
-    // Load network stats on component mount
-    use_effect(cx, (), |_| {
+    // Load network stats on component mount, when the cluster changes, or on retry
+    use_effect(cx, (&rpc_urls, reload.get()), |(rpc_urls, _)| {
         let network_stats = network_stats.to_owned();
         let loading = loading.to_owned();
         let error = error.to_owned();
+        let slot_update = slot_update.to_owned();
 
         async move {
-            #[cfg(feature = "web")]
-            {
-                match crate::utils::api::web::fetch_network_stats().await {
-                    Ok(stats) => {
-                        network_stats.set(Some(stats));
-                        loading.set(false);
-                    }
-                    Err(e) => {
-                        error.set(Some(format!("Failed to load network stats: {:?}", e)));
-                        loading.set(false);
-                    }
+            loading.set(true);
+            error.set(None);
+            // The slot subscription survives a cluster switch, so its latest
+            // slot may be the previous cluster's
+            slot_update.set(None);
+
+            let client = SolanaApiClient::new(rpc_urls);
+            match client.get_network_stats().await {
+                Ok(stats) => {
+                    // Drop slots notified by the previous cluster's connection
+                    // while the stats loaded
+                    slot_update.set(None);
+                    network_stats.set(Some(stats));
+                    loading.set(false);
+                }
+                Err(e) => {
+                    error.set(Some(e));
+                    loading.set(false);
                 }
             }
+        }
+    });
 
-            #[cfg(feature = "desktop")]
-            {
-                let client = SolanaApiClient::new();
-                match client.get_network_stats().await {
-                    Ok(stats) => {
-                        network_stats.set(Some(stats));
-                        loading.set(false);
-                    }
-                    Err(e) => {
-                        error.set(Some(format!("Failed to load network stats: {}", e)));
-                        loading.set(false);
-                    }
+    // Refresh TPS and slot time as new performance samples are produced
+    use_future(cx, (&rpc_urls,), |(rpc_urls,)| {
+        let network_stats = network_stats.to_owned();
+
+        async move {
+            let client = SolanaApiClient::new(rpc_urls);
+            loop {
+                sleep(PERFORMANCE_REFRESH_INTERVAL).await;
+                match client.get_performance_stats(PERFORMANCE_SAMPLE_COUNT).await {
+                    Ok(performance) => network_stats.with_mut(|stats| {
+                        if let Some(stats) = stats {
+                            stats.performance = performance;
+                        }
+                    }),
+                    Err(e) => log::warn!("Failed to refresh performance samples: {}", e),
                 }
             }
         }
     });
 
-    let handle_search = move |_evt: Event<MouseData>| {
-        let search_term = search_input.get().trim();
-        if !search_term.is_empty() {
-            // In a real implementation, this would navigate to account/transaction details
-            log::info!("Searching for: {}", search_term);
-        }
-    };
+    // Keep the current slot live between full reloads
+    let live_slot = slot_update
+        .get()
+        .as_ref()
+        .and_then(|value| serde_json::from_value::<SlotInfo>(value.clone()).ok())
+        .map(|info| info.slot);
 
     cx.render(rsx! {
         div { class: "explorer-page",
@@ -70,29 +91,8 @@ pub fn ExplorerPage(cx: Scope) -> Element {
                 }
 
                 // Search bar
-                div { class: "search-section",
-                    div { class: "search-container",
-                        input {
-                            class: "search-input",
-                            placeholder: "Search for addresses, transactions, blocks...",
-                            value: "{search_input}",
-                            oninput: move |evt| search_input.set(evt.value.clone()),
-                            onkeypress: move |evt| {
-                                if evt.data.key().to_string() == "Enter" {
-                                    // Simulate a mouse event for the search handler
-                                    let search_term = search_input.get().trim();
-                                    if !search_term.is_empty() {
-                                        log::info!("Searching for: {}", search_term);
-                                    }
-                                }
-                            }
-                        }
-                        button {
-                            class: "search-button",
-                            onclick: handle_search,
-                            "🔍 Search"
-                        }
-                    }
+                SearchBar {
+                    placeholder: "Search for addresses, transactions, blocks..."
                 }
             }
 
@@ -109,10 +109,26 @@ pub fn ExplorerPage(cx: Scope) -> Element {
                 } else if let Some(err) = error.get() {
                     rsx! {
                         div { class: "error",
-                            "Error: {err}"
+                            RpcErrorView {
+                                error: err,
+                                context: "Failed to Load Network Stats",
+                                on_retry: move |_| reload.set(reload.get() + 1),
+                            }
                         }
                     }
                 } else if let Some(stats) = network_stats.get() {
+                    let current_slot = live_slot.unwrap_or(stats.current_slot).max(stats.current_slot);
+                    let performance = &stats.performance;
+                    let tps_history: Vec<f64> = performance.history.iter().map(|sample| sample.tps()).collect();
+                    let non_vote_history: Vec<f64> = performance
+                        .history
+                        .iter()
+                        .filter_map(|sample| sample.non_vote_tps())
+                        .collect();
+                    let non_vote_tps = performance
+                        .non_vote_tps
+                        .map(|tps| format!("{:.0}", tps))
+                        .unwrap_or_else(|| "N/A".to_string());
                     rsx! {
                         div { class: "stats-grid",
                             div { class: "stat-card",
@@ -128,20 +144,38 @@ pub fn ExplorerPage(cx: Scope) -> Element {
                                 }
                             }
                             div { class: "stat-card",
-                                h3 { "Current Slot" }
-                                p { class: "stat-value", "{stats.current_slot}" }
-                            }
-                            div { class: "stat-card",
-                                h3 { "Current Epoch" }
-                                p { class: "stat-value", "{stats.epoch}" }
+                                h3 {
+                                    "Current Slot"
+                                    if live_slot.is_some() {
+                                        rsx! { span { class: "live-indicator", title: "Live", "●" } }
+                                    }
+                                }
+                                p { class: "stat-value", "{current_slot}" }
                             }
+                            EpochCard { epoch: stats.epoch, live_slot: live_slot }
                             div { class: "stat-card",
                                 h3 { "Validators" }
                                 p { class: "stat-value", "{stats.validator_count}" }
                             }
                             div { class: "stat-card",
                                 h3 { "Avg Slot Time" }
-                                p { class: "stat-value", "{stats.avg_slot_time:.2}s" }
+                                p { class: "stat-value", "{performance.avg_slot_time:.3}s" }
+                            }
+                            div { class: "stat-card",
+                                h3 { "TPS" }
+                                p { class: "stat-value", "{performance.tps:.0}" }
+                                Sparkline {
+                                    values: tps_history,
+                                    label: "Transactions per second, last 30 minutes",
+                                }
+                            }
+                            div { class: "stat-card",
+                                h3 { "Non-vote TPS" }
+                                p { class: "stat-value", "{non_vote_tps}" }
+                                Sparkline {
+                                    values: non_vote_history,
+                                    label: "Non-vote transactions per second, last 30 minutes",
+                                }
                             }
                         }
                     }
@@ -2,9 +2,13 @@
 
 pub mod account;
 pub mod ai;
+pub mod block;
+pub mod compare;
+pub mod epoch;
 pub mod explorer;
 pub mod not_found;
 pub mod solanow;
 pub mod transaction;
+pub mod validator;
 pub mod validators;
 pub mod wallet;
@@ -1,13 +1,465 @@
-//! SolaNow page
+//! SolaNow page: SIMD-0228 emission calculator and the cluster's inflation
+//! schedule
 
 use dioxus::prelude::*;
+use futures_util::future::join3;
+
+use crate::components::line_chart::{ChartSeries, LineChart};
+use crate::stores::cluster_store::{get_rpc_urls, use_cluster_store};
+use crate::utils::address_utils::format_number;
+use crate::utils::api::SolanaApiClient;
+use crate::utils::emission::{
+    nominal_staking_yield, project_supply, simd0228_rate, EmissionModel, InflationSchedule,
+    ProjectionPoint, YearProjection,
+};
+
+/// Years shown in the supply and yield projections
+const PROJECTION_YEARS: u64 = 10;
+
+/// Epochs between plotted projection points
+const PROJECTION_STEP: usize = 10;
+
+/// Horizons offered for the inflation schedule projection, in years
+const SCHEDULE_YEARS: [u64; 4] = [5, 10, 20, 30];
+const DEFAULT_SCHEDULE_YEARS: u64 = 10;
+
+/// Staked share range of the participation slider and charts, in percent
+const MIN_STAKED_PERCENT: u32 = 10;
+const MAX_STAKED_PERCENT: u32 = 100;
+
+/// Example values used until (or unless) the cluster answers
+const EXAMPLE_SUPPLY: f64 = 563_837_000.0;
+const EXAMPLE_STAKED_PERCENT: u32 = 65;
+const EXAMPLE_EPOCH: u64 = 750;
+
+const STATIC_COLOR: &str = "var(--text-secondary)";
+const SIMD_COLOR: &str = "var(--primary)";
+const VALIDATOR_COLOR: &str = "var(--success)";
+const FOUNDATION_COLOR: &str = "var(--error)";
+
+/// Network values the calculator starts from
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct NetworkInputs {
+    epoch: u64,
+    /// Total supply in SOL
+    supply: f64,
+    staked_percent: u32,
+}
 
 /// SolaNow page component
 pub fn SolanowPage(cx: Scope) -> Element {
+    let governed_schedule = use_state(cx, || None::<InflationSchedule>);
+    let network = use_state(cx, || None::<NetworkInputs>);
+    let loaded = use_state(cx, || false);
+    let staked_percent = use_state(cx, || EXAMPLE_STAKED_PERCENT);
+    let epoch = use_state(cx, || EXAMPLE_EPOCH);
+    let schedule_years = use_state(cx, || DEFAULT_SCHEDULE_YEARS);
+    let rpc_urls = get_rpc_urls(use_cluster_store(cx));
+
+    // Start the sliders at the cluster's current epoch and participation, and
+    // follow the cluster's inflation governor instead of the mainnet defaults
+    use_effect(cx, (&rpc_urls,), |(rpc_urls,)| {
+        let governed_schedule = governed_schedule.clone();
+        let network = network.clone();
+        let loaded = loaded.clone();
+        let staked_percent = staked_percent.clone();
+        let epoch = epoch.clone();
+
+        async move {
+            let client = SolanaApiClient::new(rpc_urls);
+            let (stats, parameters, vote_accounts) = join3(
+                client.get_network_stats(),
+                client.get_inflation_parameters(),
+                client.get_vote_accounts(),
+            )
+            .await;
+
+            match parameters {
+                Ok(parameters) => governed_schedule.set(Some(InflationSchedule::from_parameters(&parameters))),
+                Err(e) => {
+                    log::warn!("Failed to load inflation parameters, using the mainnet schedule: {}", e);
+                    governed_schedule.set(None);
+                }
+            }
+
+            match (stats, vote_accounts) {
+                (Ok(stats), Ok(vote_accounts)) => {
+                    let supply = stats.total_supply as f64;
+                    let staked = if supply > 0.0 {
+                        vote_accounts.total_stake() as f64 / supply * 100.0
+                    } else {
+                        EXAMPLE_STAKED_PERCENT as f64
+                    };
+                    let inputs = NetworkInputs {
+                        epoch: stats.epoch,
+                        supply: supply / 1_000_000_000.0,
+                        staked_percent: (staked.round() as u32)
+                            .clamp(MIN_STAKED_PERCENT, MAX_STAKED_PERCENT),
+                    };
+                    staked_percent.set(inputs.staked_percent);
+                    epoch.set(inputs.epoch);
+                    network.set(Some(inputs));
+                }
+                (Err(e), _) | (_, Err(e)) => {
+                    log::warn!("Failed to load network inputs, using example values: {}", e);
+                    network.set(None);
+                }
+            }
+            loaded.set(true);
+        }
+    });
+
+    let schedule = governed_schedule.get().unwrap_or_default();
+    let staked = *staked_percent.get() as f64 / 100.0;
+    let selected_epoch = *epoch.get();
+    let supply = network.get().map(|inputs| inputs.supply).unwrap_or(EXAMPLE_SUPPLY);
+    let current_epoch = network.get().map(|inputs| inputs.epoch).unwrap_or(EXAMPLE_EPOCH);
+    let projection_epochs = (PROJECTION_YEARS as f64 / schedule.epoch_years()).ceil() as u64;
+    let max_epoch = schedule.terminal_epoch().max(current_epoch) + projection_epochs;
+
+    let static_rate = schedule.rate_at_epoch(selected_epoch);
+    let simd_rate = simd0228_rate(static_rate, staked);
+    let static_yield = nominal_staking_yield(static_rate, staked);
+    let simd_yield = nominal_staking_yield(simd_rate, staked);
+    let years = schedule.years_at_epoch(selected_epoch);
+
+    // Issuance and yield across the participation range at the selected epoch
+    let participation: Vec<f64> = (MIN_STAKED_PERCENT..=MAX_STAKED_PERCENT)
+        .map(|percent| percent as f64 / 100.0)
+        .collect();
+    let compare_series = |static_values: Vec<f64>, simd_values: Vec<f64>| {
+        vec![
+            ChartSeries {
+                label: "Fixed schedule".to_string(),
+                values: static_values,
+                color: STATIC_COLOR,
+                dashed: true,
+            },
+            ChartSeries {
+                label: "SIMD-0228".to_string(),
+                values: simd_values,
+                color: SIMD_COLOR,
+                dashed: false,
+            },
+        ]
+    };
+    let issuance_series = compare_series(
+        participation.iter().map(|_| static_rate).collect(),
+        participation.iter().map(|&s| simd0228_rate(static_rate, s)).collect(),
+    );
+    let yield_series = compare_series(
+        participation.iter().map(|&s| nominal_staking_yield(static_rate, s)).collect(),
+        participation
+            .iter()
+            .map(|&s| nominal_staking_yield(simd0228_rate(static_rate, s), s))
+            .collect(),
+    );
+    let marker = Some((*staked_percent.get() - MIN_STAKED_PERCENT) as usize);
+
+    // Supply and yield over the coming years from the selected epoch
+    let project = |model| {
+        project_supply(model, &schedule, selected_epoch, supply, staked, projection_epochs)
+    };
+    let static_projection = project(EmissionModel::Static);
+    let simd_projection = project(EmissionModel::Simd0228);
+    let sampled = |projection: &[ProjectionPoint], value: fn(&ProjectionPoint) -> f64| {
+        projection.iter().step_by(PROJECTION_STEP).map(value).collect::<Vec<f64>>()
+    };
+    let supply_series = compare_series(
+        sampled(&static_projection, |point| point.supply),
+        sampled(&simd_projection, |point| point.supply),
+    );
+    let projected_yield_series = compare_series(
+        sampled(&static_projection, |point| point.staking_yield),
+        sampled(&simd_projection, |point| point.staking_yield),
+    );
+    let final_static = static_projection.last().map(|point| point.supply).unwrap_or(supply);
+    let final_simd = simd_projection.last().map(|point| point.supply).unwrap_or(supply);
+    let end_epoch = selected_epoch + projection_epochs;
+
+    // The governor's schedule from the start of inflation to the end of the
+    // chosen horizon, with the current epoch marked
+    let horizon_years = *schedule_years.get();
+    let yearly = schedule.project(current_epoch, supply, horizon_years);
+    let horizon_epoch = yearly.last().map(|year| year.end_epoch).unwrap_or(current_epoch);
+    let schedule_epochs: Vec<u64> = (schedule.start_epoch..=horizon_epoch).step_by(PROJECTION_STEP).collect();
+    let rate_series = |label: &str, color, dashed, rate: &dyn Fn(f64) -> f64| ChartSeries {
+        label: label.to_string(),
+        values: schedule_epochs
+            .iter()
+            .map(|&epoch| rate(schedule.years_at_epoch(epoch)))
+            .collect(),
+        color,
+        dashed,
+    };
+    let schedule_rate_series = vec![
+        rate_series("Total", SIMD_COLOR, false, &|years| schedule.rate_at_year(years)),
+        rate_series("Validators", VALIDATOR_COLOR, false, &|years| schedule.validator_rate_at_year(years)),
+        rate_series("Foundation", FOUNDATION_COLOR, true, &|years| schedule.foundation_rate_at_year(years)),
+    ];
+    let schedule_marker = Some(current_epoch.saturating_sub(schedule.start_epoch) as usize / PROJECTION_STEP);
+    let schedule_supply_series = vec![ChartSeries {
+        label: "Total supply".to_string(),
+        values: std::iter::once(supply).chain(yearly.iter().map(|year| year.end_supply)).collect(),
+        color: SIMD_COLOR,
+        dashed: false,
+    }];
+    let source = if governed_schedule.get().is_some() {
+        "getInflationGovernor"
+    } else {
+        "the mainnet-beta defaults"
+    };
+
     cx.render(rsx! {
         div { class: "solanow-page",
-            h1 { "SolaNow" }
-            p { "Real-time updates and insights from the Solana network." }
+            h1 { "SIMD-0228" }
+            p { class: "text-secondary",
+                "Market-based emission: issuance scales with the share of SOL that is staked, "
+                "instead of following the fixed disinflation schedule alone."
+            }
+
+            div { class: "section",
+                h2 { "The Formula" }
+                div { class: "formula", "i(s) = r(1 - √s + c · max(1 - √(2s), 0))" }
+                div { class: "formula", "v(s) = i(s) / s" }
+                p { class: "text-secondary small",
+                    "s is the staked share of supply, r the fixed-schedule rate for the epoch "
+                    "({schedule.initial * 100.0:.0}% tapering {schedule.taper * 100.0:.0}% a year to {schedule.terminal * 100.0:.1}%) "
+                    "and c ≈ 3.1463, which makes issuance equal r at one third staked. v(s) is the nominal staking yield."
+                }
+            }
+
+            div { class: "section",
+                h2 { "Simulation" }
+                if *loaded.get() && network.get().is_none() {
+                    rsx! {
+                        p { class: "text-tertiary small", "Cluster data unavailable, starting from example values." }
+                    }
+                }
+                div { class: "slider-group",
+                    label { r#for: "staked-slider", "Staked share of supply: {staked_percent}%" }
+                    input {
+                        id: "staked-slider",
+                        r#type: "range",
+                        min: "{MIN_STAKED_PERCENT}",
+                        max: "{MAX_STAKED_PERCENT}",
+                        step: "1",
+                        value: "{staked_percent}",
+                        oninput: move |evt| {
+                            if let Ok(value) = evt.value.parse::<u32>() {
+                                staked_percent.set(value.clamp(MIN_STAKED_PERCENT, MAX_STAKED_PERCENT));
+                            }
+                        },
+                    }
+                }
+                div { class: "slider-group",
+                    label { r#for: "epoch-slider",
+                        "Epoch: {format_number(selected_epoch)} ({years:.1} years of inflation)"
+                    }
+                    input {
+                        id: "epoch-slider",
+                        r#type: "range",
+                        min: "{schedule.start_epoch}",
+                        max: "{max_epoch}",
+                        step: "1",
+                        value: "{selected_epoch}",
+                        oninput: move |evt| {
+                            if let Ok(value) = evt.value.parse::<u64>() {
+                                epoch.set(value);
+                            }
+                        },
+                    }
+                    if selected_epoch != current_epoch {
+                        rsx! {
+                            button {
+                                class: "link-button",
+                                onclick: move |_| epoch.set(current_epoch),
+                                "Back to current epoch ({format_number(current_epoch)})"
+                            }
+                        }
+                    }
+                }
+
+                div { class: "stats-grid",
+                    ResultCard {
+                        label: "Fixed Schedule Rate",
+                        value: format!("{:.2}%", static_rate * 100.0),
+                        subvalue: "Annual issuance",
+                    }
+                    ResultCard {
+                        label: "SIMD-0228 Rate",
+                        value: format!("{:.2}%", simd_rate * 100.0),
+                        subvalue: format!("With {}% staked", staked_percent),
+                    }
+                    ResultCard {
+                        label: "Fixed Schedule Yield",
+                        value: format!("{:.2}%", static_yield * 100.0),
+                        subvalue: "Nominal, before commission",
+                    }
+                    ResultCard {
+                        label: "SIMD-0228 Yield",
+                        value: format!("{:.2}%", simd_yield * 100.0),
+                        subvalue: "Nominal, before commission",
+                    }
+                    ResultCard {
+                        label: "Issuance Avoided",
+                        value: format!("{} SOL", format_number((final_static - final_simd).max(0.0) as u64)),
+                        subvalue: format!("Over {} years", PROJECTION_YEARS),
+                    }
+                }
+            }
+
+            div { class: "section",
+                h2 { "Issuance vs. Staking Participation" }
+                LineChart {
+                    series: issuance_series,
+                    x_start: format!("{}% staked", MIN_STAKED_PERCENT),
+                    x_end: format!("{}% staked", MAX_STAKED_PERCENT),
+                    y_scale: 100.0,
+                    y_unit: "%",
+                    from_zero: true,
+                    marker: marker,
+                }
+
+                h2 { "Staking Yield vs. Staking Participation" }
+                LineChart {
+                    series: yield_series,
+                    x_start: format!("{}% staked", MIN_STAKED_PERCENT),
+                    x_end: format!("{}% staked", MAX_STAKED_PERCENT),
+                    y_scale: 100.0,
+                    y_unit: "%",
+                    from_zero: true,
+                    marker: marker,
+                }
+            }
+
+            div { class: "section",
+                h2 { "Projected Supply" }
+                p { class: "text-secondary small",
+                    "{PROJECTION_YEARS} years from epoch {format_number(selected_epoch)}, starting at today's supply of "
+                    "{format_number(supply as u64)} SOL with {staked_percent}% staked throughout."
+                }
+                LineChart {
+                    series: supply_series,
+                    x_start: format!("Epoch {}", format_number(selected_epoch)),
+                    x_end: format!("Epoch {}", format_number(end_epoch)),
+                    y_scale: 1e-6,
+                    y_unit: "M SOL",
+                    y_decimals: 1,
+                }
+
+                h2 { "Projected Staking Yield" }
+                LineChart {
+                    series: projected_yield_series,
+                    x_start: format!("Epoch {}", format_number(selected_epoch)),
+                    x_end: format!("Epoch {}", format_number(end_epoch)),
+                    y_scale: 100.0,
+                    y_unit: "%",
+                    from_zero: true,
+                }
+            }
+
+            div { class: "section",
+                h2 { "Inflation Schedule" }
+                p { class: "text-secondary small",
+                    "From {source}: {schedule.initial * 100.0:.1}% at epoch {format_number(schedule.start_epoch)}, "
+                    "tapering {schedule.taper * 100.0:.0}% a year to {schedule.terminal * 100.0:.1}% from epoch "
+                    "{format_number(schedule.terminal_epoch())}. The foundation receives {schedule.foundation * 100.0:.0}% "
+                    "of issuance for the first {schedule.foundation_term:.0} years."
+                }
+                div { class: "segmented-control",
+                    for years in SCHEDULE_YEARS {
+                        button {
+                            key: "{years}",
+                            class: if horizon_years == years { "active" } else { "" },
+                            onclick: move |_| schedule_years.set(years),
+                            "{years} years"
+                        }
+                    }
+                }
+
+                h2 { "Annual Inflation Rate" }
+                LineChart {
+                    series: schedule_rate_series,
+                    x_start: format!("Epoch {}", format_number(schedule.start_epoch)),
+                    x_end: format!("Epoch {}", format_number(horizon_epoch)),
+                    y_scale: 100.0,
+                    y_unit: "%",
+                    from_zero: true,
+                    marker: schedule_marker,
+                }
+                p { class: "text-tertiary small",
+                    "The dashed line marks the current epoch ({format_number(current_epoch)})."
+                }
+
+                h2 { "Projected Total Supply" }
+                LineChart {
+                    series: schedule_supply_series,
+                    x_start: format!("Epoch {}", format_number(current_epoch)),
+                    x_end: format!("Epoch {}", format_number(horizon_epoch)),
+                    y_scale: 1e-6,
+                    y_unit: "M SOL",
+                    y_decimals: 1,
+                }
+
+                ScheduleTable { years: yearly }
+            }
+        }
+    })
+}
+
+#[derive(Props, PartialEq)]
+struct ScheduleTableProps {
+    years: Vec<YearProjection>,
+}
+
+/// Year-by-year issuance under the fixed schedule
+fn ScheduleTable(cx: Scope<ScheduleTableProps>) -> Element {
+    cx.render(rsx! {
+        div { class: "compare-table-container",
+            table { class: "validator-table",
+                thead {
+                    tr {
+                        th { "Year" }
+                        th { "Ends at Epoch" }
+                        th { class: "numeric", "Rate" }
+                        th { class: "numeric", "Validator Issuance" }
+                        th { class: "numeric", "Foundation Issuance" }
+                        th { class: "numeric", "Total Supply" }
+                    }
+                }
+                tbody {
+                    for year in cx.props.years.iter() {
+                        tr { key: "{year.year}",
+                            td { "{year.year}" }
+                            td { "{format_number(year.end_epoch)}" }
+                            td { class: "numeric", "{year.start_rate * 100.0:.2}%" }
+                            td { class: "numeric", "{format_number(year.validator_issuance as u64)} SOL" }
+                            td { class: "numeric", "{format_number(year.foundation_issuance as u64)} SOL" }
+                            td { class: "numeric", "{format_number(year.end_supply as u64)} SOL" }
+                        }
+                    }
+                }
+            }
+        }
+    })
+}
+
+#[derive(Props, PartialEq)]
+struct ResultCardProps {
+    label: &'static str,
+    #[props(into)]
+    value: String,
+    #[props(into)]
+    subvalue: String,
+}
+
+fn ResultCard(cx: Scope<ResultCardProps>) -> Element {
+    cx.render(rsx! {
+        div { class: "stats-card",
+            span { class: "text-secondary", "{cx.props.label}" }
+            p { class: "stats-value", "{cx.props.value}" }
+            p { class: "stats-subvalue", "{cx.props.subvalue}" }
         }
     })
 }
@@ -1,7 +1,17 @@
 //! Transaction page
 
+use std::rc::Rc;
+
 use dioxus::prelude::*;
-use crate::utils::api::{TransactionDetails};
+use dioxus_router::prelude::*;
+use crate::app::Route;
+use crate::components::anchor_idl::ProgramIdl;
+use crate::components::instruction_view::InstructionTree;
+use crate::components::rpc_error_view::RpcErrorView;
+use crate::stores::cluster_store::{get_rpc_urls, use_cluster_store};
+use crate::utils::api::{RpcError, SolanaApiClient, TransactionDetails};
+use crate::utils::anchor::may_have_idl;
+use crate::utils::decoder::{DecodeContext, RawInstruction};
 
 #[derive(PartialEq, Props)]
 pub struct TransactionPageProps {
@@ -12,10 +22,12 @@ pub struct TransactionPageProps {
 pub fn TransactionPage(cx: Scope<TransactionPageProps>) -> Element {
     let transaction_data = use_state(cx, || None::<TransactionDetails>);
     let loading = use_state(cx, || true);
-    let error = use_state(cx, || None::<String>);
+    let error = use_state(cx, || None::<RpcError>);
+    let reload = use_state(cx, || 0u32);
+    let rpc_urls = get_rpc_urls(use_cluster_store(cx));
 
-    // Fetch transaction data when component mounts or transaction_id changes
-    use_effect(cx, (&cx.props.transaction_id,), |(transaction_id,)| {
+    // Fetch transaction data when component mounts, transaction_id or cluster changes, or on retry
+    use_effect(cx, (&cx.props.transaction_id, &rpc_urls, reload.get()), |(transaction_id, rpc_urls, _)| {
         let transaction_data = transaction_data.clone();
         let loading = loading.clone();
         let error = error.clone();
@@ -25,26 +37,14 @@ pub fn TransactionPage(cx: Scope<TransactionPageProps>) -> Element {
             loading.set(true);
             error.set(None);
 
-            #[cfg(feature = "web")]
-            {
-                use crate::utils::api::web::fetch_transaction;
-                match fetch_transaction(&transaction_id).await {
-                    Ok(Some(data)) => {
-                        transaction_data.set(Some(data));
-                    }
-                    Ok(None) => {
-                        error.set(Some("Transaction not found".to_string()));
-                    }
-                    Err(e) => {
-                        error.set(Some(format!("Error fetching transaction: {:?}", e)));
-                    }
+            let client = SolanaApiClient::new(rpc_urls);
+            match client.get_transaction(&transaction_id).await {
+                Ok(data) => {
+                    transaction_data.set(Some(data));
+                }
+                Err(e) => {
+                    error.set(Some(e));
                 }
-            }
-
-            #[cfg(not(feature = "web"))]
-            {
-                // For desktop/mobile, implement later or use mock data
-                error.set(Some("Transaction fetching not implemented for this platform".to_string()));
             }
 
             loading.set(false);
@@ -61,10 +61,14 @@ pub fn TransactionPage(cx: Scope<TransactionPageProps>) -> Element {
                         p { "Loading transaction..." }
                     }
                 }
-            } else if let Some(error_msg) = error.get() {
+            } else if let Some(err) = error.get() {
                 rsx! {
                     div { class: "error",
-                        p { "Error: {error_msg}" }
+                        RpcErrorView {
+                            error: err,
+                            context: "Error Loading Transaction",
+                            on_retry: move |_| reload.set(reload.get() + 1),
+                        }
                         p { "Transaction ID: {cx.props.transaction_id}" }
                     }
                 }
@@ -93,7 +97,18 @@ struct TransactionInfoProps {
 
 fn render_transaction_info(cx: Scope<TransactionInfoProps>) -> Element {
     let tx = &cx.props.transaction;
-    
+    let instructions = RawInstruction::tree_from_transaction(tx);
+    let context = Rc::new(DecodeContext::from_transaction(tx));
+
+    // Programs without a built-in decoder may be Anchor programs with an IDL
+    let mut idl_programs: Vec<String> = Vec::new();
+    for node in instructions.iter().flat_map(|node| node.iter()) {
+        let program_id = &node.instruction.program_id;
+        if may_have_idl(program_id) && !idl_programs.contains(program_id) {
+            idl_programs.push(program_id.clone());
+        }
+    }
+
     cx.render(rsx! {
         div { class: "transaction-info",
             div { class: "section",
@@ -114,7 +129,7 @@ fn render_transaction_info(cx: Scope<TransactionInfoProps>) -> Element {
                         Some(slot) => rsx! {
                             div { class: "info-item",
                                 span { class: "label", "Slot:" },
-                                span { class: "value", "{slot}" }
+                                Link { class: "value", to: Route::Block { slot }, "{slot}" }
                             }
                         },
                         None => rsx! { div {} }
@@ -164,28 +179,28 @@ fn render_transaction_info(cx: Scope<TransactionInfoProps>) -> Element {
                 }
             }
 
+            if !idl_programs.is_empty() {
+                rsx! {
+                    div { class: "section",
+                        h2 { "Program IDLs" }
+                        div { class: "program-idl-list",
+                            for program_id in idl_programs.iter() {
+                                ProgramIdl { key: "{program_id}", program_id: program_id.clone() }
+                            }
+                        }
+                    }
+                }
+            }
+
             div { class: "section",
                 h2 { "Instructions" }
                 div { class: "instruction-list",
-                    for (i, instruction) in tx.transaction.message.instructions.iter().enumerate() {
-                        div { class: "instruction-item",
-                            h3 { "Instruction #{i + 1}" },
-                            div { class: "instruction-details",
-                                div { class: "info-item",
-                                    span { class: "label", "Program:" },
-                                    span { class: "value mono", 
-                                        if let Some(program_key) = tx.transaction.message.account_keys.get(instruction.program_id_index as usize) {
-                                            program_key
-                                        } else {
-                                            "Unknown"
-                                        }
-                                    }
-                                },
-                                div { class: "info-item",
-                                    span { class: "label", "Data:" },
-                                    span { class: "value mono small", "{instruction.data}" }
-                                }
-                            }
+                    for (i, node) in instructions.into_iter().enumerate() {
+                        InstructionTree {
+                            key: "{i}",
+                            number: (i + 1).to_string(),
+                            node: node,
+                            context: context.clone(),
                         }
                     }
                 }
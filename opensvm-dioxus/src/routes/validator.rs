@@ -0,0 +1,408 @@
+//! Validator detail page
+
+use dioxus::prelude::*;
+use dioxus_router::prelude::*;
+use futures_util::future::join;
+
+use crate::app::Route;
+use crate::components::bar_chart::BarChart;
+use crate::components::rpc_error_view::RpcErrorView;
+use crate::stores::cluster_store::{get_rpc_urls, use_cluster_store};
+use crate::utils::address_utils::format_number;
+use crate::utils::api::{
+    BlockProduction, RpcError, SolanaApiClient, VoteAccountInfo, VoteAccounts, VoteState,
+};
+use crate::utils::staking_yield::{realized_yield, RealizedYield, YieldModel};
+
+/// Delegations whose last reward is used to check the APY estimate
+const SAMPLE_DELEGATIONS: usize = 20;
+
+#[derive(PartialEq, Props)]
+pub struct ValidatorPageProps {
+    pub vote_pubkey: String,
+}
+
+/// Everything shown on the page, loaded together
+#[derive(Clone)]
+struct ValidatorDetails {
+    vote_state: VoteState,
+    /// `None` if the vote account is not in `getVoteAccounts` (unstaked)
+    vote_account: Option<VoteAccountInfo>,
+    delinquent: bool,
+    total_stake: u64,
+    /// `None` if block production could not be loaded
+    block_production: Option<BlockProduction>,
+    /// `None` if inflation data could not be loaded
+    yield_model: Option<YieldModel>,
+}
+
+/// Validator detail page component
+pub fn ValidatorPage(cx: Scope<ValidatorPageProps>) -> Element {
+    let details = use_state(cx, || None::<ValidatorDetails>);
+    let loading = use_state(cx, || true);
+    let error = use_state(cx, || None::<RpcError>);
+    let reload = use_state(cx, || 0u32);
+    let rpc_urls = get_rpc_urls(use_cluster_store(cx));
+
+    // Load the vote account, stake and block production on mount, when the
+    // vote account or cluster changes, or on retry
+    use_effect(cx, (&cx.props.vote_pubkey, &rpc_urls, reload.get()), |(vote_pubkey, rpc_urls, _)| {
+        let details = details.clone();
+        let loading = loading.clone();
+        let error = error.clone();
+
+        async move {
+            loading.set(true);
+            error.set(None);
+
+            let client = SolanaApiClient::new(rpc_urls);
+            let (vote_state, vote_accounts) =
+                join(client.get_vote_state(&vote_pubkey), client.get_vote_accounts()).await;
+
+            let result = match (vote_state, vote_accounts) {
+                (Ok(vote_state), Ok(vote_accounts)) => {
+                    // Block production and inflation are secondary, so a failure
+                    // only hides the skip rate or APY
+                    let (block_production, snapshot) = join(
+                        client.get_block_production(&vote_state.node_pubkey),
+                        client.get_inflation_snapshot(),
+                    )
+                    .await;
+                    let block_production = match block_production {
+                        Ok(production) => Some(production),
+                        Err(e) => {
+                            log::warn!("Failed to load block production: {}", e);
+                            None
+                        }
+                    };
+                    let yield_model = match snapshot {
+                        Ok(snapshot) => Some(YieldModel::new(&snapshot, &vote_accounts)),
+                        Err(e) => {
+                            log::warn!("Failed to load inflation data: {}", e);
+                            None
+                        }
+                    };
+                    Ok(build_details(
+                        &vote_pubkey,
+                        vote_state,
+                        &vote_accounts,
+                        block_production,
+                        yield_model,
+                    ))
+                }
+                (Err(e), _) | (_, Err(e)) => Err(e),
+            };
+
+            match result {
+                Ok(data) => details.set(Some(data)),
+                Err(e) => {
+                    details.set(None);
+                    error.set(Some(e));
+                }
+            }
+
+            loading.set(false);
+        }
+    });
+
+    cx.render(rsx! {
+        div { class: "validator-page",
+            h1 { "Validator Details" }
+            div { class: "account-address",
+                span { class: "address-label", "Vote Account: " }
+                code { class: "address-value", "{cx.props.vote_pubkey}" }
+            }
+
+            if *loading.get() {
+                rsx! {
+                    div { class: "loading",
+                        p { "Loading validator..." }
+                    }
+                }
+            } else if let Some(err) = error.get() {
+                rsx! {
+                    div { class: "error",
+                        RpcErrorView {
+                            error: err,
+                            context: "Error Loading Validator",
+                            on_retry: move |_| reload.set(reload.get() + 1),
+                        }
+                    }
+                }
+            } else if let Some(details) = details.get() {
+                let estimate = details
+                    .vote_account
+                    .as_ref()
+                    .zip(details.yield_model.as_ref())
+                    .and_then(|(account, model)| model.estimate(account));
+
+                rsx! {
+                    ValidatorOverview { details: details }
+                    if let Some(model) = &details.yield_model {
+                        rsx! {
+                            StakingYieldSection {
+                                vote_pubkey: cx.props.vote_pubkey.clone(),
+                                estimate: estimate,
+                                epochs_per_year: model.epochs_per_year,
+                            }
+                        }
+                    }
+                    EpochCreditsSection { vote_state: &details.vote_state }
+                }
+            }
+        }
+    })
+}
+
+fn build_details(
+    vote_pubkey: &str,
+    vote_state: VoteState,
+    vote_accounts: &VoteAccounts,
+    block_production: Option<BlockProduction>,
+    yield_model: Option<YieldModel>,
+) -> ValidatorDetails {
+    let current = vote_accounts
+        .current
+        .iter()
+        .find(|account| account.vote_pubkey == vote_pubkey);
+    let delinquent = vote_accounts
+        .delinquent
+        .iter()
+        .find(|account| account.vote_pubkey == vote_pubkey);
+
+    ValidatorDetails {
+        vote_state,
+        vote_account: current.or(delinquent).cloned(),
+        delinquent: current.is_none() && delinquent.is_some(),
+        total_stake: vote_accounts.total_stake(),
+        block_production,
+        yield_model,
+    }
+}
+
+#[derive(Props)]
+struct ValidatorOverviewProps<'a> {
+    details: &'a ValidatorDetails,
+}
+
+fn ValidatorOverview<'a>(cx: Scope<'a, ValidatorOverviewProps<'a>>) -> Element<'a> {
+    let details = cx.props.details;
+    let vote_state = &details.vote_state;
+    let authorized_voter = vote_state.current_authorized_voter().unwrap_or("Unknown");
+
+    let stake = details
+        .vote_account
+        .as_ref()
+        .map(|account| account.activated_stake)
+        .unwrap_or(0);
+    let stake_share = if details.total_stake == 0 {
+        0.0
+    } else {
+        stake as f64 / details.total_stake as f64 * 100.0
+    };
+    let status = match (&details.vote_account, details.delinquent) {
+        (None, _) => ("status-badge", "Unstaked"),
+        (Some(_), true) => ("status-badge failed", "Delinquent"),
+        (Some(_), false) => ("status-badge success", "Active"),
+    };
+    let (skip_rate, leader_slots) = match &details.block_production {
+        Some(production) => (
+            production
+                .skip_rate()
+                .map(|rate| format!("{:.2}%", rate * 100.0))
+                .unwrap_or_else(|| "No leader slots yet".to_string()),
+            format!(
+                "{} of {} leader slots produced (slots {}-{})",
+                production.blocks_produced,
+                production.leader_slots,
+                production.first_slot,
+                production.last_slot
+            ),
+        ),
+        None => ("Unavailable".to_string(), String::new()),
+    };
+    let root_slot = vote_state
+        .root_slot
+        .map(format_number)
+        .unwrap_or_else(|| "None".to_string());
+
+    cx.render(rsx! {
+        div { class: "section",
+            h2 { "Overview" }
+            div { class: "info-grid",
+                div { class: "info-item",
+                    span { class: "label", "Status:" }
+                    span { class: "{status.0}", "{status.1}" }
+                }
+                div { class: "info-item",
+                    span { class: "label", "Identity:" }
+                    Link {
+                        class: "value mono",
+                        to: Route::Account { address: vote_state.node_pubkey.clone() },
+                        "{vote_state.node_pubkey}"
+                    }
+                }
+                div { class: "info-item",
+                    span { class: "label", "Authorized Voter:" }
+                    span { class: "value mono", "{authorized_voter}" }
+                }
+                div { class: "info-item",
+                    span { class: "label", "Authorized Withdrawer:" }
+                    span { class: "value mono", "{vote_state.authorized_withdrawer}" }
+                }
+                div { class: "info-item",
+                    span { class: "label", "Commission:" }
+                    span { class: "value", "{vote_state.commission}%" }
+                }
+                div { class: "info-item",
+                    span { class: "label", "Active Stake:" }
+                    span { class: "value",
+                        "{format_number(stake / 1_000_000_000)} SOL ({stake_share:.3}% of total)"
+                    }
+                }
+                div { class: "info-item",
+                    span { class: "label", "Skip Rate (current epoch):" }
+                    span { class: "value", "{skip_rate}" }
+                    if !leader_slots.is_empty() {
+                        rsx! { span { class: "text-tertiary small", "{leader_slots}" } }
+                    }
+                }
+                div { class: "info-item",
+                    span { class: "label", "Root Slot:" }
+                    span { class: "value mono", "{root_slot}" }
+                }
+            }
+        }
+    })
+}
+
+#[derive(Props, PartialEq)]
+struct StakingYieldSectionProps {
+    vote_pubkey: String,
+    /// `None` if the vote account earned no credits last epoch
+    #[props(!optional)]
+    estimate: Option<f64>,
+    epochs_per_year: f64,
+}
+
+/// Estimated APY next to the yield delegators actually received last epoch
+fn StakingYieldSection(cx: Scope<StakingYieldSectionProps>) -> Element {
+    let realized = use_state(cx, || None::<RealizedYield>);
+    let checking = use_state(cx, || true);
+    // Set when the endpoint refuses to list the vote account's delegations
+    let unlisted = use_state(cx, || false);
+    let rpc_urls = get_rpc_urls(use_cluster_store(cx));
+
+    // Sample delegations and their last reward; failures only hide the check
+    use_effect(
+        cx,
+        (&cx.props.vote_pubkey, &rpc_urls, &cx.props.epochs_per_year),
+        |(vote_pubkey, rpc_urls, epochs_per_year)| {
+            let realized = realized.clone();
+            let checking = checking.clone();
+            let unlisted = unlisted.clone();
+
+            async move {
+                checking.set(true);
+                let client = SolanaApiClient::new(rpc_urls);
+
+                // Public endpoints often reject or time out on this unpaged scan;
+                // fall back to the inflation-based estimate when they do
+                let listed = client.get_delegated_stake_accounts(&vote_pubkey).await;
+                let mut stake_accounts = match listed {
+                    Ok(stake_accounts) => stake_accounts,
+                    Err(e) => {
+                        log::info!("Delegations not listed by this endpoint: {}", e);
+                        realized.set(None);
+                        unlisted.set(true);
+                        checking.set(false);
+                        return;
+                    }
+                };
+                unlisted.set(false);
+                stake_accounts.truncate(SAMPLE_DELEGATIONS);
+
+                match client.get_inflation_rewards(&stake_accounts, None).await {
+                    Ok(rewards) => {
+                        let rewards: Vec<_> = rewards.into_iter().flatten().collect();
+                        realized.set(realized_yield(&rewards, epochs_per_year));
+                    }
+                    Err(e) => {
+                        log::warn!("Failed to load delegator rewards: {}", e);
+                        realized.set(None);
+                    }
+                }
+
+                checking.set(false);
+            }
+        },
+    );
+
+    let estimate = cx
+        .props
+        .estimate
+        .map(|apy| format!("{:.2}%", apy * 100.0))
+        .unwrap_or_else(|| "No credits last epoch".to_string());
+
+    cx.render(rsx! {
+        div { class: "section",
+            h2 { "Staking Yield" }
+            div { class: "info-grid",
+                div { class: "info-item",
+                    span { class: "label", "Estimated APY:" }
+                    span { class: "value", "{estimate}" }
+                    span { class: "text-tertiary small",
+                        "From inflation, last epoch's vote credits and commission"
+                    }
+                }
+                div { class: "info-item",
+                    span { class: "label", "Realized APY:" }
+                    if *checking.get() {
+                        rsx! { span { class: "value text-secondary", "Checking delegator rewards..." } }
+                    } else if *unlisted.get() {
+                        rsx! {
+                            span { class: "value", "{estimate}" }
+                            span { class: "text-tertiary small",
+                                "This endpoint does not list delegations; showing the estimate instead"
+                            }
+                        }
+                    } else if let Some(realized) = realized.get() {
+                        rsx! {
+                            span { class: "value", "{realized.median_apy * 100.0:.2}%" }
+                            span { class: "text-tertiary small",
+                                "Median of {realized.samples} delegations rewarded in epoch {realized.epoch}"
+                            }
+                        }
+                    } else {
+                        rsx! { span { class: "value text-secondary", "Unavailable" } }
+                    }
+                }
+            }
+        }
+    })
+}
+
+#[derive(Props)]
+struct EpochCreditsSectionProps<'a> {
+    vote_state: &'a VoteState,
+}
+
+fn EpochCreditsSection<'a>(cx: Scope<'a, EpochCreditsSectionProps<'a>>) -> Element<'a> {
+    let credits = &cx.props.vote_state.epoch_credits;
+    let values: Vec<f64> = credits.iter().map(|entry| entry.earned() as f64).collect();
+    let labels: Vec<String> = credits.iter().map(|entry| entry.epoch.to_string()).collect();
+
+    cx.render(rsx! {
+        div { class: "section",
+            h2 { "Epoch Credits" }
+            if credits.is_empty() {
+                rsx! { p { class: "text-secondary", "This vote account has not earned credits yet" } }
+            } else {
+                rsx! {
+                    p { class: "text-secondary small", "Vote credits earned per epoch" }
+                    BarChart { values: values, labels: labels, unit: " credits" }
+                }
+            }
+        }
+    })
+}
@@ -1,13 +1,135 @@
 //! Validators page
 
+use std::rc::Rc;
+
 use dioxus::prelude::*;
+use futures_util::future::join3;
+
+use crate::components::node_distribution::NodeDistributionView;
+use crate::components::rpc_error_view::RpcErrorView;
+use crate::components::validator_analytics::{RegionGlobe, ValidatorAnalytics};
+use crate::components::validator_table::ValidatorTable;
+use crate::stores::cluster_store::{get_rpc_urls, use_cluster_store};
+use crate::utils::api::{ClusterNode, InflationSnapshot, RpcError, SolanaApiClient, VoteAccounts};
+use crate::utils::geoip::GeoIpDatabase;
+use crate::utils::node_distribution::{NodeDistribution, RegionDistribution};
+use crate::utils::staking_yield::YieldModel;
 
 /// Validators page component
 pub fn ValidatorsPage(cx: Scope) -> Element {
+    let vote_accounts = use_state(cx, || None::<VoteAccounts>);
+    let cluster_nodes = use_state(cx, || None::<Vec<ClusterNode>>);
+    let inflation = use_state(cx, || None::<InflationSnapshot>);
+    let loading = use_state(cx, || true);
+    let error = use_state(cx, || None::<RpcError>);
+    let reload = use_state(cx, || 0u32);
+    let rpc_urls = get_rpc_urls(use_cluster_store(cx));
+    let geoip = use_state(cx, || None::<Rc<GeoIpDatabase>>);
+
+    // Parse the geoip table once, after the first render
+    use_effect(cx, (), |_| {
+        let geoip = geoip.to_owned();
+        async move {
+            geoip.set(Some(GeoIpDatabase::shared()));
+        }
+    });
+
+    // Load vote accounts on mount, when the cluster changes, or on retry
+    use_effect(cx, (&rpc_urls, reload.get()), |(rpc_urls, _)| {
+        let vote_accounts = vote_accounts.to_owned();
+        let cluster_nodes = cluster_nodes.to_owned();
+        let inflation = inflation.to_owned();
+        let loading = loading.to_owned();
+        let error = error.to_owned();
+
+        async move {
+            loading.set(true);
+            error.set(None);
+
+            let client = SolanaApiClient::new(rpc_urls);
+            let (accounts_result, nodes_result, inflation_result) = join3(
+                client.get_vote_accounts(),
+                client.get_cluster_nodes(),
+                client.get_inflation_snapshot(),
+            )
+            .await;
+
+            match accounts_result {
+                Ok(accounts) => vote_accounts.set(Some(accounts)),
+                Err(e) => error.set(Some(e)),
+            }
+
+            // Gossip and inflation data only feed the distribution and APY
+            // sections, so the page still renders without them
+            match nodes_result {
+                Ok(nodes) => cluster_nodes.set(Some(nodes)),
+                Err(e) => {
+                    log::warn!("Failed to load cluster nodes: {}", e);
+                    cluster_nodes.set(None);
+                }
+            }
+            match inflation_result {
+                Ok(snapshot) => inflation.set(Some(snapshot)),
+                Err(e) => {
+                    log::warn!("Failed to load inflation data: {}", e);
+                    inflation.set(None);
+                }
+            }
+
+            loading.set(false);
+        }
+    });
+
     cx.render(rsx! {
         div { class: "validators-page",
             h1 { "Solana Validators" }
             p { "Monitor validator performance metrics and network statistics." }
+
+            if *loading.get() {
+                rsx! {
+                    div { class: "loading",
+                        p { "Loading validators..." }
+                    }
+                }
+            } else if let Some(err) = error.get() {
+                rsx! {
+                    div { class: "error",
+                        RpcErrorView {
+                            error: err,
+                            context: "Failed to Load Validators",
+                            on_retry: move |_| reload.set(reload.get() + 1),
+                        }
+                    }
+                }
+            } else if let Some(accounts) = vote_accounts.get() {
+                let distributions = cluster_nodes.get().as_ref().map(|nodes| {
+                    (
+                        NodeDistribution::new(nodes, accounts),
+                        geoip
+                            .get()
+                            .as_ref()
+                            .map(|geoip| RegionDistribution::new(nodes, accounts, geoip)),
+                    )
+                });
+
+                let yield_model = inflation
+                    .get()
+                    .as_ref()
+                    .map(|snapshot| YieldModel::new(snapshot, accounts));
+
+                rsx! {
+                    ValidatorAnalytics { vote_accounts: accounts, yield_model: yield_model.clone() }
+                    if let Some((distribution, regions)) = distributions {
+                        rsx! {
+                            if let Some(regions) = regions {
+                                rsx! { RegionGlobe { regions: regions } }
+                            }
+                            NodeDistributionView { distribution: distribution }
+                        }
+                    }
+                    ValidatorTable { vote_accounts: accounts, yield_model: yield_model }
+                }
+            }
         }
     })
 }
@@ -0,0 +1,143 @@
+use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "web")]
+use web_sys::Storage;
+
+// Define the cluster options
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Cluster {
+    MainnetBeta,
+    Devnet,
+    Testnet,
+    Localnet,
+    Custom(String),
+}
+
+impl Cluster {
+    /// Built-in clusters shown in the cluster switcher
+    pub const PRESETS: [Cluster; 4] = [
+        Cluster::MainnetBeta,
+        Cluster::Devnet,
+        Cluster::Testnet,
+        Cluster::Localnet,
+    ];
+
+    /// Human readable cluster name
+    pub fn label(&self) -> &str {
+        match self {
+            Cluster::MainnetBeta => "Mainnet Beta",
+            Cluster::Devnet => "Devnet",
+            Cluster::Testnet => "Testnet",
+            Cluster::Localnet => "Localnet",
+            Cluster::Custom(_) => "Custom",
+        }
+    }
+
+    /// Stable identifier used by the cluster switcher
+    pub fn id(&self) -> &str {
+        match self {
+            Cluster::MainnetBeta => "mainnet-beta",
+            Cluster::Devnet => "devnet",
+            Cluster::Testnet => "testnet",
+            Cluster::Localnet => "localnet",
+            Cluster::Custom(_) => "custom",
+        }
+    }
+
+    /// JSON-RPC endpoint for this cluster
+    pub fn rpc_url(&self) -> String {
+        match self {
+            Cluster::MainnetBeta => "https://api.mainnet-beta.solana.com".to_string(),
+            Cluster::Devnet => "https://api.devnet.solana.com".to_string(),
+            Cluster::Testnet => "https://api.testnet.solana.com".to_string(),
+            Cluster::Localnet => "http://127.0.0.1:8899".to_string(),
+            Cluster::Custom(url) => url.clone(),
+        }
+    }
+
+    /// Ordered list of endpoints for failover, primary endpoint first
+    pub fn rpc_urls(&self) -> Vec<String> {
+        let mut urls = vec![self.rpc_url()];
+        if *self == Cluster::MainnetBeta {
+            urls.push("https://solana-rpc.publicnode.com".to_string());
+        }
+        urls
+    }
+}
+
+// Define the cluster state
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ClusterState {
+    pub cluster: Cluster,
+    /// Last custom URL entered, kept so switching presets doesn't lose it
+    #[serde(default)]
+    pub custom_url: String,
+}
+
+impl Default for ClusterState {
+    fn default() -> Self {
+        Self {
+            cluster: Cluster::MainnetBeta,
+            custom_url: String::new(),
+        }
+    }
+}
+
+// Provide the cluster state to the whole app; call once from the root component
+pub fn use_cluster_store_provider(cx: &ScopeState) {
+    use_shared_state_provider(cx, || {
+        // Try to load from local storage
+        #[cfg(feature = "web")]
+        {
+            if let Some(storage) = get_local_storage() {
+                if let Ok(Some(stored_data)) = storage.get_item("cluster-storage") {
+                    if let Ok(cluster_state) = serde_json::from_str::<ClusterState>(&stored_data) {
+                        return cluster_state;
+                    }
+                }
+            }
+        }
+        ClusterState::default()
+    });
+}
+
+// Create a hook for the cluster state
+pub fn use_cluster_store(cx: &ScopeState) -> &UseSharedState<ClusterState> {
+    use_shared_state::<ClusterState>(cx)
+        .expect("use_cluster_store_provider must be called in the root component")
+}
+
+// Helper function to get local storage
+#[cfg(feature = "web")]
+fn get_local_storage() -> Option<Storage> {
+    let window = web_sys::window()?;
+    window.local_storage().ok()?
+}
+
+// Helper function to save state to local storage
+fn save_to_local_storage(_state: &ClusterState) {
+    #[cfg(feature = "web")]
+    {
+        if let Some(storage) = get_local_storage() {
+            if let Ok(json) = serde_json::to_string(_state) {
+                let _ = storage.set_item("cluster-storage", &json);
+            }
+        }
+    }
+}
+
+// Set cluster function
+pub fn set_cluster(cluster_store: &UseSharedState<ClusterState>, cluster: Cluster) {
+    let mut state = cluster_store.write();
+    if let Cluster::Custom(url) = &cluster {
+        state.custom_url = url.clone();
+    }
+    state.cluster = cluster;
+    save_to_local_storage(&state);
+}
+
+// Get the RPC endpoints of the selected cluster, in failover order
+pub fn get_rpc_urls(cluster_store: &UseSharedState<ClusterState>) -> Vec<String> {
+    cluster_store.read().cluster.rpc_urls()
+}
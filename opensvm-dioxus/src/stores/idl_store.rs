@@ -0,0 +1,227 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+
+use crate::stores::cluster_store::{use_cluster_store, Cluster};
+use crate::utils::anchor::{Idl, IdlError};
+use crate::utils::decoder::anchor::AnchorDecoder;
+use crate::utils::decoder::DecoderRegistry;
+
+#[cfg(feature = "web")]
+use web_sys::Storage;
+
+// Where a program's IDL came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IdlSource {
+    /// JSON file imported by the user
+    Imported,
+    /// The program's on-chain IDL account
+    OnChain,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LoadedIdl {
+    pub idl: Rc<Idl>,
+    pub source: IdlSource,
+}
+
+// Define the IDL state
+pub struct IdlState {
+    /// Cluster on-chain IDLs and missing markers were looked up on
+    cluster: Option<Cluster>,
+    idls: HashMap<String, LoadedIdl>,
+    /// Programs whose on-chain IDL account was looked up and does not exist
+    missing: HashSet<String>,
+    /// JSON of imported IDLs by program id, persisted across sessions
+    imported_json: BTreeMap<String, String>,
+    /// Built-in decoders plus an Anchor decoder for every loaded IDL
+    registry: Rc<DecoderRegistry>,
+}
+
+impl Default for IdlState {
+    fn default() -> Self {
+        Self {
+            cluster: None,
+            idls: HashMap::new(),
+            missing: HashSet::new(),
+            imported_json: BTreeMap::new(),
+            registry: DecoderRegistry::shared(),
+        }
+    }
+}
+
+impl IdlState {
+    /// Whether on-chain lookups recorded here were made on `cluster`
+    pub fn is_on_cluster(&self, cluster: &Cluster) -> bool {
+        self.cluster.as_ref() == Some(cluster)
+    }
+
+    /// IDL loaded for a program
+    pub fn get(&self, program_id: &str) -> Option<&LoadedIdl> {
+        self.idls.get(program_id)
+    }
+
+    /// Whether the program is known to have no on-chain IDL
+    pub fn is_missing(&self, program_id: &str) -> bool {
+        self.missing.contains(program_id)
+    }
+
+    /// Decoders for built-in programs and programs with a loaded IDL
+    pub fn registry(&self) -> Rc<DecoderRegistry> {
+        self.registry.clone()
+    }
+
+    fn insert(&mut self, program_id: &str, idl: Idl, source: IdlSource) {
+        self.missing.remove(program_id);
+        self.idls.insert(
+            program_id.to_string(),
+            LoadedIdl {
+                idl: Rc::new(idl),
+                source,
+            },
+        );
+        self.rebuild_registry();
+    }
+
+    // On-chain IDLs only describe programs on the cluster they were read
+    // from; imported IDLs apply everywhere
+    fn set_cluster(&mut self, cluster: &Cluster) {
+        if self.is_on_cluster(cluster) {
+            return;
+        }
+        self.cluster = Some(cluster.clone());
+        self.idls.retain(|_, loaded| loaded.source == IdlSource::Imported);
+        self.missing.clear();
+        self.rebuild_registry();
+    }
+
+    // Built-in decoders take precedence over IDLs for the same program
+    fn rebuild_registry(&mut self) {
+        let mut registry = DecoderRegistry::shared().as_ref().clone();
+        for (program_id, loaded) in &self.idls {
+            if registry.get(program_id).is_none() {
+                registry.register(Rc::new(AnchorDecoder::new(program_id.clone(), loaded.idl.clone())));
+            }
+        }
+        self.registry = Rc::new(registry);
+    }
+}
+
+// Provide the IDL state to the whole app; call once from the root component,
+// after the cluster store provider
+pub fn use_idl_store_provider(cx: &ScopeState) {
+    let cluster = use_cluster_store(cx).read().cluster.clone();
+    use_shared_state_provider(cx, || {
+        // Restore imported IDLs from local storage
+        #[cfg(feature = "web")]
+        {
+            if let Some(storage) = get_local_storage() {
+                if let Ok(Some(stored_data)) = storage.get_item("idl-storage") {
+                    if let Ok(imported) = serde_json::from_str::<BTreeMap<String, String>>(&stored_data) {
+                        let mut state = IdlState {
+                            cluster: Some(cluster.clone()),
+                            ..IdlState::default()
+                        };
+                        for (program_id, json) in imported {
+                            match Idl::from_json(&json) {
+                                Ok(idl) => {
+                                    state.insert(&program_id, idl, IdlSource::Imported);
+                                    state.imported_json.insert(program_id, json);
+                                }
+                                Err(e) => log::warn!("Dropping stored IDL of {}: {}", program_id, e),
+                            }
+                        }
+                        return state;
+                    }
+                }
+            }
+        }
+        IdlState {
+            cluster: Some(cluster.clone()),
+            ..IdlState::default()
+        }
+    });
+
+    // Drop on-chain IDLs when the cluster changes
+    let idl_store = use_idl_store(cx);
+    use_effect(cx, (&cluster,), |(cluster,)| {
+        let idl_store = idl_store.clone();
+        async move {
+            if !idl_store.read().is_on_cluster(&cluster) {
+                idl_store.write().set_cluster(&cluster);
+            }
+        }
+    });
+}
+
+// Create a hook for the IDL state
+pub fn use_idl_store(cx: &ScopeState) -> &UseSharedState<IdlState> {
+    use_shared_state::<IdlState>(cx).expect("use_idl_store_provider must be called in the root component")
+}
+
+// Helper function to get local storage
+#[cfg(feature = "web")]
+fn get_local_storage() -> Option<Storage> {
+    let window = web_sys::window()?;
+    window.local_storage().ok()?
+}
+
+// Helper function to save imported IDLs to local storage
+fn save_to_local_storage(_state: &IdlState) {
+    #[cfg(feature = "web")]
+    {
+        if let Some(storage) = get_local_storage() {
+            if let Ok(json) = serde_json::to_string(&_state.imported_json) {
+                let _ = storage.set_item("idl-storage", &json);
+            }
+        }
+    }
+}
+
+// Import an IDL JSON file for a program, replacing any IDL loaded for it
+pub fn import_idl(idl_store: &UseSharedState<IdlState>, program_id: &str, json: &str) -> Result<(), IdlError> {
+    let idl = Idl::from_json(json)?;
+    if let Some(address) = idl.address.as_deref().filter(|address| *address != program_id) {
+        return Err(IdlError::new(format!("This IDL is for program {}", address)));
+    }
+
+    let mut state = idl_store.write();
+    state.insert(program_id, idl, IdlSource::Imported);
+    state.imported_json.insert(program_id.to_string(), json.to_string());
+    save_to_local_storage(&state);
+    Ok(())
+}
+
+// Forget an imported IDL; the on-chain IDL is looked up again
+pub fn remove_imported_idl(idl_store: &UseSharedState<IdlState>, program_id: &str) {
+    let mut state = idl_store.write();
+    state.idls.remove(program_id);
+    state.imported_json.remove(program_id);
+    state.rebuild_registry();
+    save_to_local_storage(&state);
+}
+
+// Record the result of looking up a program's on-chain IDL on `cluster`; an
+// imported IDL is kept over the on-chain one, and lookups finishing after a
+// cluster switch are dropped
+pub fn set_on_chain_idl(
+    idl_store: &UseSharedState<IdlState>,
+    cluster: &Cluster,
+    program_id: &str,
+    idl: Option<Idl>,
+) {
+    let mut state = idl_store.write();
+    if !state.is_on_cluster(cluster) {
+        return;
+    }
+    if state.get(program_id).is_some_and(|loaded| loaded.source == IdlSource::Imported) {
+        return;
+    }
+    match idl {
+        Some(idl) => state.insert(program_id, idl, IdlSource::OnChain),
+        None => {
+            state.missing.insert(program_id.to_string());
+        }
+    }
+}
@@ -1,4 +1,6 @@
 //! State management for the application
 
 // Re-export stores here as needed
+pub mod cluster_store;
+pub mod idl_store;
 pub mod theme_store;
@@ -19,8 +19,8 @@ pub struct SearchInputResult {
 pub fn parse_search_input(input: &str) -> SearchInputResult {
     let trimmed = input.trim();
 
-    // Check if it's a transaction signature (base58 encoded, 88 characters)
-    if trimmed.len() >= 32 && trimmed.chars().all(|c| c.is_alphanumeric()) {
+    // Check if it's a transaction signature (base58 encoded, 87-88 characters)
+    if trimmed.len() > 44 && trimmed.chars().all(|c| c.is_alphanumeric()) {
         return SearchInputResult {
             is_valid: true,
             value: trimmed.to_string(),
@@ -37,7 +37,7 @@ pub fn parse_search_input(input: &str) -> SearchInputResult {
         };
     }
 
-    // Check if it's an account address (base58 encoded)
+    // Check if it's an account address (base58 encoded, 32-44 characters)
     if trimmed.len() >= 32 && trimmed.chars().all(|c| c.is_alphanumeric()) {
         return SearchInputResult {
             is_valid: true,
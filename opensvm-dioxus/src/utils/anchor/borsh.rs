@@ -0,0 +1,316 @@
+//! Borsh values described by an Anchor IDL
+//!
+//! Anchor serializes instruction arguments and account data with Borsh:
+//! little-endian integers, u32-prefixed vectors and strings, one-byte option
+//! and enum tags, and fields in declaration order.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use super::idl::{IdlFields, IdlType, IdlTypeDef};
+use crate::utils::decoder::{DataReader, DecodeError, FieldValue};
+
+/// Deepest nesting of defined types followed, so recursive types cannot
+/// exhaust the stack
+const MAX_DEPTH: usize = 32;
+
+/// Longest byte string shown in full
+const SHOWN_BYTES: usize = 64;
+
+/// Value decoded from Borsh data
+#[derive(Debug, Clone, PartialEq)]
+pub enum BorshValue {
+    Bool(bool),
+    Unsigned(u128),
+    Signed(i128),
+    Float(f64),
+    /// 256-bit integer as big-endian hex
+    Wide(String),
+    Bytes(Vec<u8>),
+    Text(String),
+    Pubkey(String),
+    Option(Option<Box<BorshValue>>),
+    List(Vec<BorshValue>),
+    Struct(Vec<(String, BorshValue)>),
+    Tuple(Vec<BorshValue>),
+    Enum { variant: String, fields: Box<BorshValue> },
+}
+
+impl BorshValue {
+    /// Field value for display, keeping addresses linkable
+    pub fn into_field_value(self) -> FieldValue {
+        match self {
+            BorshValue::Pubkey(address) => FieldValue::Address(address),
+            BorshValue::Unsigned(value) => FieldValue::Number(value),
+            BorshValue::Text(text) => FieldValue::Text(text),
+            BorshValue::Option(Some(value)) => value.into_field_value(),
+            value => FieldValue::Text(value.to_string()),
+        }
+    }
+
+    fn is_empty_fields(&self) -> bool {
+        match self {
+            BorshValue::Struct(fields) => fields.is_empty(),
+            BorshValue::Tuple(values) => values.is_empty(),
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for BorshValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BorshValue::Bool(value) => write!(f, "{}", value),
+            BorshValue::Unsigned(value) => write!(f, "{}", value),
+            BorshValue::Signed(value) => write!(f, "{}", value),
+            BorshValue::Float(value) => write!(f, "{}", value),
+            BorshValue::Wide(hex) => write!(f, "0x{}", hex),
+            BorshValue::Bytes(bytes) if bytes.len() > SHOWN_BYTES => {
+                write!(f, "0x{}… ({} bytes)", hex(&bytes[..SHOWN_BYTES]), bytes.len())
+            }
+            BorshValue::Bytes(bytes) => write!(f, "0x{}", hex(bytes)),
+            BorshValue::Text(text) => write!(f, "{:?}", text),
+            BorshValue::Pubkey(address) => write!(f, "{}", address),
+            BorshValue::Option(None) => write!(f, "None"),
+            BorshValue::Option(Some(value)) => write!(f, "{}", value),
+            BorshValue::List(values) => write!(f, "[{}]", join(values.iter())),
+            BorshValue::Struct(fields) if fields.is_empty() => write!(f, "{{}}"),
+            BorshValue::Struct(fields) => {
+                let fields = fields.iter().map(|(name, value)| format!("{}: {}", name, value));
+                write!(f, "{{ {} }}", join(fields))
+            }
+            BorshValue::Tuple(values) => write!(f, "({})", join(values.iter())),
+            BorshValue::Enum { variant, fields } if fields.is_empty_fields() => write!(f, "{}", variant),
+            BorshValue::Enum { variant, fields } => write!(f, "{} {}", variant, fields),
+        }
+    }
+}
+
+fn join(values: impl Iterator<Item = impl ToString>) -> String {
+    values.map(|value| value.to_string()).collect::<Vec<_>>().join(", ")
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// Decode one value of type `ty`, resolving defined types in `types`
+pub fn decode(
+    ty: &IdlType,
+    types: &HashMap<String, IdlTypeDef>,
+    data: &mut DataReader,
+) -> Result<BorshValue, DecodeError> {
+    Decoder { types }.value(ty, data, 0)
+}
+
+/// Decode the fields of a struct of type `def`
+pub fn decode_def(
+    def: &IdlTypeDef,
+    types: &HashMap<String, IdlTypeDef>,
+    data: &mut DataReader,
+) -> Result<BorshValue, DecodeError> {
+    Decoder { types }.def(def, data, 0)
+}
+
+struct Decoder<'a> {
+    types: &'a HashMap<String, IdlTypeDef>,
+}
+
+impl Decoder<'_> {
+    fn value(&self, ty: &IdlType, data: &mut DataReader, depth: usize) -> Result<BorshValue, DecodeError> {
+        Ok(match ty {
+            IdlType::Bool => match data.u8()? {
+                0 => BorshValue::Bool(false),
+                1 => BorshValue::Bool(true),
+                value => return Err(DecodeError::new(format!("Invalid bool {}", value))),
+            },
+            IdlType::U8 => BorshValue::Unsigned(data.u8()?.into()),
+            IdlType::I8 => BorshValue::Signed(data.i8()?.into()),
+            IdlType::U16 => BorshValue::Unsigned(data.u16()?.into()),
+            IdlType::I16 => BorshValue::Signed(data.i16()?.into()),
+            IdlType::U32 => BorshValue::Unsigned(data.u32()?.into()),
+            IdlType::I32 => BorshValue::Signed(data.i32()?.into()),
+            IdlType::U64 => BorshValue::Unsigned(data.u64()?.into()),
+            IdlType::I64 => BorshValue::Signed(data.i64()?.into()),
+            IdlType::U128 => BorshValue::Unsigned(data.u128()?),
+            IdlType::I128 => BorshValue::Signed(data.i128()?),
+            IdlType::U256 | IdlType::I256 => {
+                let mut bytes = data.bytes(32)?.to_vec();
+                bytes.reverse();
+                BorshValue::Wide(hex(&bytes))
+            }
+            IdlType::F32 => BorshValue::Float(data.f32()?.into()),
+            IdlType::F64 => BorshValue::Float(data.f64()?),
+            IdlType::Bytes => {
+                let len = self.len(data)?;
+                BorshValue::Bytes(data.bytes(len)?.to_vec())
+            }
+            IdlType::String => {
+                let len = self.len(data)?;
+                let bytes = data.bytes(len)?;
+                BorshValue::Text(
+                    String::from_utf8(bytes.to_vec())
+                        .map_err(|_| DecodeError::new("String is not valid UTF-8"))?,
+                )
+            }
+            IdlType::Pubkey => BorshValue::Pubkey(data.pubkey()?),
+            IdlType::Vec(inner) => {
+                let len = self.len(data)?;
+                BorshValue::List(self.values(inner, len, data, depth)?)
+            }
+            IdlType::Option(inner) => match data.u8()? {
+                0 => BorshValue::Option(None),
+                1 => BorshValue::Option(Some(Box::new(self.value(inner, data, depth)?))),
+                tag => return Err(DecodeError::new(format!("Invalid option tag {}", tag))),
+            },
+            IdlType::COption(inner) => match data.u32()? {
+                0 => {
+                    // COption reserves space for the value even when absent
+                    self.value(inner, data, depth)?;
+                    BorshValue::Option(None)
+                }
+                1 => BorshValue::Option(Some(Box::new(self.value(inner, data, depth)?))),
+                tag => return Err(DecodeError::new(format!("Invalid option tag {}", tag))),
+            },
+            IdlType::Array(inner, len) => BorshValue::List(self.values(inner, *len, data, depth)?),
+            IdlType::Defined(name) => {
+                if depth >= MAX_DEPTH {
+                    return Err(DecodeError::new("Types nested too deeply"));
+                }
+                let def = self
+                    .types
+                    .get(name)
+                    .ok_or_else(|| DecodeError::new(format!("Type {} is not defined in the IDL", name)))?;
+                self.def(def, data, depth + 1)?
+            }
+        })
+    }
+
+    fn def(&self, def: &IdlTypeDef, data: &mut DataReader, depth: usize) -> Result<BorshValue, DecodeError> {
+        match def {
+            IdlTypeDef::Struct(fields) => self.fields(fields, data, depth),
+            IdlTypeDef::Enum(variants) => {
+                let tag = data.u8()?;
+                let variant = variants
+                    .get(tag as usize)
+                    .ok_or_else(|| DecodeError::new(format!("Invalid enum variant {}", tag)))?;
+                Ok(BorshValue::Enum {
+                    variant: variant.name.clone(),
+                    fields: Box::new(self.fields(&variant.fields, data, depth)?),
+                })
+            }
+            IdlTypeDef::Alias(ty) => self.value(ty, data, depth),
+        }
+    }
+
+    fn fields(&self, fields: &IdlFields, data: &mut DataReader, depth: usize) -> Result<BorshValue, DecodeError> {
+        match fields {
+            IdlFields::Named(fields) => Ok(BorshValue::Struct(
+                fields
+                    .iter()
+                    .map(|field| Ok((field.name.clone(), self.value(&field.ty, data, depth)?)))
+                    .collect::<Result<_, DecodeError>>()?,
+            )),
+            IdlFields::Tuple(types) => Ok(BorshValue::Tuple(
+                types
+                    .iter()
+                    .map(|ty| self.value(ty, data, depth))
+                    .collect::<Result<_, _>>()?,
+            )),
+        }
+    }
+
+    fn values(
+        &self,
+        ty: &IdlType,
+        len: usize,
+        data: &mut DataReader,
+        depth: usize,
+    ) -> Result<Vec<BorshValue>, DecodeError> {
+        (0..len).map(|_| self.value(ty, data, depth)).collect()
+    }
+
+    /// u32 length prefix, checked against the data left so a corrupt length
+    /// cannot make the decoder loop over empty elements
+    fn len(&self, data: &mut DataReader) -> Result<usize, DecodeError> {
+        let len = data.u32()? as usize;
+        if len > data.remaining() {
+            return Err(DecodeError::new(format!(
+                "Length {} exceeds the {} bytes left",
+                len,
+                data.remaining()
+            )));
+        }
+        Ok(len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::anchor::idl::{IdlField, IdlVariant};
+
+    /// `enum Side { Bid, Ask { price: u64 } }`
+    fn types() -> HashMap<String, IdlTypeDef> {
+        let variants = vec![
+            IdlVariant {
+                name: "Bid".to_string(),
+                fields: IdlFields::Tuple(Vec::new()),
+            },
+            IdlVariant {
+                name: "Ask".to_string(),
+                fields: IdlFields::Named(vec![IdlField {
+                    name: "price".to_string(),
+                    ty: IdlType::U64,
+                }]),
+            },
+        ];
+        HashMap::from([("Side".to_string(), IdlTypeDef::Enum(variants))])
+    }
+
+    fn decode_bytes(ty: &IdlType, data: &[u8]) -> Result<BorshValue, DecodeError> {
+        decode(ty, &types(), &mut DataReader::new(data))
+    }
+
+    #[test]
+    fn decodes_options() {
+        let ty = IdlType::Option(Box::new(IdlType::U16));
+        assert_eq!(decode_bytes(&ty, &[0]).unwrap(), BorshValue::Option(None));
+        assert_eq!(
+            decode_bytes(&ty, &[1, 0x34, 0x12]).unwrap(),
+            BorshValue::Option(Some(Box::new(BorshValue::Unsigned(0x1234))))
+        );
+        assert_eq!(decode_bytes(&ty, &[2]).unwrap_err().message, "Invalid option tag 2");
+    }
+
+    #[test]
+    fn decodes_vectors() {
+        let ty = IdlType::Vec(Box::new(IdlType::I8));
+        let value = decode_bytes(&ty, &[2, 0, 0, 0, 0xff, 3]).unwrap();
+        assert_eq!(value, BorshValue::List(vec![BorshValue::Signed(-1), BorshValue::Signed(3)]));
+        assert_eq!(value.to_string(), "[-1, 3]");
+    }
+
+    #[test]
+    fn decodes_enums() {
+        let ty = IdlType::Defined("Side".to_string());
+        assert_eq!(decode_bytes(&ty, &[0]).unwrap().to_string(), "Bid");
+
+        let mut data = vec![1];
+        data.extend(25u64.to_le_bytes());
+        assert_eq!(decode_bytes(&ty, &data).unwrap().to_string(), "Ask { price: 25 }");
+        assert_eq!(decode_bytes(&ty, &[2]).unwrap_err().message, "Invalid enum variant 2");
+    }
+
+    #[test]
+    fn length_prefixes_longer_than_the_data_are_an_error() {
+        let error = decode_bytes(&IdlType::Bytes, &[100, 0, 0, 0, 1, 2]).unwrap_err();
+        assert_eq!(error.message, "Length 100 exceeds the 2 bytes left");
+    }
+
+    #[test]
+    fn undefined_types_are_an_error() {
+        let error = decode_bytes(&IdlType::Defined("Missing".to_string()), &[0]).unwrap_err();
+        assert_eq!(error.message, "Type Missing is not defined in the IDL");
+    }
+}
@@ -0,0 +1,501 @@
+//! Anchor IDL model
+//!
+//! Anchor has written two IDL formats: the legacy one, produced before
+//! Anchor 0.30, names instructions in camelCase and leaves discriminators to
+//! be derived from names; the current one lists every discriminator and moves
+//! account and event layouts into `types`. Both are parsed into the same
+//! [`Idl`].
+
+use std::collections::HashMap;
+use std::fmt;
+use std::io::Read;
+
+use flate2::read::ZlibDecoder;
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+
+use crate::utils::decoder::DataReader;
+
+/// Length of the discriminators Anchor derives from names
+const DISCRIMINATOR_LEN: usize = 8;
+
+/// Largest decompressed IDL accepted from an on-chain IDL account
+const MAX_IDL_SIZE: u64 = 10 * 1024 * 1024;
+
+/// Program interface described by an Anchor IDL
+#[derive(Debug, Clone, PartialEq)]
+pub struct Idl {
+    /// Program name as written in the IDL, e.g. "whirlpool"
+    pub name: String,
+    /// Program id recorded in the IDL, if any
+    pub address: Option<String>,
+    pub instructions: Vec<IdlInstruction>,
+    pub accounts: Vec<IdlAccount>,
+    pub events: Vec<IdlEvent>,
+    /// Type definitions by name, including account and event layouts
+    pub types: HashMap<String, IdlTypeDef>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct IdlInstruction {
+    pub name: String,
+    pub discriminator: Vec<u8>,
+    /// Account names in order, nested account groups flattened
+    pub accounts: Vec<String>,
+    pub args: Vec<IdlField>,
+}
+
+/// Account type the program stores, identified by its discriminator
+#[derive(Debug, Clone, PartialEq)]
+pub struct IdlAccount {
+    pub name: String,
+    pub discriminator: Vec<u8>,
+}
+
+/// Event the program emits, identified by its discriminator; its layout is
+/// the type of the same name
+#[derive(Debug, Clone, PartialEq)]
+pub struct IdlEvent {
+    pub name: String,
+    pub discriminator: Vec<u8>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct IdlField {
+    pub name: String,
+    pub ty: IdlType,
+}
+
+/// Type of a field or argument
+#[derive(Debug, Clone, PartialEq)]
+pub enum IdlType {
+    Bool,
+    U8,
+    I8,
+    U16,
+    I16,
+    U32,
+    I32,
+    U64,
+    I64,
+    U128,
+    I128,
+    U256,
+    I256,
+    F32,
+    F64,
+    Bytes,
+    String,
+    Pubkey,
+    Vec(Box<IdlType>),
+    Option(Box<IdlType>),
+    /// `COption`, with a four-byte tag instead of Borsh's one byte
+    COption(Box<IdlType>),
+    Array(Box<IdlType>, usize),
+    /// Type defined by name in the IDL's `types`
+    Defined(String),
+}
+
+/// Fields of a struct or enum variant
+#[derive(Debug, Clone, PartialEq)]
+pub enum IdlFields {
+    Named(Vec<IdlField>),
+    Tuple(Vec<IdlType>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct IdlVariant {
+    pub name: String,
+    pub fields: IdlFields,
+}
+
+/// Type defined in the IDL's `types`
+#[derive(Debug, Clone, PartialEq)]
+pub enum IdlTypeDef {
+    Struct(IdlFields),
+    Enum(Vec<IdlVariant>),
+    Alias(IdlType),
+}
+
+/// Why an IDL could not be loaded
+#[derive(Debug, Clone, PartialEq)]
+pub struct IdlError {
+    pub message: String,
+}
+
+impl IdlError {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self { message: message.into() }
+    }
+}
+
+impl fmt::Display for IdlError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for IdlError {}
+
+impl Idl {
+    /// Parse an IDL in either the legacy or the current JSON format
+    pub fn from_json(json: &str) -> Result<Self, IdlError> {
+        let value: Value =
+            serde_json::from_str(json).map_err(|e| IdlError::new(format!("Invalid JSON: {}", e)))?;
+
+        let name = value
+            .pointer("/metadata/name")
+            .or_else(|| value.get("name"))
+            .and_then(Value::as_str)
+            .ok_or_else(|| IdlError::new("IDL has no program name"))?
+            .to_string();
+        let address = value
+            .get("address")
+            .or_else(|| value.pointer("/metadata/address"))
+            .and_then(Value::as_str)
+            .map(str::to_string);
+
+        let mut types = HashMap::new();
+        for ty in list(&value, "types") {
+            types.insert(str_field(ty, "name")?.to_string(), IdlTypeDef::parse(field(ty, "type")?)?);
+        }
+
+        let mut accounts = Vec::new();
+        for account in list(&value, "accounts") {
+            let name = str_field(account, "name")?;
+            // Legacy IDLs define account layouts inline
+            if let Some(ty) = account.get("type") {
+                types.insert(name.to_string(), IdlTypeDef::parse(ty)?);
+            }
+            accounts.push(IdlAccount {
+                name: name.to_string(),
+                discriminator: discriminator(account, "account", name)?,
+            });
+        }
+
+        let mut events = Vec::new();
+        for event in list(&value, "events") {
+            let name = str_field(event, "name")?;
+            // Legacy IDLs list event fields inline
+            if let Some(fields) = event.get("fields") {
+                types.insert(name.to_string(), IdlTypeDef::Struct(IdlFields::parse(Some(fields))?));
+            }
+            events.push(IdlEvent {
+                name: name.to_string(),
+                discriminator: discriminator(event, "event", name)?,
+            });
+        }
+
+        let instructions = list(&value, "instructions")
+            .iter()
+            .map(IdlInstruction::parse)
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            name,
+            address,
+            instructions,
+            accounts,
+            events,
+            types,
+        })
+    }
+
+    /// Parse the IDL stored in a program's on-chain IDL account: a
+    /// discriminator, the authority, a u32 length and zlib-compressed JSON
+    pub fn from_account_data(data: &[u8]) -> Result<Self, IdlError> {
+        let mut reader = DataReader::new(data);
+        let not_idl_account = |_| IdlError::new("Not an Anchor IDL account");
+        let tag = reader.bytes(DISCRIMINATOR_LEN).map_err(not_idl_account)?;
+        if tag != hash_discriminator("account:IdlAccount") {
+            return Err(IdlError::new("Not an Anchor IDL account"));
+        }
+        reader.pubkey().map_err(not_idl_account)?;
+        let len = reader.u32().map_err(not_idl_account)?;
+        let compressed = reader
+            .bytes(len as usize)
+            .map_err(|_| IdlError::new("IDL account data is truncated"))?;
+
+        let mut json = String::new();
+        ZlibDecoder::new(compressed)
+            .take(MAX_IDL_SIZE)
+            .read_to_string(&mut json)
+            .map_err(|e| IdlError::new(format!("Could not decompress IDL: {}", e)))?;
+        Self::from_json(&json)
+    }
+
+    /// Instruction whose discriminator starts `data`
+    pub fn instruction(&self, data: &[u8]) -> Option<&IdlInstruction> {
+        self.instructions
+            .iter()
+            .filter(|instruction| matches_discriminator(&instruction.discriminator, data))
+            .max_by_key(|instruction| instruction.discriminator.len())
+    }
+
+    /// Account type whose discriminator starts `data`
+    pub fn account(&self, data: &[u8]) -> Option<&IdlAccount> {
+        self.accounts
+            .iter()
+            .filter(|account| matches_discriminator(&account.discriminator, data))
+            .max_by_key(|account| account.discriminator.len())
+    }
+
+    /// Event whose discriminator starts `data`
+    pub fn event(&self, data: &[u8]) -> Option<&IdlEvent> {
+        self.events
+            .iter()
+            .filter(|event| matches_discriminator(&event.discriminator, data))
+            .max_by_key(|event| event.discriminator.len())
+    }
+}
+
+impl IdlInstruction {
+    fn parse(value: &Value) -> Result<Self, IdlError> {
+        let name = str_field(value, "name")?;
+        let mut accounts = Vec::new();
+        flatten_accounts(list(value, "accounts"), None, &mut accounts)?;
+
+        Ok(Self {
+            name: name.to_string(),
+            discriminator: discriminator(value, "global", &snake_case(name))?,
+            accounts,
+            args: list(value, "args").iter().map(IdlField::parse).collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+impl IdlField {
+    fn parse(value: &Value) -> Result<Self, IdlError> {
+        Ok(Self {
+            name: str_field(value, "name")?.to_string(),
+            ty: IdlType::parse(field(value, "type")?)?,
+        })
+    }
+}
+
+impl IdlType {
+    fn parse(value: &Value) -> Result<Self, IdlError> {
+        if let Some(name) = value.as_str() {
+            return Ok(match name {
+                "bool" => IdlType::Bool,
+                "u8" => IdlType::U8,
+                "i8" => IdlType::I8,
+                "u16" => IdlType::U16,
+                "i16" => IdlType::I16,
+                "u32" => IdlType::U32,
+                "i32" => IdlType::I32,
+                "u64" => IdlType::U64,
+                "i64" => IdlType::I64,
+                "u128" => IdlType::U128,
+                "i128" => IdlType::I128,
+                "u256" => IdlType::U256,
+                "i256" => IdlType::I256,
+                "f32" => IdlType::F32,
+                "f64" => IdlType::F64,
+                "bytes" => IdlType::Bytes,
+                "string" => IdlType::String,
+                "publicKey" | "pubkey" => IdlType::Pubkey,
+                other => return Err(IdlError::new(format!("Unsupported type \"{}\"", other))),
+            });
+        }
+
+        if let Some(inner) = value.get("vec") {
+            Ok(IdlType::Vec(Box::new(Self::parse(inner)?)))
+        } else if let Some(inner) = value.get("option") {
+            Ok(IdlType::Option(Box::new(Self::parse(inner)?)))
+        } else if let Some(inner) = value.get("coption") {
+            Ok(IdlType::COption(Box::new(Self::parse(inner)?)))
+        } else if let Some(array) = value.get("array") {
+            match array.as_array().map(Vec::as_slice) {
+                Some([inner, len]) => {
+                    let len = len
+                        .as_u64()
+                        .ok_or_else(|| IdlError::new("Generic array lengths are not supported"))?;
+                    Ok(IdlType::Array(Box::new(Self::parse(inner)?), len as usize))
+                }
+                _ => Err(IdlError::new("Invalid array type")),
+            }
+        } else if let Some(defined) = value.get("defined") {
+            // Legacy IDLs name the type directly, current ones in an object
+            // that may also carry generic arguments
+            if defined.get("generics").and_then(Value::as_array).is_some_and(|g| !g.is_empty()) {
+                return Err(IdlError::new("Generic types are not supported"));
+            }
+            defined
+                .as_str()
+                .or_else(|| defined.get("name").and_then(Value::as_str))
+                .map(|name| IdlType::Defined(name.to_string()))
+                .ok_or_else(|| IdlError::new("Invalid defined type"))
+        } else if value.get("generic").is_some() {
+            Err(IdlError::new("Generic types are not supported"))
+        } else {
+            Err(IdlError::new(format!("Unsupported type {}", value)))
+        }
+    }
+}
+
+impl IdlFields {
+    /// Named fields, positional fields, or none for unit variants
+    fn parse(value: Option<&Value>) -> Result<Self, IdlError> {
+        let fields = value.and_then(Value::as_array).map(Vec::as_slice).unwrap_or_default();
+        if fields.iter().all(|field| field.get("type").is_some()) {
+            Ok(IdlFields::Named(fields.iter().map(IdlField::parse).collect::<Result<_, _>>()?))
+        } else {
+            Ok(IdlFields::Tuple(fields.iter().map(IdlType::parse).collect::<Result<_, _>>()?))
+        }
+    }
+}
+
+impl IdlTypeDef {
+    fn parse(value: &Value) -> Result<Self, IdlError> {
+        match str_field(value, "kind")? {
+            "struct" => Ok(IdlTypeDef::Struct(IdlFields::parse(value.get("fields"))?)),
+            "enum" => {
+                let variants = list(value, "variants")
+                    .iter()
+                    .map(|variant| {
+                        Ok(IdlVariant {
+                            name: str_field(variant, "name")?.to_string(),
+                            fields: IdlFields::parse(variant.get("fields"))?,
+                        })
+                    })
+                    .collect::<Result<_, IdlError>>()?;
+                Ok(IdlTypeDef::Enum(variants))
+            }
+            "type" => Ok(IdlTypeDef::Alias(IdlType::parse(field(value, "alias")?)?)),
+            kind => Err(IdlError::new(format!("Unsupported type kind \"{}\"", kind))),
+        }
+    }
+}
+
+/// Append the names of an instruction's accounts, prefixing accounts of
+/// nested groups with the group name
+fn flatten_accounts(accounts: &[Value], group: Option<&str>, names: &mut Vec<String>) -> Result<(), IdlError> {
+    for account in accounts {
+        let name = str_field(account, "name")?;
+        let name = match group {
+            Some(group) => format!("{} › {}", group, title_case(name)),
+            None => title_case(name),
+        };
+        match account.get("accounts").and_then(Value::as_array) {
+            Some(nested) => flatten_accounts(nested, Some(&name), names)?,
+            None => names.push(name),
+        }
+    }
+    Ok(())
+}
+
+/// Discriminator listed in the IDL, or the one Anchor derives from the
+/// namespaced name when the IDL predates explicit discriminators
+fn discriminator(value: &Value, namespace: &str, name: &str) -> Result<Vec<u8>, IdlError> {
+    match value.get("discriminator") {
+        Some(discriminator) => serde_json::from_value(discriminator.clone())
+            .map_err(|_| IdlError::new(format!("Invalid discriminator for \"{}\"", name))),
+        None => Ok(hash_discriminator(&format!("{}:{}", namespace, name))),
+    }
+}
+
+/// First eight bytes of the SHA-256 of `preimage`
+fn hash_discriminator(preimage: &str) -> Vec<u8> {
+    Sha256::digest(preimage.as_bytes())[..DISCRIMINATOR_LEN].to_vec()
+}
+
+fn matches_discriminator(discriminator: &[u8], data: &[u8]) -> bool {
+    !discriminator.is_empty() && data.starts_with(discriminator)
+}
+
+fn field<'a>(value: &'a Value, key: &str) -> Result<&'a Value, IdlError> {
+    value
+        .get(key)
+        .ok_or_else(|| IdlError::new(format!("Missing \"{}\" in {}", key, value)))
+}
+
+fn str_field<'a>(value: &'a Value, key: &str) -> Result<&'a str, IdlError> {
+    field(value, key)?
+        .as_str()
+        .ok_or_else(|| IdlError::new(format!("\"{}\" is not a string", key)))
+}
+
+/// Array under `key`, empty if absent
+fn list<'a>(value: &'a Value, key: &str) -> &'a [Value] {
+    value.get(key).and_then(Value::as_array).map(Vec::as_slice).unwrap_or_default()
+}
+
+/// Split a camelCase or snake_case name into lowercase words
+fn words(name: &str) -> Vec<String> {
+    let chars: Vec<char> = name.chars().collect();
+    let mut words = Vec::new();
+    let mut word = String::new();
+    for (i, &c) in chars.iter().enumerate() {
+        if c == '_' || c == '-' || c == ' ' {
+            if !word.is_empty() {
+                words.push(std::mem::take(&mut word));
+            }
+            continue;
+        }
+        if c.is_uppercase() && !word.is_empty() {
+            let previous = chars[i - 1];
+            let next_lower = chars.get(i + 1).is_some_and(|next| next.is_lowercase());
+            // "createATA" -> create, ata; "ATAAccount" -> ata, account
+            if previous.is_lowercase() || previous.is_ascii_digit() || (previous.is_uppercase() && next_lower) {
+                words.push(std::mem::take(&mut word));
+            }
+        }
+        word.extend(c.to_lowercase());
+    }
+    if !word.is_empty() {
+        words.push(word);
+    }
+    words
+}
+
+/// snake_case form of a name, as Anchor hashes instruction names
+pub fn snake_case(name: &str) -> String {
+    words(name).join("_")
+}
+
+/// Title Case form of a name for display, e.g. "swapExactIn" -> "Swap Exact In"
+pub fn title_case(name: &str) -> String {
+    words(name)
+        .iter()
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn snake_case_matches_anchor_instruction_names() {
+        assert_eq!(snake_case("swapExactIn"), "swap_exact_in");
+        assert_eq!(snake_case("createATA"), "create_ata");
+        assert_eq!(snake_case("ATAAccount"), "ata_account");
+        assert_eq!(snake_case("swapV2"), "swap_v2");
+        assert_eq!(snake_case("already_snake"), "already_snake");
+        assert_eq!(snake_case("Initialize"), "initialize");
+    }
+
+    #[test]
+    fn title_case_splits_words() {
+        assert_eq!(title_case("swapExactIn"), "Swap Exact In");
+        assert_eq!(title_case("create_ata"), "Create Ata");
+        assert_eq!(title_case("ATAAccount"), "Ata Account");
+    }
+
+    #[test]
+    fn legacy_instructions_hash_their_snake_case_name() {
+        let idl = Idl::from_json(
+            r#"{ "name": "counter", "instructions": [{ "name": "incrementBy", "accounts": [], "args": [] }] }"#,
+        )
+        .unwrap();
+        assert_eq!(idl.instructions[0].discriminator, hash_discriminator("global:increment_by"));
+    }
+}
@@ -0,0 +1,112 @@
+//! Anchor programs
+//!
+//! Anchor programs describe their instructions and accounts in an IDL. The
+//! IDL is either imported by the user as JSON or read from the IDL account
+//! Anchor publishes at an address derived from the program id.
+
+pub mod borsh;
+pub mod idl;
+
+pub use idl::{Idl, IdlError};
+
+use curve25519_dalek::edwards::CompressedEdwardsY;
+use sha2::{Digest, Sha256};
+
+use crate::utils::decoder::DecoderRegistry;
+
+/// Seed of the IDL account, relative to the program's signer address
+const IDL_SEED: &str = "anchor:idl";
+
+/// Widely used programs that are not written with Anchor and have no IDL
+/// account, so looking one up is wasted work
+const NON_ANCHOR_PROGRAMS: &[&str] = &[
+    // Associated Token Account
+    "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL",
+    // Memo v1 and v2
+    "Memo1UhkJRfHyvLMcVucJwxXeuD728EqVDDwQDxFMNo",
+    "MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr",
+    // BPF loaders
+    "BPFLoader1111111111111111111111111111111111",
+    "BPFLoader2111111111111111111111111111111111",
+    "BPFLoaderUpgradeab1e11111111111111111111111",
+    "LoaderV411111111111111111111111111111111111",
+    // Signature verification precompiles
+    "Ed25519SigVerify111111111111111111111111111",
+    "KeccakSecp256k11111111111111111111111111111",
+    "Secp256r1SigVerify1111111111111111111111111",
+    // Config and Feature programs
+    "Config1111111111111111111111111111111111111",
+    "Feature111111111111111111111111111111111111",
+    // SPL Stake Pool, Name Service and Metaplex Token Metadata
+    "SPoo1Ku8WFXoNDMHPsrGSTSG1Y47rzgn41SLUNakuHy",
+    "namesLPneVptA9Z5rqUDD9tMTWEJwofgaYwp8cawRkX",
+    "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s",
+];
+
+/// Whether a program may be an Anchor program with an IDL: programs with a
+/// built-in decoder and known non-Anchor programs are not
+pub fn may_have_idl(program_id: &str) -> bool {
+    DecoderRegistry::shared().get(program_id).is_none() && !NON_ANCHOR_PROGRAMS.contains(&program_id)
+}
+
+/// Address of a program's on-chain IDL account, or `None` if `program_id` is
+/// not a valid address
+pub fn idl_address(program_id: &str) -> Option<String> {
+    let program_id = decode_pubkey(program_id)?;
+    let (base, _) = find_program_address(&[], &program_id)?;
+    let address = create_with_seed(&base, IDL_SEED, &program_id);
+    Some(bs58::encode(address).into_string())
+}
+
+fn decode_pubkey(address: &str) -> Option<[u8; 32]> {
+    bs58::decode(address).into_vec().ok()?.try_into().ok()
+}
+
+/// Program derived address of `seeds` and its bump seed: the first hash,
+/// trying bumps from 255 down, that is not a point on the ed25519 curve
+fn find_program_address(seeds: &[&[u8]], program_id: &[u8; 32]) -> Option<([u8; 32], u8)> {
+    (0..=u8::MAX).rev().find_map(|bump| {
+        let mut hasher = Sha256::new();
+        for seed in seeds {
+            hasher.update(seed);
+        }
+        hasher.update([bump]);
+        hasher.update(program_id);
+        hasher.update(b"ProgramDerivedAddress");
+        let address: [u8; 32] = hasher.finalize().into();
+        let on_curve = CompressedEdwardsY(address).decompress().is_some();
+        (!on_curve).then_some((address, bump))
+    })
+}
+
+/// Address of an account created with `create_account_with_seed`
+fn create_with_seed(base: &[u8; 32], seed: &str, owner: &[u8; 32]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(base);
+    hasher.update(seed.as_bytes());
+    hasher.update(owner);
+    hasher.finalize().into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn known_non_anchor_programs_have_no_idl() {
+        assert!(!may_have_idl("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"));
+        assert!(!may_have_idl("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr"));
+        assert!(!may_have_idl("11111111111111111111111111111111"));
+        assert!(may_have_idl("JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4"));
+    }
+
+    #[test]
+    fn idl_address_matches_anchor() {
+        // IDL account of Jupiter Aggregator v6, as `anchor idl fetch` reads it
+        assert_eq!(
+            idl_address("JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4").as_deref(),
+            Some("C88XWfp26heEmDkmfSzeXP7Fd7GQJ2j9dDTUsyiZbUTa")
+        );
+        assert_eq!(idl_address("not an address"), None);
+    }
+}
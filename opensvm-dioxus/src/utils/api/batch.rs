@@ -0,0 +1,223 @@
+//! JSON-RPC batch requests
+//!
+//! Several calls are sent as one JSON array and the responses are matched
+//! back to typed results by request id:
+//!
+//! ```ignore
+//! let mut batch = RpcBatch::new();
+//! let slot = batch.add::<u64>("getSlot", vec![]);
+//! let mut results = client.send_batch(batch).await?;
+//! let slot = results.take(slot)?;
+//! ```
+
+use std::collections::HashMap;
+use std::marker::PhantomData;
+
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use super::error::RpcError;
+use super::types::{JsonRpcRequest, JsonRpcResponse};
+
+/// Typed reference to one call inside an [`RpcBatch`]
+#[derive(Debug)]
+pub struct BatchHandle<R> {
+    id: u64,
+    method: &'static str,
+    _result: PhantomData<fn() -> R>,
+}
+
+/// A set of JSON-RPC calls sent in a single HTTP request
+#[derive(Debug, Default)]
+pub struct RpcBatch {
+    requests: Vec<JsonRpcRequest<'static>>,
+}
+
+impl RpcBatch {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queue a call whose result deserializes into `R`
+    pub fn add<R: DeserializeOwned>(
+        &mut self,
+        method: &'static str,
+        params: Vec<Value>,
+    ) -> BatchHandle<R> {
+        let id = self.requests.len() as u64 + 1;
+        self.requests.push(JsonRpcRequest {
+            jsonrpc: "2.0",
+            id,
+            method,
+            params,
+        });
+
+        BatchHandle {
+            id,
+            method,
+            _result: PhantomData,
+        }
+    }
+
+    /// The queued calls, in order
+    pub(crate) fn requests(&self) -> &[JsonRpcRequest<'static>] {
+        &self.requests
+    }
+}
+
+/// Results of an [`RpcBatch`], keyed by request id
+#[derive(Debug, Default)]
+pub struct BatchResults {
+    results: HashMap<u64, Result<Value, RpcError>>,
+}
+
+impl BatchResults {
+    /// Parse a batch response body
+    ///
+    /// Endpoints that reject the whole batch answer with a single error
+    /// object instead of an array; that error is returned directly.
+    pub(crate) fn parse(body: &str) -> Result<Self, RpcError> {
+        let responses: Vec<JsonRpcResponse> = match serde_json::from_str::<Value>(body)? {
+            Value::Array(items) => items
+                .into_iter()
+                .map(serde_json::from_value)
+                .collect::<Result<_, _>>()?,
+            single => {
+                let response: JsonRpcResponse = serde_json::from_value(single)?;
+                return Err(response.error.map(RpcError::from).unwrap_or_else(|| {
+                    RpcError::Decode("Expected an array in batch response".to_string())
+                }));
+            }
+        };
+
+        let mut results = HashMap::with_capacity(responses.len());
+        for response in responses {
+            let Some(id) = response.id else {
+                continue;
+            };
+            let result = match response.error {
+                Some(error) => Err(RpcError::from(error)),
+                None => Ok(response.result),
+            };
+            results.insert(id, result);
+        }
+
+        Ok(Self { results })
+    }
+
+    pub(crate) fn insert(&mut self, id: u64, result: Result<Value, RpcError>) {
+        self.results.insert(id, result);
+    }
+
+    pub(crate) fn get(&self, id: u64) -> Option<&Result<Value, RpcError>> {
+        self.results.get(&id)
+    }
+
+    pub(crate) fn extend(&mut self, other: BatchResults) {
+        self.results.extend(other.results);
+    }
+
+    /// Whether any call in the batch was rate limited
+    pub(crate) fn rate_limited(&self) -> Option<RpcError> {
+        self.results.values().find_map(|result| match result {
+            Err(error @ RpcError::RateLimited { .. }) => Some(error.clone()),
+            _ => None,
+        })
+    }
+
+    /// Take the typed result of one call
+    pub fn take<R: DeserializeOwned>(&mut self, handle: BatchHandle<R>) -> Result<R, RpcError> {
+        let result = self.results.remove(&handle.id).ok_or_else(|| {
+            RpcError::Decode(format!("Missing batch response for {}", handle.method))
+        })?;
+
+        Ok(serde_json::from_value(result?)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn responses_are_matched_by_id_not_position() {
+        let mut batch = RpcBatch::new();
+        let slot = batch.add::<u64>("getSlot", vec![]);
+        let height = batch.add::<u64>("getBlockHeight", vec![]);
+        assert_eq!(batch.requests().len(), 2);
+
+        let body = r#"[
+            {"jsonrpc":"2.0","id":2,"result":230000000},
+            {"jsonrpc":"2.0","id":1,"result":250000000}
+        ]"#;
+        let mut results = BatchResults::parse(body).unwrap();
+        assert_eq!(results.take(height), Ok(230_000_000));
+        assert_eq!(results.take(slot), Ok(250_000_000));
+    }
+
+    #[test]
+    fn missing_responses_name_the_method() {
+        let mut batch = RpcBatch::new();
+        let slot = batch.add::<u64>("getSlot", vec![]);
+        let height = batch.add::<u64>("getBlockHeight", vec![]);
+
+        let mut results = BatchResults::parse(r#"[{"jsonrpc":"2.0","id":1,"result":7}]"#).unwrap();
+        assert_eq!(results.take(slot), Ok(7));
+        assert_eq!(
+            results.take(height),
+            Err(RpcError::Decode("Missing batch response for getBlockHeight".to_string()))
+        );
+    }
+
+    #[test]
+    fn entry_errors_only_fail_their_own_call() {
+        let mut batch = RpcBatch::new();
+        let slot = batch.add::<u64>("getSlot", vec![]);
+        let block = batch.add::<Value>("getBlock", vec![Value::from(5u64)]);
+        let supply = batch.add::<Value>("getSupply", vec![]);
+
+        let body = r#"[
+            {"jsonrpc":"2.0","id":1,"result":9},
+            {"jsonrpc":"2.0","id":2,"error":{"code":-32007,"message":"Slot 5 was skipped"}},
+            {"jsonrpc":"2.0","id":3,"error":{"code":429,"message":"Too many requests"}}
+        ]"#;
+        let mut results = BatchResults::parse(body).unwrap();
+        assert_eq!(
+            results.rate_limited(),
+            Some(RpcError::RateLimited { retry_after: None })
+        );
+        assert_eq!(results.take(slot), Ok(9));
+        assert_eq!(
+            results.take(block),
+            Err(RpcError::JsonRpc {
+                code: -32007,
+                message: "Slot 5 was skipped".to_string(),
+                data: None,
+            })
+        );
+        assert_eq!(results.take(supply), Err(RpcError::RateLimited { retry_after: None }));
+    }
+
+    #[test]
+    fn rejected_batches_return_the_single_error() {
+        let body = r#"{
+            "jsonrpc":"2.0",
+            "id":null,
+            "error":{"code":-32600,"message":"Batch requests are disabled"}
+        }"#;
+        assert_eq!(
+            BatchResults::parse(body).unwrap_err(),
+            RpcError::JsonRpc {
+                code: -32600,
+                message: "Batch requests are disabled".to_string(),
+                data: None,
+            }
+        );
+
+        assert!(matches!(
+            BatchResults::parse(r#"{"jsonrpc":"2.0","id":1,"result":3}"#),
+            Err(RpcError::Decode(_))
+        ));
+        assert!(matches!(BatchResults::parse("<html>"), Err(RpcError::Decode(_))));
+    }
+}
@@ -0,0 +1,265 @@
+//! Response cache with per-method TTLs and in-flight request deduplication
+//!
+//! The cache lives for the lifetime of the app (per thread), so navigating
+//! between pages reuses results fetched by earlier pages.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::time::Duration;
+
+use futures_util::future::{LocalBoxFuture, Shared};
+use serde_json::Value;
+
+use super::error::RpcError;
+
+/// Upper bound on cached responses before old entries are evicted
+const MAX_ENTRIES: usize = 512;
+
+/// Identifies a cacheable request
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CacheKey {
+    /// Primary endpoint of the cluster, so clusters never share entries
+    pub cluster: String,
+    pub method: String,
+    pub params: String,
+    pub commitment: Option<String>,
+}
+
+impl CacheKey {
+    pub fn new(cluster: &str, method: &str, params: &[Value]) -> Self {
+        Self {
+            cluster: cluster.to_string(),
+            method: method.to_string(),
+            params: Value::from(params.to_vec()).to_string(),
+            commitment: commitment_of(params),
+        }
+    }
+}
+
+/// Commitment level from the trailing config object of `params`, if any
+fn commitment_of(params: &[Value]) -> Option<String> {
+    params
+        .last()
+        .and_then(|config| config.get("commitment"))
+        .and_then(Value::as_str)
+        .map(str::to_string)
+}
+
+/// How long a response stays valid
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Ttl {
+    Forever,
+    For(Duration),
+}
+
+/// TTL for a successful response, or `None` if it must not be cached
+///
+/// Immutable data (finalized transactions and blocks) is kept forever, while
+/// fast-moving data such as the current slot is only cached briefly. A
+/// transaction or block fetched at `confirmed` counts as finalized once its
+/// slot is at or below the cluster's last recorded finalized slot.
+pub fn ttl_for(key: &CacheKey, result: &Value) -> Option<Ttl> {
+    let finalized = key.commitment.as_deref() == Some("finalized")
+        || slot_of(key, result).is_some_and(|slot| finalized_slot(&key.cluster).is_some_and(|root| slot <= root));
+
+    match key.method.as_str() {
+        // A missing transaction or block may still land, so never cache `null`
+        "getTransaction" | "getBlock" if result.is_null() => None,
+        "getTransaction" | "getBlock" if finalized => Some(Ttl::Forever),
+        "getTransaction" | "getBlock" => Some(Ttl::For(Duration::from_secs(300))),
+        "getSlot" | "getBlockHeight" | "getEpochInfo" => Some(Ttl::For(Duration::from_secs(2))),
+        "getAccountInfo" | "getBalance" | "getSignaturesForAddress" | "getSupply"
+        | "getVoteAccounts" | "getRecentPerformanceSamples" | "getBlockProduction" => {
+            Some(Ttl::For(Duration::from_secs(30)))
+        }
+        "getClusterNodes" | "getInflationRate" | "getProgramAccounts" => {
+            Some(Ttl::For(Duration::from_secs(300)))
+        }
+        "getEpochSchedule" | "getInflationGovernor" | "getGenesisHash" | "getSlotLeaders"
+        | "getInflationReward" => {
+            Some(Ttl::For(Duration::from_secs(3600)))
+        }
+        _ => Some(Ttl::For(Duration::from_secs(5))),
+    }
+}
+
+/// Slot a transaction or block response belongs to
+fn slot_of(key: &CacheKey, result: &Value) -> Option<u64> {
+    match key.method.as_str() {
+        "getTransaction" => result.get("slot")?.as_u64(),
+        "getBlock" => serde_json::from_str::<Vec<Value>>(&key.params).ok()?.first()?.as_u64(),
+        _ => None,
+    }
+}
+
+struct CacheEntry {
+    value: Value,
+    inserted_ms: i64,
+    expires_ms: Option<i64>,
+}
+
+/// A request currently on the wire, shared by every caller that asks for it
+pub type InFlight = Shared<LocalBoxFuture<'static, Result<Value, RpcError>>>;
+
+thread_local! {
+    static RESPONSES: RefCell<HashMap<CacheKey, CacheEntry>> = RefCell::new(HashMap::new());
+    static IN_FLIGHT: RefCell<HashMap<CacheKey, InFlight>> = RefCell::new(HashMap::new());
+    /// Highest finalized slot seen per cluster
+    static FINALIZED_SLOTS: RefCell<HashMap<String, u64>> = RefCell::new(HashMap::new());
+}
+
+fn now_ms() -> i64 {
+    chrono::Utc::now().timestamp_millis()
+}
+
+/// Look up a cached response that has not expired yet
+pub fn get(key: &CacheKey) -> Option<Value> {
+    RESPONSES.with(|responses| {
+        let responses = responses.borrow();
+        let entry = responses.get(key)?;
+        match entry.expires_ms {
+            Some(expires_ms) if expires_ms <= now_ms() => None,
+            _ => Some(entry.value.clone()),
+        }
+    })
+}
+
+/// Cache a successful response according to [`ttl_for`]
+pub fn store(key: &CacheKey, value: &Value) {
+    let Some(ttl) = ttl_for(key, value) else {
+        return;
+    };
+
+    let now = now_ms();
+    let expires_ms = match ttl {
+        Ttl::Forever => None,
+        Ttl::For(duration) => Some(now + duration.as_millis() as i64),
+    };
+
+    RESPONSES.with(|responses| {
+        let mut responses = responses.borrow_mut();
+        if responses.len() >= MAX_ENTRIES && !responses.contains_key(key) {
+            evict(&mut responses, now);
+        }
+        responses.insert(
+            key.clone(),
+            CacheEntry {
+                value: value.clone(),
+                inserted_ms: now,
+                expires_ms,
+            },
+        );
+    });
+}
+
+/// Drop expired entries, then the oldest entry if the cache is still full
+fn evict(responses: &mut HashMap<CacheKey, CacheEntry>, now: i64) {
+    responses.retain(|_, entry| entry.expires_ms.is_none_or(|expires_ms| expires_ms > now));
+
+    if responses.len() >= MAX_ENTRIES {
+        let oldest = responses
+            .iter()
+            .min_by_key(|(_, entry)| entry.inserted_ms)
+            .map(|(key, _)| key.clone());
+        if let Some(oldest) = oldest {
+            responses.remove(&oldest);
+        }
+    }
+}
+
+/// Highest finalized slot recorded for `cluster`
+pub fn finalized_slot(cluster: &str) -> Option<u64> {
+    FINALIZED_SLOTS.with(|slots| slots.borrow().get(cluster).copied())
+}
+
+/// Record that `cluster` has finalized `slot`, and keep cached transactions
+/// and blocks at or below it forever
+pub fn record_finalized_slot(cluster: &str, slot: u64) {
+    let advanced = FINALIZED_SLOTS.with(|slots| {
+        let mut slots = slots.borrow_mut();
+        let root = slots.entry(cluster.to_string()).or_default();
+        let advanced = slot > *root;
+        *root = (*root).max(slot);
+        advanced
+    });
+    if !advanced {
+        return;
+    }
+
+    RESPONSES.with(|responses| {
+        for (key, entry) in responses.borrow_mut().iter_mut() {
+            if key.cluster == cluster
+                && entry.expires_ms.is_some()
+                && ttl_for(key, &entry.value) == Some(Ttl::Forever)
+            {
+                entry.expires_ms = None;
+            }
+        }
+    });
+}
+
+/// The in-flight request for `key`, if one is running
+pub fn in_flight(key: &CacheKey) -> Option<InFlight> {
+    IN_FLIGHT.with(|in_flight| in_flight.borrow().get(key).cloned())
+}
+
+/// Register a request so identical concurrent requests can join it
+pub fn start_in_flight(key: &CacheKey, request: InFlight) {
+    IN_FLIGHT.with(|in_flight| {
+        in_flight.borrow_mut().insert(key.clone(), request);
+    });
+}
+
+/// Forget a finished in-flight request, unless a newer one replaced it
+pub fn finish_in_flight(key: &CacheKey, request: &InFlight) {
+    IN_FLIGHT.with(|in_flight| {
+        let mut in_flight = in_flight.borrow_mut();
+        if in_flight.get(key).is_some_and(|current| current.ptr_eq(request)) {
+            in_flight.remove(key);
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn transaction_key(cluster: &str) -> CacheKey {
+        let params = [
+            Value::from("5h6xBEauJ3PK6SWCZ1PGjBvj8vDdWG3KpwATGy1ARAXFSDwt8GFXM7W5Ncn16wmqokgpiKRLuS83KUxyZyv2sUYv"),
+            serde_json::json!({ "encoding": "json", "commitment": "confirmed" }),
+        ];
+        CacheKey::new(cluster, "getTransaction", &params)
+    }
+
+    #[test]
+    fn confirmed_transactions_are_kept_once_finalized() {
+        let key = transaction_key("https://api.test-finalized.invalid");
+        let result = serde_json::json!({ "slot": 250_000_000u64 });
+        assert_eq!(ttl_for(&key, &result), Some(Ttl::For(Duration::from_secs(300))));
+
+        store(&key, &result);
+        record_finalized_slot(&key.cluster, 249_999_999);
+        assert_eq!(ttl_for(&key, &result), Some(Ttl::For(Duration::from_secs(300))));
+
+        record_finalized_slot(&key.cluster, 250_000_031);
+        assert_eq!(ttl_for(&key, &result), Some(Ttl::Forever));
+        let expires_ms = RESPONSES.with(|responses| responses.borrow().get(&key).map(|entry| entry.expires_ms));
+        assert_eq!(expires_ms, Some(None));
+    }
+
+    #[test]
+    fn finalized_slot_is_per_cluster_and_never_moves_back() {
+        record_finalized_slot("https://api.test-a.invalid", 100);
+        record_finalized_slot("https://api.test-a.invalid", 90);
+        assert_eq!(finalized_slot("https://api.test-a.invalid"), Some(100));
+        assert_eq!(finalized_slot("https://api.test-b.invalid"), None);
+
+        let key = CacheKey::new(
+            "https://api.test-b.invalid",
+            "getBlock",
+            &[Value::from(50u64), serde_json::json!({ "commitment": "confirmed" })],
+        );
+        assert_eq!(ttl_for(&key, &serde_json::json!({})), Some(Ttl::For(Duration::from_secs(300))));
+    }
+}
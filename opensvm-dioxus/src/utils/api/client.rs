@@ -0,0 +1,584 @@
+//! Typed Solana JSON-RPC client shared by every platform
+
+use std::cell::Cell;
+
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use futures_util::FutureExt;
+
+use super::batch::{BatchResults, RpcBatch};
+use super::cache::{self, CacheKey};
+use super::error::RpcError;
+use super::health;
+use super::retry::{sleep, RetryPolicy};
+use super::transport::{DefaultTransport, RpcTransport};
+use super::types::*;
+
+/// Performance samples (one per minute) fetched for throughput stats
+pub const PERFORMANCE_SAMPLE_COUNT: usize = 30;
+
+/// Byte offset of the voter pubkey in a delegated stake account
+const STAKE_VOTER_OFFSET: usize = 124;
+
+/// JSON-RPC error codes for skipped, pruned or not yet available slots
+const BLOCK_NOT_AVAILABLE: [i64; 4] = [-32004, -32007, -32009, -32014];
+
+/// Solana RPC client built on top of an [`RpcTransport`]
+///
+/// Requests are retried with backoff according to the [`RetryPolicy`] and
+/// fail over across `endpoints` in order, skipping endpoints that recently
+/// failed. Responses are cached and identical concurrent requests are merged
+/// (see [`super::cache`]).
+#[derive(Clone)]
+pub struct SolanaApiClient<T = DefaultTransport> {
+    transport: T,
+    endpoints: Vec<String>,
+    retry_policy: RetryPolicy,
+    next_id: Cell<u64>,
+}
+
+impl SolanaApiClient {
+    /// Create a client for `endpoints` using the platform transport
+    ///
+    /// Pages pass the RPC URLs of the cluster selected in the cluster store.
+    pub fn new(endpoints: Vec<String>) -> Self {
+        Self::with_transport(DefaultTransport::default(), endpoints)
+    }
+}
+
+impl<T: RpcTransport + Clone + 'static> SolanaApiClient<T> {
+    /// Create a client with an explicit transport and ordered endpoint list
+    pub fn with_transport(transport: T, endpoints: Vec<String>) -> Self {
+        Self {
+            transport,
+            endpoints,
+            retry_policy: RetryPolicy::default(),
+            next_id: Cell::new(1),
+        }
+    }
+
+    /// Replace the retry policy
+    #[allow(dead_code)]
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Primary endpoint, used to keep cache entries of different clusters apart
+    fn cluster_id(&self) -> &str {
+        self.endpoints.first().map(String::as_str).unwrap_or_default()
+    }
+
+    /// Make a JSON-RPC request and deserialize its result
+    ///
+    /// Served from the response cache when possible; otherwise joins an
+    /// identical request already in flight or starts a new one.
+    pub async fn request<R>(&self, method: &str, params: Vec<Value>) -> Result<R, RpcError>
+    where
+        R: DeserializeOwned,
+    {
+        let key = CacheKey::new(self.cluster_id(), method, &params);
+        if let Some(result) = cache::get(&key) {
+            return Ok(serde_json::from_value(result)?);
+        }
+
+        let request = match cache::in_flight(&key) {
+            Some(request) => request,
+            None => {
+                let id = self.next_id.get();
+                self.next_id.set(id.wrapping_add(1));
+
+                let body = serde_json::to_string(&JsonRpcRequest {
+                    jsonrpc: "2.0",
+                    id,
+                    method,
+                    params,
+                })?;
+
+                let client = self.clone();
+                let request = async move {
+                    client.send_with_retry(&body, parse_single_response).await
+                }
+                .boxed_local()
+                .shared();
+                cache::start_in_flight(&key, request.clone());
+                request
+            }
+        };
+
+        let result = request.clone().await;
+        cache::finish_in_flight(&key, &request);
+
+        let result = result?;
+        cache::store(&key, &result);
+        Ok(serde_json::from_value(result)?)
+    }
+
+    /// Send several calls in one JSON-RPC batch request
+    ///
+    /// Calls with a cached response are answered locally and left out of the
+    /// batch. Per-call errors are reported when the result is taken from the
+    /// returned [`BatchResults`].
+    pub async fn send_batch(&self, batch: RpcBatch) -> Result<BatchResults, RpcError> {
+        let mut results = BatchResults::default();
+        let mut pending = Vec::new();
+        for request in batch.requests() {
+            let key = CacheKey::new(self.cluster_id(), request.method, &request.params);
+            match cache::get(&key) {
+                Some(result) => results.insert(request.id, Ok(result)),
+                None => pending.push((request, key)),
+            }
+        }
+
+        if pending.is_empty() {
+            return Ok(results);
+        }
+
+        let requests: Vec<_> = pending.iter().map(|(request, _)| request).collect();
+        let body = serde_json::to_string(&requests)?;
+        let fetched = self
+            .send_with_retry(&body, |body| {
+                let results = BatchResults::parse(&body)?;
+                // Retry the whole batch if the endpoint throttled any call in it
+                match results.rate_limited() {
+                    Some(error) => Err(error),
+                    None => Ok(results),
+                }
+            })
+            .await?;
+
+        for (request, key) in &pending {
+            if let Some(Ok(result)) = fetched.get(request.id) {
+                cache::store(key, result);
+            }
+        }
+        results.extend(fetched);
+
+        Ok(results)
+    }
+
+    /// Send a serialized request, retrying and failing over on retryable errors
+    ///
+    /// `parse` turns a successful HTTP response body into the caller's result.
+    async fn send_with_retry<X>(
+        &self,
+        body: &str,
+        parse: impl Fn(String) -> Result<X, RpcError>,
+    ) -> Result<X, RpcError> {
+        let policy = &self.retry_policy;
+        let mut attempt = 0;
+
+        loop {
+            let endpoint = health::pick_endpoint(&self.endpoints).ok_or_else(|| {
+                RpcError::Transport("No RPC endpoint configured".to_string())
+            })?;
+
+            let error = match self.send_once(endpoint, body, &parse).await {
+                Ok(result) => {
+                    health::record_success(endpoint);
+                    return Ok(result);
+                }
+                Err(error) if error.is_retryable() => error,
+                Err(error) => return Err(error),
+            };
+
+            let retry_after = error.retry_after();
+            health::record_failure(endpoint, policy.endpoint_cooldown, retry_after);
+            log::warn!("RPC request to {} failed: {}", endpoint, error);
+
+            attempt += 1;
+            if attempt >= policy.max_attempts
+                || retry_after.is_some_and(|delay| delay > policy.max_retry_after)
+            {
+                return Err(error);
+            }
+
+            // Fail over immediately if another endpoint is available,
+            // otherwise back off before hitting the same endpoint again
+            let has_fallback = self
+                .endpoints
+                .iter()
+                .any(|e| e != endpoint && !health::is_cooling_down(e));
+            if !has_fallback {
+                sleep(policy.delay_for(attempt - 1, retry_after)).await;
+            }
+        }
+    }
+
+    /// Send a serialized request to a single endpoint
+    async fn send_once<X>(
+        &self,
+        endpoint: &str,
+        body: &str,
+        parse: impl Fn(String) -> Result<X, RpcError>,
+    ) -> Result<X, RpcError> {
+        let response = self.transport.post_json(endpoint, body.to_string()).await?;
+        if response.status == 429 {
+            return Err(RpcError::RateLimited {
+                retry_after: response.retry_after,
+            });
+        }
+        if !response.is_success() {
+            return Err(RpcError::HttpStatus {
+                status: response.status,
+                body: response.body,
+            });
+        }
+
+        parse(response.body)
+    }
+
+    /// Get account information
+    pub async fn get_account_info(
+        &self,
+        address: &str,
+    ) -> Result<Option<AccountInfo>, RpcError> {
+        let params = vec![
+            Value::String(address.to_string()),
+            serde_json::json!({
+                "encoding": "base64",
+                "commitment": "confirmed"
+            }),
+        ];
+
+        let response: RpcResponse<Option<AccountInfo>> =
+            self.request("getAccountInfo", params).await?;
+        Ok(response.value)
+    }
+
+    /// Get transaction details, failing with [`RpcError::NotFound`] if the
+    /// signature is unknown to the cluster
+    pub async fn get_transaction(
+        &self,
+        signature: &str,
+    ) -> Result<TransactionDetails, RpcError> {
+        let params = vec![
+            Value::String(signature.to_string()),
+            serde_json::json!({
+                "encoding": "json",
+                "commitment": "confirmed",
+                "maxSupportedTransactionVersion": 0
+            }),
+        ];
+
+        let transaction: Option<TransactionDetails> =
+            self.request("getTransaction", params).await?;
+        let transaction = transaction.ok_or_else(|| RpcError::NotFound("Transaction".to_string()))?;
+        if let Some(slot) = transaction.slot {
+            self.check_finalized(slot).await;
+        }
+        Ok(transaction)
+    }
+
+    /// Get a block with full transaction details and rewards, failing with
+    /// [`RpcError::NotFound`] if the slot was skipped or is not available
+    pub async fn get_block(&self, slot: u64) -> Result<BlockDetails, RpcError> {
+        let params = vec![
+            Value::from(slot),
+            serde_json::json!({
+                "encoding": "json",
+                "transactionDetails": "full",
+                "rewards": true,
+                "commitment": "confirmed",
+                "maxSupportedTransactionVersion": 0
+            }),
+        ];
+
+        match self.request::<Option<BlockDetails>>("getBlock", params).await {
+            Ok(Some(block)) => {
+                self.check_finalized(slot).await;
+                Ok(block)
+            }
+            Ok(None) => Err(RpcError::NotFound("Block".to_string())),
+            Err(RpcError::JsonRpc { code, .. }) if BLOCK_NOT_AVAILABLE.contains(&code) => {
+                Err(RpcError::NotFound("Block".to_string()))
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Get the highest finalized slot, recording it so cached confirmed
+    /// transactions and blocks up to it are kept for good
+    pub async fn get_finalized_slot(&self) -> Result<u64, RpcError> {
+        let slot = self
+            .request("getSlot", vec![serde_json::json!({ "commitment": "finalized" })])
+            .await?;
+        cache::record_finalized_slot(self.cluster_id(), slot);
+        Ok(slot)
+    }
+
+    /// Fetched at `confirmed`, a transaction or block in `slot` can only be
+    /// cached for good once the slot is finalized; refresh the finalized slot
+    /// when it is not known to cover `slot` yet
+    async fn check_finalized(&self, slot: u64) {
+        if cache::finalized_slot(self.cluster_id()).is_some_and(|root| slot <= root) {
+            return;
+        }
+        if let Err(e) = self.get_finalized_slot().await {
+            log::warn!("Failed to get the finalized slot: {}", e);
+        }
+    }
+
+    /// Get the leader scheduled for `slot`, if the leader schedule for its
+    /// epoch is still available
+    pub async fn get_slot_leader(&self, slot: u64) -> Result<Option<String>, RpcError> {
+        let leaders: Vec<String> = self
+            .request("getSlotLeaders", vec![Value::from(slot), Value::from(1)])
+            .await?;
+        Ok(leaders.into_iter().next())
+    }
+
+    /// Get signatures for transactions involving `address`, newest first
+    ///
+    /// Pages backwards in time: pass the last signature of the previous page
+    /// as `before` to continue. `until` stops the search at an older
+    /// signature (exclusive). `limit` is capped at 1000 by the RPC node.
+    pub async fn get_signatures_for_address(
+        &self,
+        address: &str,
+        before: Option<&str>,
+        until: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<TransactionSignature>, RpcError> {
+        let mut config = serde_json::json!({
+            "commitment": "confirmed",
+            "limit": limit.clamp(1, 1000)
+        });
+        if let Some(before) = before {
+            config["before"] = Value::String(before.to_string());
+        }
+        if let Some(until) = until {
+            config["until"] = Value::String(until.to_string());
+        }
+
+        self.request(
+            "getSignaturesForAddress",
+            vec![Value::String(address.to_string()), config],
+        )
+        .await
+    }
+
+    /// Get network stats (aggregated information) in a single batch request
+    pub async fn get_network_stats(&self) -> Result<NetworkStats, RpcError> {
+        let mut batch = RpcBatch::new();
+        let supply = batch.add::<RpcResponse<SupplyInfo>>(
+            "getSupply",
+            vec![serde_json::json!({
+                "commitment": "confirmed",
+                "excludeNonCirculatingAccountsList": true
+            })],
+        );
+        let epoch_info = batch.add::<EpochInfo>("getEpochInfo", vec![]);
+        let current_slot = batch.add::<u64>("getSlot", vec![]);
+        let vote_accounts = batch.add::<VoteAccounts>(
+            "getVoteAccounts",
+            vec![serde_json::json!({ "commitment": "confirmed" })],
+        );
+        let samples = batch.add::<Vec<PerformanceSample>>(
+            "getRecentPerformanceSamples",
+            vec![Value::from(PERFORMANCE_SAMPLE_COUNT)],
+        );
+
+        let mut results = self.send_batch(batch).await?;
+        let supply = results.take(supply)?;
+        let epoch_info = results.take(epoch_info)?;
+        let current_slot = results.take(current_slot)?;
+        let vote_accounts = results.take(vote_accounts)?;
+        let samples = results.take(samples)?;
+
+        // Count validators from the current (non-delinquent) vote accounts
+        let validator_count = vote_accounts.current.len();
+
+        Ok(NetworkStats {
+            total_supply: supply.value.total,
+            circulating_supply: supply.value.circulating,
+            current_slot,
+            epoch: epoch_info.epoch,
+            validator_count,
+            performance: PerformanceStats::from_samples(samples),
+        })
+    }
+
+    /// Get all vote accounts, split into current and delinquent
+    pub async fn get_vote_accounts(&self) -> Result<VoteAccounts, RpcError> {
+        self.request(
+            "getVoteAccounts",
+            vec![serde_json::json!({ "commitment": "confirmed" })],
+        )
+        .await
+    }
+
+    /// Get every node currently visible in gossip
+    pub async fn get_cluster_nodes(&self) -> Result<Vec<ClusterNode>, RpcError> {
+        self.request("getClusterNodes", vec![]).await
+    }
+
+    /// Get the decoded state of a vote account
+    pub async fn get_vote_state(&self, vote_pubkey: &str) -> Result<VoteState, RpcError> {
+        let params = vec![
+            Value::String(vote_pubkey.to_string()),
+            serde_json::json!({
+                "encoding": "jsonParsed",
+                "commitment": "confirmed"
+            }),
+        ];
+
+        let response: RpcResponse<Option<Value>> = self.request("getAccountInfo", params).await?;
+        let account = response
+            .value
+            .ok_or_else(|| RpcError::NotFound("Vote account".to_string()))?;
+        let account: ParsedAccount<VoteState> = serde_json::from_value(account)
+            .map_err(|_| RpcError::NotFound("Vote account".to_string()))?;
+        if account.data.program != "vote" || account.data.parsed.kind != "vote" {
+            return Err(RpcError::NotFound("Vote account".to_string()));
+        }
+
+        Ok(account.data.parsed.info)
+    }
+
+    /// Get leader slots and produced blocks of `identity` in the current epoch
+    pub async fn get_block_production(&self, identity: &str) -> Result<BlockProduction, RpcError> {
+        let params = vec![serde_json::json!({
+            "identity": identity,
+            "commitment": "confirmed"
+        })];
+
+        let response: RpcResponse<BlockProductionInfo> =
+            self.request("getBlockProduction", params).await?;
+        let (leader_slots, blocks_produced) = response
+            .value
+            .by_identity
+            .get(identity)
+            .copied()
+            .unwrap_or((0, 0));
+
+        Ok(BlockProduction {
+            leader_slots,
+            blocks_produced,
+            first_slot: response.value.range.first_slot,
+            last_slot: response.value.range.last_slot,
+        })
+    }
+
+    /// Get the inflation governor, current rate and epoch schedule in one round trip
+    pub async fn get_inflation_parameters(&self) -> Result<InflationParameters, RpcError> {
+        let mut batch = RpcBatch::new();
+        let governor = batch.add::<InflationGovernor>("getInflationGovernor", vec![]);
+        let rate = batch.add::<InflationRate>("getInflationRate", vec![]);
+        let epoch_schedule = batch.add::<EpochSchedule>("getEpochSchedule", vec![]);
+
+        let mut results = self.send_batch(batch).await?;
+        Ok(InflationParameters {
+            governor: results.take(governor)?,
+            rate: results.take(rate)?,
+            epoch_schedule: results.take(epoch_schedule)?,
+        })
+    }
+
+    /// Get the current epoch, epoch schedule and slot time in one round trip
+    pub async fn get_epoch_overview(&self) -> Result<EpochOverview, RpcError> {
+        let mut batch = RpcBatch::new();
+        let epoch_info = batch.add::<EpochInfo>("getEpochInfo", vec![]);
+        let epoch_schedule = batch.add::<EpochSchedule>("getEpochSchedule", vec![]);
+        let samples = batch.add::<Vec<PerformanceSample>>(
+            "getRecentPerformanceSamples",
+            vec![Value::from(PERFORMANCE_SAMPLE_COUNT)],
+        );
+
+        let mut results = self.send_batch(batch).await?;
+        Ok(EpochOverview {
+            epoch_info: results.take(epoch_info)?,
+            epoch_schedule: results.take(epoch_schedule)?,
+            performance: PerformanceStats::from_samples(results.take(samples)?),
+            observed_at: chrono::Utc::now().timestamp(),
+        })
+    }
+
+    /// Get inflation, supply, epoch and slot time in one round trip
+    pub async fn get_inflation_snapshot(&self) -> Result<InflationSnapshot, RpcError> {
+        let mut batch = RpcBatch::new();
+        let inflation = batch.add::<InflationRate>("getInflationRate", vec![]);
+        // Same params as `get_network_stats`, so the cached response is shared
+        let supply = batch.add::<RpcResponse<SupplyInfo>>(
+            "getSupply",
+            vec![serde_json::json!({
+                "commitment": "confirmed",
+                "excludeNonCirculatingAccountsList": true
+            })],
+        );
+        let epoch_info = batch.add::<EpochInfo>("getEpochInfo", vec![]);
+        let samples = batch.add::<Vec<PerformanceSample>>(
+            "getRecentPerformanceSamples",
+            vec![Value::from(PERFORMANCE_SAMPLE_COUNT)],
+        );
+
+        let mut results = self.send_batch(batch).await?;
+        Ok(InflationSnapshot {
+            inflation: results.take(inflation)?,
+            total_supply: results.take(supply)?.value.total,
+            epoch_info: results.take(epoch_info)?,
+            performance: PerformanceStats::from_samples(results.take(samples)?),
+        })
+    }
+
+    /// Get the staking rewards paid to `addresses` at the end of `epoch`
+    /// (the last completed epoch when `None`), in the same order
+    pub async fn get_inflation_rewards(
+        &self,
+        addresses: &[String],
+        epoch: Option<u64>,
+    ) -> Result<Vec<Option<InflationReward>>, RpcError> {
+        let mut config = serde_json::json!({ "commitment": "confirmed" });
+        if let Some(epoch) = epoch {
+            config["epoch"] = Value::from(epoch);
+        }
+
+        self.request("getInflationReward", vec![serde_json::json!(addresses), config])
+            .await
+    }
+
+    /// Get the addresses of stake accounts delegated to `vote_pubkey`
+    pub async fn get_delegated_stake_accounts(
+        &self,
+        vote_pubkey: &str,
+    ) -> Result<Vec<String>, RpcError> {
+        let params = vec![
+            Value::String(STAKE_PROGRAM_ID.to_string()),
+            serde_json::json!({
+                "commitment": "confirmed",
+                "encoding": "base64",
+                // Only the addresses are needed
+                "dataSlice": { "offset": 0, "length": 0 },
+                "filters": [
+                    { "memcmp": { "offset": STAKE_VOTER_OFFSET, "bytes": vote_pubkey } }
+                ]
+            }),
+        ];
+
+        let accounts: Vec<ProgramAccountKey> = self.request("getProgramAccounts", params).await?;
+        Ok(accounts.into_iter().map(|account| account.pubkey).collect())
+    }
+
+    /// Get throughput stats from the last `limit` performance samples
+    pub async fn get_performance_stats(&self, limit: usize) -> Result<PerformanceStats, RpcError> {
+        let samples: Vec<PerformanceSample> = self
+            .request(
+                "getRecentPerformanceSamples",
+                vec![Value::from(limit.clamp(1, 720))],
+            )
+            .await?;
+        Ok(PerformanceStats::from_samples(samples))
+    }
+}
+
+/// Parse a single JSON-RPC response body into its `result`
+fn parse_single_response(body: String) -> Result<Value, RpcError> {
+    let json_response: JsonRpcResponse = serde_json::from_str(&body)?;
+
+    if let Some(error) = json_response.error {
+        return Err(error.into());
+    }
+
+    Ok(json_response.result)
+}
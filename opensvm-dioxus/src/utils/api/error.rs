@@ -0,0 +1,188 @@
+//! Structured errors returned by the RPC client
+
+use std::fmt;
+use std::time::Duration;
+
+use serde_json::Value;
+
+/// JSON-RPC error code for invalid params (bad address, signature, ...)
+const INVALID_PARAMS: i64 = -32602;
+
+/// Everything that can go wrong while talking to an RPC endpoint
+#[derive(Debug, Clone, PartialEq)]
+pub enum RpcError {
+    /// The request could not be sent or the connection failed
+    Transport(String),
+    /// The endpoint answered with a non-success HTTP status
+    HttpStatus { status: u16, body: String },
+    /// The response body was not the JSON we expected
+    Decode(String),
+    /// The endpoint returned a JSON-RPC error object
+    JsonRpc {
+        code: i64,
+        message: String,
+        data: Option<Value>,
+    },
+    /// The endpoint is throttling us (HTTP 429)
+    RateLimited { retry_after: Option<Duration> },
+    /// No response arrived in time
+    Timeout,
+    /// The requested item does not exist on the selected cluster
+    NotFound(String),
+}
+
+impl RpcError {
+    /// Short heading suitable for an error card
+    pub fn title(&self) -> &'static str {
+        match self {
+            RpcError::Transport(_) => "Network Error",
+            RpcError::HttpStatus { .. } => "RPC Endpoint Error",
+            RpcError::Decode(_) => "Unexpected Response",
+            RpcError::JsonRpc { .. } => "RPC Error",
+            RpcError::RateLimited { .. } => "Rate Limited",
+            RpcError::Timeout => "Request Timed Out",
+            RpcError::NotFound(_) => "Not Found",
+        }
+    }
+
+    /// What the user can do about it
+    pub fn retry_hint(&self) -> String {
+        match self {
+            RpcError::Transport(_) => {
+                "Check your connection or switch to another cluster endpoint.".to_string()
+            }
+            RpcError::HttpStatus { status, .. } if *status >= 500 => {
+                "The endpoint is having problems. Try again in a moment.".to_string()
+            }
+            RpcError::HttpStatus { .. } => {
+                "The endpoint rejected the request. Check the RPC URL for this cluster.".to_string()
+            }
+            RpcError::Decode(_) => {
+                "The endpoint may not be a Solana JSON-RPC server. Check the RPC URL.".to_string()
+            }
+            RpcError::JsonRpc { code, .. } if *code == INVALID_PARAMS => {
+                "Check that the address or signature is valid.".to_string()
+            }
+            RpcError::JsonRpc { .. } => "Try again later or switch to another endpoint.".to_string(),
+            RpcError::RateLimited {
+                retry_after: Some(delay),
+            } => format!(
+                "Wait {}s before retrying, or use a custom RPC endpoint.",
+                delay.as_secs().max(1)
+            ),
+            RpcError::RateLimited { retry_after: None } => {
+                "Wait a few seconds before retrying, or use a custom RPC endpoint.".to_string()
+            }
+            RpcError::Timeout => "The endpoint may be under heavy load. Try again.".to_string(),
+            RpcError::NotFound(_) => {
+                "Check the value, or switch to the cluster where it exists.".to_string()
+            }
+        }
+    }
+
+    /// Delay requested by the server before retrying, if any
+    pub fn retry_after(&self) -> Option<Duration> {
+        match self {
+            RpcError::RateLimited { retry_after } => *retry_after,
+            _ => None,
+        }
+    }
+
+    /// Whether repeating the same request may succeed
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            RpcError::Transport(_) | RpcError::RateLimited { .. } | RpcError::Timeout => true,
+            RpcError::HttpStatus { status, .. } => *status >= 500 || *status == 408,
+            RpcError::Decode(_) | RpcError::JsonRpc { .. } | RpcError::NotFound(_) => false,
+        }
+    }
+}
+
+impl fmt::Display for RpcError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RpcError::Transport(msg) => write!(f, "Could not reach the RPC endpoint: {}", msg),
+            RpcError::HttpStatus { status, .. } => {
+                write!(f, "The RPC endpoint responded with HTTP {}", status)
+            }
+            RpcError::Decode(msg) => write!(f, "Could not decode the RPC response: {}", msg),
+            RpcError::JsonRpc { code, message, .. } => write!(f, "{} (code {})", message, code),
+            RpcError::RateLimited { .. } => write!(f, "The RPC endpoint is rate limiting requests"),
+            RpcError::Timeout => write!(f, "The RPC endpoint did not respond in time"),
+            RpcError::NotFound(what) => write!(f, "{} was not found", what),
+        }
+    }
+}
+
+impl std::error::Error for RpcError {}
+
+impl From<serde_json::Error> for RpcError {
+    fn from(err: serde_json::Error) -> Self {
+        RpcError::Decode(err.to_string())
+    }
+}
+
+#[cfg(any(feature = "desktop", feature = "android"))]
+impl From<reqwest::Error> for RpcError {
+    fn from(err: reqwest::Error) -> Self {
+        if err.is_timeout() {
+            RpcError::Timeout
+        } else {
+            RpcError::Transport(err.to_string())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::api::types::JsonRpcError;
+
+    fn json_rpc_error(code: i64) -> RpcError {
+        RpcError::from(JsonRpcError {
+            code,
+            message: "Too many requests for a specific RPC call".to_string(),
+            data: None,
+        })
+    }
+
+    #[test]
+    fn code_429_is_rate_limited() {
+        assert_eq!(json_rpc_error(429), RpcError::RateLimited { retry_after: None });
+        assert!(matches!(json_rpc_error(-32005), RpcError::JsonRpc { code: -32005, .. }));
+    }
+
+    #[test]
+    fn only_transient_failures_are_retryable() {
+        let retryable = [
+            RpcError::Transport("connection reset".to_string()),
+            RpcError::RateLimited { retry_after: None },
+            RpcError::RateLimited { retry_after: Some(Duration::from_secs(2)) },
+            RpcError::Timeout,
+            RpcError::HttpStatus { status: 408, body: String::new() },
+            RpcError::HttpStatus { status: 500, body: String::new() },
+            RpcError::HttpStatus { status: 503, body: String::new() },
+        ];
+        for error in retryable {
+            assert!(error.is_retryable(), "{:?}", error);
+        }
+
+        let permanent = [
+            RpcError::HttpStatus { status: 400, body: String::new() },
+            RpcError::HttpStatus { status: 403, body: String::new() },
+            RpcError::Decode("expected value".to_string()),
+            json_rpc_error(INVALID_PARAMS),
+            RpcError::NotFound("Transaction".to_string()),
+        ];
+        for error in permanent {
+            assert!(!error.is_retryable(), "{:?}", error);
+        }
+    }
+
+    #[test]
+    fn retry_after_is_only_reported_for_rate_limits() {
+        let delay = Duration::from_secs(2);
+        assert_eq!(RpcError::RateLimited { retry_after: Some(delay) }.retry_after(), Some(delay));
+        assert_eq!(RpcError::Timeout.retry_after(), None);
+    }
+}
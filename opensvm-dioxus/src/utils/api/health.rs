@@ -0,0 +1,155 @@
+//! Per-endpoint health tracking used for failover
+//!
+//! Health is shared by every client on the thread, so an endpoint that just
+//! failed for one page is skipped by the next page as well.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::time::Duration;
+
+/// Health record for a single RPC endpoint
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct EndpointHealth {
+    /// Consecutive failed requests
+    pub consecutive_failures: u32,
+    /// Unix time in milliseconds until which the endpoint is skipped
+    pub cooldown_until_ms: i64,
+}
+
+thread_local! {
+    static ENDPOINT_HEALTH: RefCell<HashMap<String, EndpointHealth>> = RefCell::new(HashMap::new());
+}
+
+fn now_ms() -> i64 {
+    chrono::Utc::now().timestamp_millis()
+}
+
+/// Whether `endpoint` is currently cooling down after a failure
+pub fn is_cooling_down(endpoint: &str) -> bool {
+    ENDPOINT_HEALTH.with(|health| {
+        health
+            .borrow()
+            .get(endpoint)
+            .map(|h| h.cooldown_until_ms > now_ms())
+            .unwrap_or(false)
+    })
+}
+
+/// Pick the first endpoint that is not cooling down
+///
+/// If every endpoint is cooling down, the one that recovers soonest is used so
+/// requests never stall completely.
+pub fn pick_endpoint(endpoints: &[String]) -> Option<&String> {
+    if let Some(endpoint) = endpoints.iter().find(|e| !is_cooling_down(e)) {
+        return Some(endpoint);
+    }
+
+    ENDPOINT_HEALTH.with(|health| {
+        let health = health.borrow();
+        endpoints.iter().min_by_key(|e| {
+            health
+                .get(e.as_str())
+                .map(|h| h.cooldown_until_ms)
+                .unwrap_or(0)
+        })
+    })
+}
+
+/// Record a successful request, clearing any cooldown
+pub fn record_success(endpoint: &str) {
+    ENDPOINT_HEALTH.with(|health| {
+        health.borrow_mut().remove(endpoint);
+    });
+}
+
+/// Record a failed request and skip the endpoint for a cooldown period
+///
+/// The cooldown grows with consecutive failures, up to four times `cooldown`.
+/// `min_cooldown` (e.g. a `Retry-After`) is honoured if it is longer.
+pub fn record_failure(endpoint: &str, cooldown: Duration, min_cooldown: Option<Duration>) {
+    ENDPOINT_HEALTH.with(|health| {
+        let mut health = health.borrow_mut();
+        let entry = health.entry(endpoint.to_string()).or_default();
+        entry.consecutive_failures += 1;
+
+        let scaled = cooldown.saturating_mul(entry.consecutive_failures.min(4));
+        let cooldown = min_cooldown.map_or(scaled, |min| scaled.max(min));
+        entry.cooldown_until_ms = entry
+            .cooldown_until_ms
+            .max(now_ms() + cooldown.as_millis() as i64);
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn endpoints() -> Vec<String> {
+        vec![
+            "https://primary.invalid".to_string(),
+            "https://secondary.invalid".to_string(),
+            "https://tertiary.invalid".to_string(),
+        ]
+    }
+
+    fn cooldown_ms(endpoint: &str) -> i64 {
+        ENDPOINT_HEALTH.with(|health| health.borrow()[endpoint].cooldown_until_ms) - now_ms()
+    }
+
+    #[test]
+    fn failures_only_cool_down_their_endpoint() {
+        let endpoints = endpoints();
+        assert_eq!(pick_endpoint(&endpoints), Some(&endpoints[0]));
+
+        record_failure(&endpoints[0], Duration::from_secs(30), None);
+        assert!(is_cooling_down(&endpoints[0]));
+        assert!(!is_cooling_down(&endpoints[1]));
+        assert_eq!(pick_endpoint(&endpoints), Some(&endpoints[1]));
+
+        record_success(&endpoints[0]);
+        assert!(!is_cooling_down(&endpoints[0]));
+        assert_eq!(pick_endpoint(&endpoints), Some(&endpoints[0]));
+    }
+
+    #[test]
+    fn the_soonest_recovering_endpoint_is_used_when_all_cool_down() {
+        let endpoints = endpoints();
+        record_failure(&endpoints[0], Duration::from_secs(60), None);
+        record_failure(&endpoints[1], Duration::from_secs(10), None);
+        record_failure(&endpoints[2], Duration::from_secs(30), None);
+        assert_eq!(pick_endpoint(&endpoints), Some(&endpoints[1]));
+        assert_eq!(pick_endpoint(&[]), None);
+    }
+
+    #[test]
+    fn cooldowns_grow_with_failures_and_honour_retry_after() {
+        let endpoint = "https://flaky.invalid";
+        let cooldown = Duration::from_secs(30);
+        for _ in 0..6 {
+            record_failure(endpoint, cooldown, None);
+        }
+        let capped = cooldown_ms(endpoint);
+        assert!(capped > 119_000 && capped <= 120_000, "{}", capped);
+
+        record_failure("https://throttled.invalid", cooldown, Some(Duration::from_secs(90)));
+        let retry_after = cooldown_ms("https://throttled.invalid");
+        assert!(retry_after > 89_000 && retry_after <= 90_000, "{}", retry_after);
+    }
+
+    #[test]
+    fn endpoints_recover_once_the_cooldown_expires() {
+        let endpoint = "https://recovering.invalid";
+        record_failure(endpoint, Duration::from_secs(30), None);
+        assert!(is_cooling_down(endpoint));
+
+        ENDPOINT_HEALTH.with(|health| {
+            health.borrow_mut().get_mut(endpoint).unwrap().cooldown_until_ms = now_ms() - 1;
+        });
+        assert!(!is_cooling_down(endpoint));
+
+        // The failure count survives expiry, so the next cooldown is longer
+        record_failure(endpoint, Duration::from_secs(30), None);
+        let next = cooldown_ms(endpoint);
+        assert!(next > 59_000 && next <= 60_000, "{}", next);
+    }
+}
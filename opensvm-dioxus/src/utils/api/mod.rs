@@ -0,0 +1,24 @@
+//! API utilities for fetching Solana blockchain data
+//!
+//! Requests go through a single [`SolanaApiClient`] that is generic over an
+//! [`RpcTransport`], so every platform shares the same request/response
+//! handling and only the HTTP layer differs.
+
+pub mod batch;
+pub mod cache;
+pub mod client;
+pub mod error;
+pub mod health;
+pub mod retry;
+pub mod transport;
+pub mod types;
+
+#[allow(unused_imports)]
+pub use batch::{BatchHandle, BatchResults, RpcBatch};
+pub use client::{SolanaApiClient, PERFORMANCE_SAMPLE_COUNT};
+pub use error::RpcError;
+#[allow(unused_imports)]
+pub use retry::RetryPolicy;
+#[allow(unused_imports)]
+pub use transport::{DefaultTransport, RpcTransport, TransportResponse};
+pub use types::*;
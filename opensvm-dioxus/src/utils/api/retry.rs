@@ -0,0 +1,127 @@
+//! Retry policy with exponential backoff and jitter
+
+use std::time::Duration;
+
+/// Controls how the client retries failed requests
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    /// Total number of attempts, including the first one
+    pub max_attempts: u32,
+    /// Delay before the first retry
+    pub base_delay: Duration,
+    /// Upper bound for the exponential backoff delay
+    pub max_delay: Duration,
+    /// Fraction of the delay (0.0 - 1.0) that is randomized
+    pub jitter: f64,
+    /// Longest `Retry-After` we are willing to wait before giving up
+    pub max_retry_after: Duration,
+    /// How long a failing endpoint is skipped before it is tried again
+    pub endpoint_cooldown: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 4,
+            base_delay: Duration::from_millis(250),
+            max_delay: Duration::from_secs(5),
+            jitter: 0.5,
+            max_retry_after: Duration::from_secs(10),
+            endpoint_cooldown: Duration::from_secs(30),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Backoff delay before retry number `attempt` (0 = first retry)
+    ///
+    /// A server supplied `Retry-After` overrides the computed delay.
+    pub fn delay_for(&self, attempt: u32, retry_after: Option<Duration>) -> Duration {
+        if let Some(retry_after) = retry_after {
+            return retry_after;
+        }
+
+        let exponential = self
+            .base_delay
+            .saturating_mul(2u32.saturating_pow(attempt))
+            .min(self.max_delay);
+        let jitter = self.jitter.clamp(0.0, 1.0);
+
+        exponential.mul_f64(1.0 - jitter * random_fraction())
+    }
+}
+
+/// Random number in `[0, 1)` used for jitter
+fn random_fraction() -> f64 {
+    // uuid v4 is already backed by the platform RNG on every target; the low
+    // 53 bits are all random (version and variant bits sit higher up)
+    const MANTISSA: u64 = 1 << 53;
+    (uuid::Uuid::new_v4().as_u128() as u64 % MANTISSA) as f64 / MANTISSA as f64
+}
+
+/// Wait for `duration` on the current platform's timer
+pub async fn sleep(duration: Duration) {
+    #[cfg(feature = "web")]
+    {
+        gloo::timers::future::TimeoutFuture::new(duration.as_millis() as u32).await;
+    }
+
+    #[cfg(all(not(feature = "web"), any(feature = "desktop", feature = "android")))]
+    {
+        tokio::time::sleep(duration).await;
+    }
+
+    #[cfg(not(any(feature = "web", feature = "desktop", feature = "android")))]
+    {
+        let _ = duration;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn without_jitter() -> RetryPolicy {
+        RetryPolicy {
+            jitter: 0.0,
+            ..RetryPolicy::default()
+        }
+    }
+
+    #[test]
+    fn backoff_doubles_until_the_cap() {
+        let policy = without_jitter();
+        assert_eq!(policy.delay_for(0, None), Duration::from_millis(250));
+        assert_eq!(policy.delay_for(1, None), Duration::from_millis(500));
+        assert_eq!(policy.delay_for(4, None), Duration::from_secs(4));
+        assert_eq!(policy.delay_for(5, None), Duration::from_secs(5));
+        assert_eq!(policy.delay_for(u32::MAX, None), Duration::from_secs(5));
+    }
+
+    #[test]
+    fn jitter_only_shortens_the_delay() {
+        let policy = RetryPolicy::default();
+        for _ in 0..200 {
+            let delay = policy.delay_for(2, None);
+            assert!(delay > Duration::from_millis(500), "{:?}", delay);
+            assert!(delay <= Duration::from_secs(1), "{:?}", delay);
+        }
+
+        // Out of range jitter is clamped rather than producing negative delays
+        let policy = RetryPolicy {
+            jitter: 7.0,
+            ..RetryPolicy::default()
+        };
+        for _ in 0..200 {
+            assert!(policy.delay_for(2, None) <= Duration::from_secs(1));
+        }
+    }
+
+    #[test]
+    fn retry_after_overrides_backoff() {
+        let policy = RetryPolicy::default();
+        let retry_after = Some(Duration::from_secs(3));
+        assert_eq!(policy.delay_for(0, retry_after), Duration::from_secs(3));
+        assert_eq!(policy.delay_for(9, retry_after), Duration::from_secs(3));
+    }
+}
@@ -0,0 +1,238 @@
+//! HTTP transports used by the RPC client
+//!
+//! Each platform provides one [`RpcTransport`] implementation:
+//! - Web: browser `fetch` through `web-sys`
+//! - Desktop / Android: `reqwest`
+
+use std::future::Future;
+use std::time::Duration;
+
+use super::error::RpcError;
+
+/// How long a single HTTP request may take before it fails with [`RpcError::Timeout`]
+pub const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Raw HTTP response returned by a transport
+#[derive(Debug, Clone)]
+pub struct TransportResponse {
+    pub status: u16,
+    pub body: String,
+    /// Parsed `Retry-After` header, if the server sent one
+    pub retry_after: Option<Duration>,
+}
+
+impl TransportResponse {
+    /// Whether the HTTP status is in the 2xx range
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+}
+
+/// Parse a `Retry-After` header given either as seconds or as an HTTP date
+pub fn parse_retry_after(value: &str) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let date = chrono::DateTime::parse_from_rfc2822(value).ok()?;
+    let millis = date.timestamp_millis() - chrono::Utc::now().timestamp_millis();
+    Some(Duration::from_millis(millis.max(0) as u64))
+}
+
+/// A transport capable of POSTing a JSON body to an RPC endpoint
+pub trait RpcTransport {
+    /// Send `body` to `url` and return the raw HTTP response
+    fn post_json(
+        &self,
+        url: &str,
+        body: String,
+    ) -> impl Future<Output = Result<TransportResponse, RpcError>>;
+}
+
+/// Transport used by [`super::SolanaApiClient::new`] on the current platform
+#[cfg(feature = "web")]
+pub type DefaultTransport = FetchTransport;
+
+#[cfg(all(not(feature = "web"), any(feature = "desktop", feature = "android")))]
+pub type DefaultTransport = ReqwestTransport;
+
+#[cfg(not(any(feature = "web", feature = "desktop", feature = "android")))]
+pub type DefaultTransport = UnavailableTransport;
+
+/// Transport backed by `reqwest` (desktop/mobile)
+#[cfg(any(feature = "desktop", feature = "android"))]
+#[derive(Clone)]
+pub struct ReqwestTransport {
+    client: reqwest::Client,
+}
+
+#[cfg(any(feature = "desktop", feature = "android"))]
+impl Default for ReqwestTransport {
+    fn default() -> Self {
+        let client = reqwest::Client::builder()
+            .timeout(REQUEST_TIMEOUT)
+            .build()
+            .unwrap_or_default();
+        Self { client }
+    }
+}
+
+#[cfg(any(feature = "desktop", feature = "android"))]
+impl RpcTransport for ReqwestTransport {
+    async fn post_json(
+        &self,
+        url: &str,
+        body: String,
+    ) -> Result<TransportResponse, RpcError> {
+        let response = self
+            .client
+            .post(url)
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .body(body)
+            .send()
+            .await?;
+
+        let status = response.status().as_u16();
+        let retry_after = response
+            .headers()
+            .get(reqwest::header::RETRY_AFTER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(parse_retry_after);
+        let body = response.text().await?;
+
+        Ok(TransportResponse {
+            status,
+            body,
+            retry_after,
+        })
+    }
+}
+
+/// Transport backed by the browser `fetch` API (web)
+#[cfg(feature = "web")]
+#[derive(Clone, Default)]
+pub struct FetchTransport;
+
+#[cfg(feature = "web")]
+impl RpcTransport for FetchTransport {
+    async fn post_json(
+        &self,
+        url: &str,
+        body: String,
+    ) -> Result<TransportResponse, RpcError> {
+        use gloo::timers::callback::Timeout;
+        use wasm_bindgen::{JsCast, JsValue};
+        use wasm_bindgen_futures::JsFuture;
+        use web_sys::{AbortController, Request, RequestInit, RequestMode, Response};
+
+        // Abort the fetch if it takes longer than REQUEST_TIMEOUT
+        let controller = AbortController::new().map_err(js_error)?;
+        let abort_handle = controller.clone();
+        let _timeout = Timeout::new(REQUEST_TIMEOUT.as_millis() as u32, move || {
+            abort_handle.abort();
+        });
+
+        let opts = RequestInit::new();
+        opts.set_method("POST");
+        opts.set_mode(RequestMode::Cors);
+        opts.set_body(&JsValue::from_str(&body));
+        opts.set_signal(Some(&controller.signal()));
+
+        let request = Request::new_with_str_and_init(url, &opts).map_err(js_error)?;
+        request
+            .headers()
+            .set("Content-Type", "application/json")
+            .map_err(js_error)?;
+
+        let window = web_sys::window()
+            .ok_or_else(|| RpcError::Transport("No window available for fetch".to_string()))?;
+        let resp_value = JsFuture::from(window.fetch_with_request(&request))
+            .await
+            .map_err(js_error)?;
+        let resp: Response = resp_value.dyn_into().map_err(js_error)?;
+
+        let status = resp.status();
+        // Only visible when the endpoint lists it in Access-Control-Expose-Headers
+        let retry_after = resp
+            .headers()
+            .get("Retry-After")
+            .ok()
+            .flatten()
+            .and_then(|value| parse_retry_after(&value));
+        let text = JsFuture::from(resp.text().map_err(js_error)?)
+            .await
+            .map_err(js_error)?;
+        let body = text
+            .as_string()
+            .ok_or_else(|| RpcError::Decode("Response body is not text".to_string()))?;
+
+        Ok(TransportResponse {
+            status,
+            body,
+            retry_after,
+        })
+    }
+}
+
+/// Convert a JavaScript exception into an [`RpcError`]
+#[cfg(feature = "web")]
+fn js_error(value: wasm_bindgen::JsValue) -> RpcError {
+    use wasm_bindgen::JsCast;
+
+    if let Some(exception) = value.dyn_ref::<js_sys::Error>() {
+        if exception.name() == "AbortError" {
+            return RpcError::Timeout;
+        }
+        return RpcError::Transport(String::from(exception.message()));
+    }
+
+    RpcError::Transport(value.as_string().unwrap_or_else(|| format!("{:?}", value)))
+}
+
+/// Placeholder transport for builds without a platform feature
+#[cfg(not(any(feature = "web", feature = "desktop", feature = "android")))]
+#[derive(Clone, Default)]
+pub struct UnavailableTransport;
+
+#[cfg(not(any(feature = "web", feature = "desktop", feature = "android")))]
+impl RpcTransport for UnavailableTransport {
+    async fn post_json(
+        &self,
+        _url: &str,
+        _body: String,
+    ) -> Result<TransportResponse, RpcError> {
+        Err(RpcError::Transport(
+            "No RPC transport available for this platform".to_string(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn retry_after_accepts_seconds() {
+        assert_eq!(parse_retry_after("0"), Some(Duration::ZERO));
+        assert_eq!(parse_retry_after(" 120 "), Some(Duration::from_secs(120)));
+    }
+
+    #[test]
+    fn retry_after_accepts_http_dates() {
+        let soon = chrono::Utc::now() + chrono::Duration::seconds(30);
+        let delay = parse_retry_after(&soon.to_rfc2822()).unwrap();
+        assert!(delay > Duration::from_secs(25) && delay <= Duration::from_secs(30), "{:?}", delay);
+
+        // Dates in the past mean "retry now"
+        assert_eq!(parse_retry_after("Wed, 21 Oct 2015 07:28:00 GMT"), Some(Duration::ZERO));
+    }
+
+    #[test]
+    fn retry_after_rejects_garbage() {
+        assert_eq!(parse_retry_after(""), None);
+        assert_eq!(parse_retry_after("-5"), None);
+        assert_eq!(parse_retry_after("1.5"), None);
+        assert_eq!(parse_retry_after("soon"), None);
+    }
+}
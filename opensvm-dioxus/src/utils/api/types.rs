@@ -0,0 +1,822 @@
+//! Typed JSON-RPC request and response structures
+
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::error::RpcError;
+
+/// Standard JSON-RPC request structure
+#[derive(Serialize, Debug)]
+pub(crate) struct JsonRpcRequest<'a> {
+    pub jsonrpc: &'a str,
+    pub id: u64,
+    pub method: &'a str,
+    pub params: Vec<Value>,
+}
+
+/// Standard JSON-RPC response structure
+#[derive(Deserialize, Debug)]
+pub(crate) struct JsonRpcResponse {
+    /// Request id, used to match batch responses
+    pub id: Option<u64>,
+    #[serde(default)]
+    pub result: Value,
+    pub error: Option<JsonRpcError>,
+}
+
+#[derive(Deserialize, Debug)]
+pub(crate) struct JsonRpcError {
+    pub code: i64,
+    pub message: String,
+    pub data: Option<Value>,
+}
+
+impl From<JsonRpcError> for RpcError {
+    fn from(error: JsonRpcError) -> Self {
+        // Some providers report throttling as a JSON-RPC error instead of HTTP 429
+        if error.code == 429 {
+            return RpcError::RateLimited { retry_after: None };
+        }
+        RpcError::JsonRpc {
+            code: error.code,
+            message: error.message,
+            data: error.data,
+        }
+    }
+}
+
+/// Account information structure
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountInfo {
+    pub data: Vec<String>,
+    pub executable: bool,
+    pub lamports: u64,
+    pub owner: String,
+    pub rent_epoch: u64,
+}
+
+impl AccountInfo {
+    /// Account data, decoded from the base64 encoding the client requests
+    pub fn data_bytes(&self) -> Option<Vec<u8>> {
+        match self.data.as_slice() {
+            [data, encoding] if encoding == "base64" => BASE64.decode(data).ok(),
+            _ => None,
+        }
+    }
+}
+
+/// Wrapper for RPC results that are returned as `{ context, value }`
+#[derive(Deserialize, Debug)]
+pub(crate) struct RpcResponse<T> {
+    pub value: T,
+}
+
+/// Transaction signature information
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionSignature {
+    pub signature: String,
+    pub slot: Option<u64>,
+    pub err: Option<Value>,
+    pub memo: Option<String>,
+    pub block_time: Option<i64>,
+    pub confirmation_status: Option<String>,
+}
+
+/// Detailed transaction information
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionDetails {
+    pub slot: Option<u64>,
+    pub transaction: TransactionInfo,
+    pub meta: Option<TransactionMeta>,
+    pub block_time: Option<i64>,
+    /// `"legacy"` or a numeric version such as `0`
+    pub version: Option<Value>,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct TransactionInfo {
+    pub message: TransactionMessage,
+    pub signatures: Vec<String>,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionMessage {
+    pub account_keys: Vec<String>,
+    pub header: MessageHeader,
+    pub instructions: Vec<TransactionInstruction>,
+    pub recent_blockhash: String,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct MessageHeader {
+    pub num_required_signatures: u8,
+    pub num_readonly_signed_accounts: u8,
+    pub num_readonly_unsigned_accounts: u8,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionInstruction {
+    pub accounts: Vec<u8>,
+    pub data: String,
+    pub program_id_index: u8,
+}
+
+/// Instructions invoked through cross-program invocations while one
+/// top-level instruction executed, in execution order
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct InnerInstructions {
+    /// Index of the top-level instruction
+    pub index: u8,
+    pub instructions: Vec<InnerInstruction>,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct InnerInstruction {
+    #[serde(flatten)]
+    pub instruction: TransactionInstruction,
+    /// Invocation depth: top-level instructions run at 1, the instructions
+    /// they invoke at 2, and so on. Not recorded by older validators.
+    pub stack_height: Option<u32>,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionMeta {
+    pub err: Option<Value>,
+    pub fee: u64,
+    pub inner_instructions: Option<Vec<InnerInstructions>>,
+    pub log_messages: Option<Vec<String>>,
+    pub post_balances: Vec<u64>,
+    pub post_token_balances: Option<Vec<TokenBalance>>,
+    pub pre_balances: Vec<u64>,
+    pub pre_token_balances: Option<Vec<TokenBalance>>,
+    pub rewards: Option<Vec<Value>>,
+    pub status: Option<Value>,
+    /// Accounts loaded from address lookup tables (version 0 transactions)
+    pub loaded_addresses: Option<LoadedAddresses>,
+}
+
+/// Token account balance before or after a transaction
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenBalance {
+    /// Index into the transaction's account keys
+    pub account_index: u8,
+    pub mint: String,
+    pub owner: Option<String>,
+    pub program_id: Option<String>,
+    pub ui_token_amount: UiTokenAmount,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct UiTokenAmount {
+    /// Raw amount as a decimal string
+    pub amount: String,
+    pub decimals: u8,
+    pub ui_amount_string: Option<String>,
+}
+
+/// Accounts a version 0 transaction loaded from address lookup tables,
+/// appended after the message's static account keys
+#[derive(Deserialize, Debug, Clone, PartialEq, Default)]
+pub struct LoadedAddresses {
+    pub writable: Vec<String>,
+    pub readonly: Vec<String>,
+}
+
+/// Account referenced by a transaction, with its access flags
+#[derive(Debug, Clone, PartialEq)]
+pub struct AccountKey {
+    pub pubkey: String,
+    pub signer: bool,
+    pub writable: bool,
+}
+
+/// Program id of the native Vote program
+pub const VOTE_PROGRAM_ID: &str = "Vote111111111111111111111111111111111111111";
+
+/// Program id of the native Stake program
+pub const STAKE_PROGRAM_ID: &str = "Stake11111111111111111111111111111111111111";
+
+impl TransactionDetails {
+    /// Whether this is a validator vote transaction
+    pub fn is_vote(&self) -> bool {
+        let message = &self.transaction.message;
+        message.instructions.iter().any(|instruction| {
+            message
+                .account_keys
+                .get(instruction.program_id_index as usize)
+                .is_some_and(|program_id| program_id == VOTE_PROGRAM_ID)
+        })
+    }
+
+    /// Static account keys followed by any loaded from lookup tables, in the
+    /// order instruction account indexes refer to them
+    pub fn account_keys(&self) -> Vec<AccountKey> {
+        let message = &self.transaction.message;
+        let header = &message.header;
+        let total = message.account_keys.len();
+        let signers = header.num_required_signatures as usize;
+        let readonly_signers = header.num_readonly_signed_accounts as usize;
+        let readonly_unsigned = header.num_readonly_unsigned_accounts as usize;
+
+        let mut keys: Vec<AccountKey> = message
+            .account_keys
+            .iter()
+            .enumerate()
+            .map(|(index, pubkey)| {
+                let signer = index < signers;
+                let writable = if signer {
+                    index < signers.saturating_sub(readonly_signers)
+                } else {
+                    index < total.saturating_sub(readonly_unsigned)
+                };
+                AccountKey { pubkey: pubkey.clone(), signer, writable }
+            })
+            .collect();
+
+        if let Some(loaded) = self.meta.as_ref().and_then(|meta| meta.loaded_addresses.as_ref()) {
+            let loaded_key = |writable| move |pubkey: &String| AccountKey {
+                pubkey: pubkey.clone(),
+                signer: false,
+                writable,
+            };
+            keys.extend(loaded.writable.iter().map(loaded_key(true)));
+            keys.extend(loaded.readonly.iter().map(loaded_key(false)));
+        }
+        keys
+    }
+}
+
+/// Block returned by `getBlock` with full transaction details
+///
+/// Transactions reuse [`TransactionDetails`]; their `slot` and `block_time`
+/// are not set by the RPC and are `None`.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockDetails {
+    pub blockhash: String,
+    pub previous_blockhash: String,
+    pub parent_slot: u64,
+    pub block_time: Option<i64>,
+    pub block_height: Option<u64>,
+    #[serde(default)]
+    pub transactions: Vec<TransactionDetails>,
+    #[serde(default)]
+    pub rewards: Vec<Reward>,
+}
+
+impl BlockDetails {
+    /// Identity that received the block's fee reward, i.e. the leader
+    pub fn fee_recipient(&self) -> Option<&str> {
+        self.rewards
+            .iter()
+            .find(|reward| reward.reward_type.as_deref() == Some("Fee"))
+            .map(|reward| reward.pubkey.as_str())
+    }
+}
+
+/// Reward credited in a block or epoch
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Reward {
+    pub pubkey: String,
+    pub lamports: i64,
+    pub post_balance: u64,
+    /// `"Fee"`, `"Rent"`, `"Staking"` or `"Voting"`
+    pub reward_type: Option<String>,
+    pub commission: Option<u8>,
+}
+
+/// Supply information
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SupplyInfo {
+    pub total: u64,
+    pub circulating: u64,
+    pub non_circulating: u64,
+    pub non_circulating_accounts: Vec<String>,
+}
+
+/// Annual inflation rates returned by `getInflationRate`
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct InflationRate {
+    /// Total inflation as a fraction of supply per year
+    pub total: f64,
+    /// Part of `total` paid to validators and their delegators
+    pub validator: f64,
+    /// Part of `total` paid to the foundation
+    pub foundation: f64,
+    pub epoch: u64,
+}
+
+/// Parameters of the disinflation schedule returned by `getInflationGovernor`
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct InflationGovernor {
+    /// Annual rate in the first year of inflation
+    pub initial: f64,
+    /// Annual rate once the taper bottoms out
+    pub terminal: f64,
+    /// Yearly reduction of the rate
+    pub taper: f64,
+    /// Share of inflation paid to the foundation
+    pub foundation: f64,
+    /// Years the foundation share is paid for
+    pub foundation_term: f64,
+}
+
+/// Epoch layout returned by `getEpochSchedule`
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct EpochSchedule {
+    pub slots_per_epoch: u64,
+    pub leader_schedule_slot_offset: u64,
+    /// Whether epochs start short and double until `slots_per_epoch`
+    pub warmup: bool,
+    pub first_normal_epoch: u64,
+    pub first_normal_slot: u64,
+}
+
+/// Length of the first epoch when the schedule warms up
+pub const MINIMUM_SLOTS_PER_EPOCH: u64 = 32;
+
+impl EpochSchedule {
+    /// Slots in `epoch`, accounting for warmup. Warmup epochs too long to
+    /// represent fall back to `slots_per_epoch`.
+    pub fn slots_in_epoch(&self, epoch: u64) -> u64 {
+        if epoch < self.first_normal_epoch {
+            u32::try_from(epoch)
+                .ok()
+                .and_then(|doublings| 1u64.checked_shl(doublings))
+                .and_then(|factor| factor.checked_mul(MINIMUM_SLOTS_PER_EPOCH))
+                .unwrap_or(self.slots_per_epoch)
+        } else {
+            self.slots_per_epoch
+        }
+    }
+
+    /// First slot of `epoch`, or `None` if it is past the last slot
+    pub fn first_slot_in_epoch(&self, epoch: u64) -> Option<u64> {
+        if epoch <= self.first_normal_epoch {
+            let doublings = u32::try_from(epoch).ok()?;
+            1u64.checked_shl(doublings)?
+                .checked_sub(1)?
+                .checked_mul(MINIMUM_SLOTS_PER_EPOCH)
+        } else {
+            (epoch - self.first_normal_epoch)
+                .checked_mul(self.slots_per_epoch)?
+                .checked_add(self.first_normal_slot)
+        }
+    }
+
+    /// Last slot of `epoch`, or `None` if it is past the last slot
+    pub fn last_slot_in_epoch(&self, epoch: u64) -> Option<u64> {
+        self.first_slot_in_epoch(epoch)?
+            .checked_add(self.slots_in_epoch(epoch))?
+            .checked_sub(1)
+    }
+
+    /// Epoch containing `slot` and the slot's index within it. A malformed
+    /// schedule with `slots_per_epoch == 0` is treated as one slot per epoch.
+    pub fn epoch_and_slot_index(&self, slot: u64) -> (u64, u64) {
+        if slot < self.first_normal_slot {
+            let epoch = (slot + MINIMUM_SLOTS_PER_EPOCH + 1).next_power_of_two().trailing_zeros()
+                - MINIMUM_SLOTS_PER_EPOCH.trailing_zeros()
+                - 1;
+            let epoch = epoch as u64;
+            // Warmup epochs start well within range
+            let first_slot = self.first_slot_in_epoch(epoch).unwrap_or(0);
+            (epoch, slot - first_slot)
+        } else {
+            let normal_index = slot - self.first_normal_slot;
+            let slots_per_epoch = self.slots_per_epoch.max(1);
+            (
+                self.first_normal_epoch.saturating_add(normal_index / slots_per_epoch),
+                normal_index % slots_per_epoch,
+            )
+        }
+    }
+}
+
+/// Staking reward credited to an account at an epoch boundary
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct InflationReward {
+    pub epoch: u64,
+    pub effective_slot: u64,
+    /// Reward in lamports
+    pub amount: u64,
+    /// Balance after the reward was credited
+    pub post_balance: u64,
+    /// Vote account commission when the reward was paid
+    pub commission: Option<u8>,
+}
+
+/// Everything needed to turn inflation into staking yield, fetched together
+#[derive(Debug, Clone)]
+pub struct InflationSnapshot {
+    pub inflation: InflationRate,
+    pub total_supply: u64,
+    pub epoch_info: EpochInfo,
+    pub performance: PerformanceStats,
+}
+
+/// Current epoch, epoch layout and slot time, fetched together
+#[derive(Debug, Clone, PartialEq)]
+pub struct EpochOverview {
+    pub epoch_info: EpochInfo,
+    pub epoch_schedule: EpochSchedule,
+    pub performance: PerformanceStats,
+    /// Unix time (seconds) the response was received, when the cluster was
+    /// at `epoch_info.absolute_slot`
+    pub observed_at: i64,
+}
+
+/// Inputs of the inflation schedule, fetched together
+#[derive(Debug, Clone, PartialEq)]
+pub struct InflationParameters {
+    pub governor: InflationGovernor,
+    pub rate: InflationRate,
+    pub epoch_schedule: EpochSchedule,
+}
+
+/// Account address from a `getProgramAccounts` response
+#[derive(Deserialize, Debug, Clone)]
+pub(crate) struct ProgramAccountKey {
+    pub pubkey: String,
+}
+
+/// Epoch information returned by `getEpochInfo`
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct EpochInfo {
+    pub absolute_slot: u64,
+    pub block_height: u64,
+    pub epoch: u64,
+    pub slot_index: u64,
+    pub slots_in_epoch: u64,
+    pub transaction_count: Option<u64>,
+}
+
+/// Vote account as returned by `getVoteAccounts`
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct VoteAccountInfo {
+    pub vote_pubkey: String,
+    /// Validator identity
+    pub node_pubkey: String,
+    /// Stake delegated to this vote account and active in the current epoch
+    pub activated_stake: u64,
+    /// Whether the account is staked for the current epoch
+    pub epoch_vote_account: bool,
+    /// Percentage (0-100) of rewards kept by the validator
+    pub commission: u8,
+    pub last_vote: u64,
+    pub root_slot: u64,
+    /// Recent `(epoch, credits, previous_credits)` entries
+    #[serde(default)]
+    pub epoch_credits: Vec<(u64, u64, u64)>,
+}
+
+/// Staked vote account of validator `identity` with no votes or credits
+#[cfg(test)]
+pub(crate) fn test_vote_account(identity: &str, stake: u64) -> VoteAccountInfo {
+    VoteAccountInfo {
+        vote_pubkey: format!("vote-{}", identity),
+        node_pubkey: identity.to_string(),
+        activated_stake: stake,
+        epoch_vote_account: true,
+        commission: 0,
+        last_vote: 0,
+        root_slot: 0,
+        epoch_credits: Vec::new(),
+    }
+}
+
+/// Result of `getVoteAccounts`
+#[derive(Deserialize, Debug, Clone, PartialEq, Default)]
+pub struct VoteAccounts {
+    pub current: Vec<VoteAccountInfo>,
+    pub delinquent: Vec<VoteAccountInfo>,
+}
+
+impl VoteAccounts {
+    /// Total active stake across current and delinquent accounts, in lamports
+    pub fn total_stake(&self) -> u64 {
+        self.current
+            .iter()
+            .chain(&self.delinquent)
+            .map(|account| account.activated_stake)
+            .sum()
+    }
+}
+
+/// Node advertised in gossip, from `getClusterNodes`
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ClusterNode {
+    /// Node identity
+    pub pubkey: String,
+    pub gossip: Option<String>,
+    pub tpu: Option<String>,
+    /// JSON-RPC address, if the node serves RPC publicly
+    pub rpc: Option<String>,
+    pub version: Option<String>,
+    pub feature_set: Option<u32>,
+    pub shred_version: Option<u16>,
+}
+
+/// Vote account state as decoded by the RPC node (`jsonParsed` encoding)
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct VoteState {
+    /// Validator identity
+    pub node_pubkey: String,
+    pub authorized_withdrawer: String,
+    #[serde(default)]
+    pub authorized_voters: Vec<AuthorizedVoter>,
+    pub commission: u8,
+    #[serde(default)]
+    pub epoch_credits: Vec<EpochCredits>,
+    pub root_slot: Option<u64>,
+    pub last_timestamp: Option<VoteTimestamp>,
+}
+
+impl VoteState {
+    /// Voter authorized for the most recent epoch
+    pub fn current_authorized_voter(&self) -> Option<&str> {
+        self.authorized_voters
+            .iter()
+            .max_by_key(|voter| voter.epoch)
+            .map(|voter| voter.authorized_voter.as_str())
+    }
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AuthorizedVoter {
+    pub epoch: u64,
+    pub authorized_voter: String,
+}
+
+/// Vote credits earned up to the end of `epoch`
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct EpochCredits {
+    pub epoch: u64,
+    #[serde(deserialize_with = "u64_from_string")]
+    pub credits: u64,
+    #[serde(deserialize_with = "u64_from_string")]
+    pub previous_credits: u64,
+}
+
+impl EpochCredits {
+    /// Credits earned during this epoch alone
+    pub fn earned(&self) -> u64 {
+        self.credits.saturating_sub(self.previous_credits)
+    }
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct VoteTimestamp {
+    pub slot: u64,
+    pub timestamp: i64,
+}
+
+/// Account data in `jsonParsed` encoding
+#[derive(Deserialize, Debug, Clone)]
+pub(crate) struct ParsedAccountData<T> {
+    pub program: String,
+    pub parsed: ParsedInfo<T>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub(crate) struct ParsedInfo<T> {
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub info: T,
+}
+
+/// Account returned by `getAccountInfo` in `jsonParsed` encoding
+#[derive(Deserialize, Debug, Clone)]
+pub(crate) struct ParsedAccount<T> {
+    pub data: ParsedAccountData<T>,
+}
+
+/// Leader slots and produced blocks of one validator, from `getBlockProduction`
+#[derive(Debug, Clone, PartialEq)]
+pub struct BlockProduction {
+    pub leader_slots: u64,
+    pub blocks_produced: u64,
+    pub first_slot: u64,
+    pub last_slot: u64,
+}
+
+impl BlockProduction {
+    /// Fraction (0.0 - 1.0) of leader slots without a block
+    pub fn skip_rate(&self) -> Option<f64> {
+        if self.leader_slots == 0 {
+            return None;
+        }
+        Some(1.0 - self.blocks_produced as f64 / self.leader_slots as f64)
+    }
+}
+
+/// Raw `getBlockProduction` result
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct BlockProductionInfo {
+    /// Identity -> `[leader_slots, blocks_produced]`
+    pub by_identity: std::collections::HashMap<String, (u64, u64)>,
+    pub range: SlotRange,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct SlotRange {
+    pub first_slot: u64,
+    pub last_slot: u64,
+}
+
+/// Deserialize a `u64` the RPC encodes as a string to avoid precision loss
+fn u64_from_string<'de, D>(deserializer: D) -> Result<u64, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum StringOrNumber {
+        String(String),
+        Number(u64),
+    }
+
+    match StringOrNumber::deserialize(deserializer)? {
+        StringOrNumber::String(value) => value.parse().map_err(serde::de::Error::custom),
+        StringOrNumber::Number(value) => Ok(value),
+    }
+}
+
+/// One sample returned by `getRecentPerformanceSamples`
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PerformanceSample {
+    pub slot: u64,
+    pub num_transactions: u64,
+    /// Missing on nodes older than v1.15
+    #[serde(default)]
+    pub num_non_vote_transactions: Option<u64>,
+    pub num_slots: u64,
+    pub sample_period_secs: u16,
+}
+
+impl PerformanceSample {
+    /// Transactions per second during the sample
+    pub fn tps(&self) -> f64 {
+        self.num_transactions as f64 / f64::from(self.sample_period_secs.max(1))
+    }
+
+    /// Non-vote transactions per second during the sample
+    pub fn non_vote_tps(&self) -> Option<f64> {
+        self.num_non_vote_transactions
+            .map(|count| count as f64 / f64::from(self.sample_period_secs.max(1)))
+    }
+}
+
+/// Throughput and slot time averaged over recent performance samples
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct PerformanceStats {
+    /// Average slot time in seconds
+    pub avg_slot_time: f64,
+    pub tps: f64,
+    /// `None` if the node does not report non-vote transaction counts
+    pub non_vote_tps: Option<f64>,
+    /// Samples the averages were computed from, oldest first
+    pub history: Vec<PerformanceSample>,
+}
+
+impl PerformanceStats {
+    /// Aggregate samples as returned by the RPC (newest first)
+    pub fn from_samples(mut samples: Vec<PerformanceSample>) -> Self {
+        samples.retain(|sample| sample.sample_period_secs > 0);
+        samples.reverse();
+
+        let total_secs: f64 = samples
+            .iter()
+            .map(|sample| f64::from(sample.sample_period_secs))
+            .sum();
+        let total_slots: u64 = samples.iter().map(|sample| sample.num_slots).sum();
+        let total_transactions: u64 = samples.iter().map(|sample| sample.num_transactions).sum();
+        let total_non_vote: Option<u64> = samples
+            .iter()
+            .map(|sample| sample.num_non_vote_transactions)
+            .sum();
+
+        if total_secs == 0.0 {
+            return Self::default();
+        }
+
+        Self {
+            avg_slot_time: if total_slots == 0 {
+                0.0
+            } else {
+                total_secs / total_slots as f64
+            },
+            tps: total_transactions as f64 / total_secs,
+            non_vote_tps: total_non_vote.map(|count| count as f64 / total_secs),
+            history: samples,
+        }
+    }
+}
+
+/// Network stats
+#[derive(Debug, Clone)]
+pub struct NetworkStats {
+    pub total_supply: u64,
+    pub circulating_supply: u64,
+    pub current_slot: u64,
+    pub epoch: u64,
+    pub validator_count: usize,
+    /// Slot time and TPS from recent performance samples
+    pub performance: PerformanceStats,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!((actual - expected).abs() < 1e-9, "{} != {}", actual, expected);
+    }
+
+    fn sample(slot: u64, transactions: u64, non_vote: Option<u64>, secs: u16) -> PerformanceSample {
+        PerformanceSample {
+            slot,
+            num_transactions: transactions,
+            num_non_vote_transactions: non_vote,
+            num_slots: u64::from(secs) * 5 / 2,
+            sample_period_secs: secs,
+        }
+    }
+
+    #[test]
+    fn no_samples_give_empty_stats() {
+        assert_eq!(PerformanceStats::from_samples(Vec::new()), PerformanceStats::default());
+    }
+
+    #[test]
+    fn samples_without_a_period_are_dropped() {
+        let stats = PerformanceStats::from_samples(vec![sample(300, 9_000, None, 0)]);
+        assert_eq!(stats, PerformanceStats::default());
+
+        let stats = PerformanceStats::from_samples(vec![
+            sample(300, 9_000, Some(900), 0),
+            sample(150, 120_000, Some(12_000), 60),
+        ]);
+        assert_eq!(stats.history.len(), 1);
+        assert_close(stats.tps, 2_000.0);
+        assert_eq!(stats.non_vote_tps, Some(200.0));
+        // Per-sample rates never divide by zero either
+        assert_close(sample(300, 9_000, None, 0).tps(), 9_000.0);
+    }
+
+    #[test]
+    fn tps_is_weighted_by_sample_period_and_history_is_oldest_first() {
+        // Newest first, as returned by getRecentPerformanceSamples
+        let stats = PerformanceStats::from_samples(vec![
+            sample(450, 180_000, Some(30_000), 60),
+            sample(300, 60_000, Some(6_000), 30),
+            sample(150, 120_000, Some(12_000), 60),
+        ]);
+
+        assert_close(stats.tps, 360_000.0 / 150.0);
+        assert_close(stats.non_vote_tps.unwrap(), 48_000.0 / 150.0);
+        assert_close(stats.avg_slot_time, 0.4);
+
+        let slots: Vec<u64> = stats.history.iter().map(|sample| sample.slot).collect();
+        assert_eq!(slots, vec![150, 300, 450]);
+        let peak = stats.history.iter().map(PerformanceSample::tps).fold(0.0, f64::max);
+        assert_close(peak, 3_000.0);
+    }
+
+    #[test]
+    fn non_vote_tps_needs_every_sample_to_report_it() {
+        let stats = PerformanceStats::from_samples(vec![
+            sample(300, 60_000, Some(6_000), 60),
+            sample(150, 60_000, None, 60),
+        ]);
+        assert_close(stats.tps, 1_000.0);
+        assert_eq!(stats.non_vote_tps, None);
+    }
+}
@@ -0,0 +1,59 @@
+//! Address Lookup Table program decoder
+//!
+//! Lookup table instructions are bincode-encoded with a u32 variant index.
+
+use super::{
+    DataReader, DecodeContext, DecodeError, DecodedInstruction, FieldValue, InstructionDecoder,
+    RawInstruction,
+};
+
+/// Program id of the Address Lookup Table program
+pub const ADDRESS_LOOKUP_TABLE_PROGRAM_ID: &str = "AddressLookupTab1e1111111111111111111111111";
+
+pub struct AddressLookupTableDecoder;
+
+impl InstructionDecoder for AddressLookupTableDecoder {
+    fn program_id(&self) -> &str {
+        ADDRESS_LOOKUP_TABLE_PROGRAM_ID
+    }
+
+    fn program_name(&self) -> &str {
+        "Address Lookup Table Program"
+    }
+
+    fn decode(
+        &self,
+        instruction: &RawInstruction,
+        _context: &DecodeContext,
+    ) -> Result<DecodedInstruction, DecodeError> {
+        let mut data = DataReader::new(&instruction.data);
+        let accounts = &instruction.accounts;
+        let decoded = |name: &str| DecodedInstruction::new(self.program_name(), name);
+
+        let decoded = match data.u32()? {
+            0 => decoded("Create Lookup Table")
+                .field("Recent Slot", FieldValue::Number(data.u64()?.into()))
+                .field("Bump Seed", FieldValue::Number(data.u8()?.into()))
+                .roles(&["Lookup Table", "Authority", "Payer", "System Program"], accounts),
+            1 => decoded("Freeze Lookup Table").roles(&["Lookup Table", "Authority"], accounts),
+            2 => {
+                let len = data.u64()?;
+                let mut extended = decoded("Extend Lookup Table")
+                    .field("New Addresses", FieldValue::Number(len.into()));
+                for index in 0..len {
+                    extended = extended.field(
+                        format!("Address #{}", index + 1),
+                        FieldValue::Address(data.pubkey()?),
+                    );
+                }
+                extended.roles(&["Lookup Table", "Authority", "Payer", "System Program"], accounts)
+            }
+            3 => decoded("Deactivate Lookup Table").roles(&["Lookup Table", "Authority"], accounts),
+            4 => decoded("Close Lookup Table")
+                .roles(&["Lookup Table", "Authority", "Recipient"], accounts),
+            tag => return Err(DecodeError::unknown_instruction(tag)),
+        };
+
+        Ok(decoded)
+    }
+}
@@ -0,0 +1,255 @@
+//! Anchor program decoder
+//!
+//! Anchor instruction and account data start with a discriminator that
+//! selects the instruction or account type in the program's IDL; the rest is
+//! Borsh-encoded as the IDL describes. Events emitted with `emit_cpi!` are
+//! self-invocations whose data is [`EVENT_IX_TAG`], the event's
+//! discriminator and the event.
+
+use std::rc::Rc;
+
+use super::{
+    DataReader, DecodeContext, DecodeError, DecodedField, DecodedInstruction, InstructionDecoder,
+    RawInstruction,
+};
+use crate::utils::anchor::borsh::{self, BorshValue};
+use crate::utils::anchor::idl::{title_case, IdlTypeDef};
+use crate::utils::anchor::Idl;
+
+/// Prefix of `emit_cpi!` event instructions, the little-endian bytes of
+/// Anchor's `EVENT_IX_TAG` (`sha256("anchor:event")[..8]` read as a u64)
+pub const EVENT_IX_TAG: [u8; 8] = [0xe4, 0x45, 0xa5, 0x2e, 0x51, 0xcb, 0x9a, 0x1d];
+
+/// Account data decoded with an IDL
+#[derive(Debug, Clone, PartialEq)]
+pub struct DecodedAccount {
+    pub program_name: String,
+    /// Account type name, e.g. "Pool"
+    pub name: String,
+    pub fields: Vec<DecodedField>,
+}
+
+pub struct AnchorDecoder {
+    program_id: String,
+    program_name: String,
+    idl: Rc<Idl>,
+}
+
+impl AnchorDecoder {
+    pub fn new(program_id: impl Into<String>, idl: Rc<Idl>) -> Self {
+        Self {
+            program_id: program_id.into(),
+            program_name: title_case(&idl.name),
+            idl,
+        }
+    }
+
+    /// Decode account data by its discriminator
+    pub fn decode_account(&self, data: &[u8]) -> Result<DecodedAccount, DecodeError> {
+        let account = self
+            .idl
+            .account(data)
+            .ok_or_else(|| DecodeError::new("No account type in the IDL matches the discriminator"))?;
+        let def = self.idl.types.get(&account.name).ok_or_else(|| {
+            DecodeError::new(format!("Account type {} is not defined in the IDL", account.name))
+        })?;
+
+        let mut reader = DataReader::new(&data[account.discriminator.len()..]);
+        Ok(DecodedAccount {
+            program_name: self.program_name.clone(),
+            name: title_case(&account.name),
+            fields: self.decode_fields(def, &mut reader)?,
+        })
+    }
+
+    /// Decode an `emit_cpi!` event, given the data after [`EVENT_IX_TAG`]
+    fn decode_event(&self, data: &[u8], instruction: &RawInstruction) -> Result<DecodedInstruction, DecodeError> {
+        let Some(event) = self.idl.event(data) else {
+            let tag: String = data.iter().take(8).map(|byte| format!("{:02x}", byte)).collect();
+            return Err(DecodeError::new(format!("Unknown event discriminator 0x{}", tag)));
+        };
+        let def = self.idl.types.get(&event.name).ok_or_else(|| {
+            DecodeError::new(format!("Event type {} is not defined in the IDL", event.name))
+        })?;
+
+        let mut reader = DataReader::new(&data[event.discriminator.len()..]);
+        let mut decoded = DecodedInstruction::new(self.program_name(), format!("Event: {}", title_case(&event.name)));
+        decoded.fields = self.decode_fields(def, &mut reader)?;
+        Ok(decoded.roles(&["Event Authority"], &instruction.accounts))
+    }
+
+    /// Fields of a struct, or the whole value as one field for other types
+    fn decode_fields(&self, def: &IdlTypeDef, reader: &mut DataReader) -> Result<Vec<DecodedField>, DecodeError> {
+        Ok(match (def, borsh::decode_def(def, &self.idl.types, reader)?) {
+            (IdlTypeDef::Struct(_), BorshValue::Struct(fields)) => fields
+                .into_iter()
+                .map(|(name, value)| DecodedField {
+                    label: title_case(&name),
+                    value: value.into_field_value(),
+                })
+                .collect(),
+            (_, value) => vec![DecodedField {
+                label: "Data".to_string(),
+                value: value.into_field_value(),
+            }],
+        })
+    }
+}
+
+impl InstructionDecoder for AnchorDecoder {
+    fn program_id(&self) -> &str {
+        &self.program_id
+    }
+
+    fn program_name(&self) -> &str {
+        &self.program_name
+    }
+
+    fn decode(
+        &self,
+        instruction: &RawInstruction,
+        _context: &DecodeContext,
+    ) -> Result<DecodedInstruction, DecodeError> {
+        let data = &instruction.data;
+        if let Some(event) = data.strip_prefix(&EVENT_IX_TAG) {
+            return self.decode_event(event, instruction);
+        }
+        let Some(idl_instruction) = self.idl.instruction(data) else {
+            let tag: String = data.iter().take(8).map(|byte| format!("{:02x}", byte)).collect();
+            return Err(DecodeError::unknown_instruction(format!("discriminator 0x{}", tag)));
+        };
+
+        let mut reader = DataReader::new(&data[idl_instruction.discriminator.len()..]);
+        let mut decoded = DecodedInstruction::new(self.program_name(), title_case(&idl_instruction.name));
+        for arg in &idl_instruction.args {
+            let value = borsh::decode(&arg.ty, &self.idl.types, &mut reader)?;
+            decoded = decoded.field(title_case(&arg.name), value.into_field_value());
+        }
+
+        let roles: Vec<&str> = idl_instruction.accounts.iter().map(String::as_str).collect();
+        Ok(decoded.roles(&roles, &instruction.accounts))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::decoder::{test_instruction, FieldValue};
+    use sha2::{Digest, Sha256};
+
+    const PROGRAM_ID: &str = "Counter111111111111111111111111111111111111";
+    const COUNTER: &str = "9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM";
+
+    /// IDL in the legacy format, with inline event fields
+    const LEGACY_IDL: &str = r#"{
+        "version": "0.1.0",
+        "name": "counter",
+        "instructions": [
+            {
+                "name": "incrementBy",
+                "accounts": [{ "name": "counter", "isMut": true, "isSigner": false }],
+                "args": [{ "name": "amount", "type": "u64" }]
+            }
+        ],
+        "events": [
+            {
+                "name": "Incremented",
+                "fields": [
+                    { "name": "counter", "type": "publicKey", "index": false },
+                    { "name": "total", "type": "u64", "index": false }
+                ]
+            }
+        ]
+    }"#;
+
+    fn decoder(json: &str) -> AnchorDecoder {
+        AnchorDecoder::new(PROGRAM_ID, Rc::new(Idl::from_json(json).unwrap()))
+    }
+
+    fn decode(decoder: &AnchorDecoder, data: Vec<u8>) -> Result<DecodedInstruction, DecodeError> {
+        decoder.decode(&test_instruction(PROGRAM_ID, data, &[COUNTER]), &DecodeContext::default())
+    }
+
+    #[test]
+    fn decodes_instructions_by_legacy_hashed_discriminator() {
+        let mut data = Sha256::digest("global:increment_by")[..8].to_vec();
+        data.extend(5u64.to_le_bytes());
+        let decoded = decode(&decoder(LEGACY_IDL), data).unwrap();
+
+        assert_eq!(decoded.name, "Increment By");
+        assert_eq!(decoded.fields[0].label, "Amount");
+        assert_eq!(decoded.fields[0].value, FieldValue::Number(5));
+        assert_eq!(decoded.accounts[0].role, "Counter");
+        assert_eq!(decoded.accounts[0].account.pubkey, COUNTER);
+    }
+
+    #[test]
+    fn truncated_arguments_are_an_error() {
+        let mut data = Sha256::digest("global:increment_by")[..8].to_vec();
+        data.extend([5, 0, 0]);
+        let error = decode(&decoder(LEGACY_IDL), data).unwrap_err();
+        assert!(error.message.starts_with("Data too short"), "{}", error);
+    }
+
+    #[test]
+    fn unknown_instructions_are_reported() {
+        let error = decode(&decoder(LEGACY_IDL), vec![0xab; 8]).unwrap_err();
+        assert!(error.message.contains("discriminator 0xabababababababab"), "{}", error);
+    }
+
+    #[test]
+    fn event_ix_tag_is_the_anchor_event_hash() {
+        let mut tag = Sha256::digest("anchor:event")[..8].to_vec();
+        tag.reverse();
+        assert_eq!(tag, EVENT_IX_TAG);
+    }
+
+    #[test]
+    fn decodes_emit_cpi_events_of_legacy_idls() {
+        let mut data = EVENT_IX_TAG.to_vec();
+        data.extend_from_slice(&Sha256::digest("event:Incremented")[..8]);
+        data.extend(bs58::decode(COUNTER).into_vec().unwrap());
+        data.extend(42u64.to_le_bytes());
+        let decoded = decode(&decoder(LEGACY_IDL), data).unwrap();
+
+        assert_eq!(decoded.program_name, "Counter");
+        assert_eq!(decoded.name, "Event: Incremented");
+        assert_eq!(decoded.fields[0].label, "Counter");
+        assert_eq!(decoded.fields[0].value, FieldValue::Address(COUNTER.to_string()));
+        assert_eq!(decoded.fields[1].label, "Total");
+        assert_eq!(decoded.fields[1].value, FieldValue::Number(42));
+        assert_eq!(decoded.accounts[0].role, "Event Authority");
+    }
+
+    #[test]
+    fn decodes_emit_cpi_events_of_current_idls() {
+        let idl = r#"{
+            "address": "Counter111111111111111111111111111111111111",
+            "metadata": { "name": "counter", "version": "0.1.0", "spec": "0.1.0" },
+            "instructions": [],
+            "events": [{ "name": "Reset", "discriminator": [1, 2, 3, 4, 5, 6, 7, 8] }],
+            "types": [
+                {
+                    "name": "Reset",
+                    "type": { "kind": "struct", "fields": [{ "name": "previous_total", "type": "u64" }] }
+                }
+            ]
+        }"#;
+        let mut data = EVENT_IX_TAG.to_vec();
+        data.extend([1, 2, 3, 4, 5, 6, 7, 8]);
+        data.extend(7u64.to_le_bytes());
+        let decoded = decode(&decoder(idl), data).unwrap();
+
+        assert_eq!(decoded.name, "Event: Reset");
+        assert_eq!(decoded.fields[0].label, "Previous Total");
+        assert_eq!(decoded.fields[0].value, FieldValue::Number(7));
+    }
+
+    #[test]
+    fn unknown_events_are_reported() {
+        let mut data = EVENT_IX_TAG.to_vec();
+        data.extend([0xff; 8]);
+        let error = decode(&decoder(LEGACY_IDL), data).unwrap_err();
+        assert_eq!(error.message, "Unknown event discriminator 0xffffffffffffffff");
+    }
+}
@@ -0,0 +1,91 @@
+//! Compute Budget program decoder
+//!
+//! Compute Budget instructions are borsh-encoded: a one-byte variant index
+//! followed by a single little-endian value. They take no accounts.
+
+use super::{
+    DataReader, DecodeContext, DecodeError, DecodedInstruction, FieldValue, InstructionDecoder,
+    RawInstruction,
+};
+
+/// Program id of the Compute Budget program
+pub const COMPUTE_BUDGET_PROGRAM_ID: &str = "ComputeBudget111111111111111111111111111111";
+
+pub struct ComputeBudgetDecoder;
+
+impl InstructionDecoder for ComputeBudgetDecoder {
+    fn program_id(&self) -> &str {
+        COMPUTE_BUDGET_PROGRAM_ID
+    }
+
+    fn program_name(&self) -> &str {
+        "Compute Budget Program"
+    }
+
+    fn decode(
+        &self,
+        instruction: &RawInstruction,
+        _context: &DecodeContext,
+    ) -> Result<DecodedInstruction, DecodeError> {
+        let mut data = DataReader::new(&instruction.data);
+        let decoded = |name: &str| DecodedInstruction::new(self.program_name(), name);
+
+        let decoded = match data.u8()? {
+            0 => decoded("Request Units (deprecated)")
+                .field("Compute Units", FieldValue::Number(data.u32()?.into()))
+                .field("Additional Fee", FieldValue::Lamports(data.u32()?.into())),
+            1 => decoded("Request Heap Frame")
+                .field("Heap Size", FieldValue::Text(format!("{} bytes", data.u32()?))),
+            2 => decoded("Set Compute Unit Limit")
+                .field("Compute Units", FieldValue::Number(data.u32()?.into())),
+            3 => decoded("Set Compute Unit Price").field(
+                "Price",
+                FieldValue::Text(format!("{} micro-lamports per compute unit", data.u64()?)),
+            ),
+            4 => decoded("Set Loaded Accounts Data Size Limit")
+                .field("Size Limit", FieldValue::Text(format!("{} bytes", data.u32()?))),
+            tag => return Err(DecodeError::unknown_instruction(tag)),
+        };
+
+        Ok(decoded.roles(&[], &instruction.accounts))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::decoder::test_instruction;
+
+    fn decode(data: &str) -> Result<DecodedInstruction, DecodeError> {
+        let data = bs58::decode(data).into_vec().unwrap();
+        let instruction = test_instruction(COMPUTE_BUDGET_PROGRAM_ID, data, &[]);
+        ComputeBudgetDecoder.decode(&instruction, &DecodeContext::default())
+    }
+
+    #[test]
+    fn decodes_set_compute_unit_price() {
+        // 100,000 micro-lamports per compute unit, as returned by getTransaction
+        let decoded = decode("3gJqkocMWaMm").unwrap();
+        assert_eq!(decoded.name, "Set Compute Unit Price");
+        assert_eq!(
+            decoded.fields[0].value,
+            FieldValue::Text("100000 micro-lamports per compute unit".to_string())
+        );
+    }
+
+    #[test]
+    fn decodes_set_compute_unit_limit() {
+        let decoded = decode("Fj2Eoy").unwrap();
+        assert_eq!(decoded.name, "Set Compute Unit Limit");
+        assert_eq!(decoded.fields[0].value, FieldValue::Number(200_000));
+    }
+
+    #[test]
+    fn truncated_price_is_an_error() {
+        let instruction = test_instruction(COMPUTE_BUDGET_PROGRAM_ID, vec![3, 0xa0, 0x86, 0x01], &[]);
+        let error = ComputeBudgetDecoder
+            .decode(&instruction, &DecodeContext::default())
+            .unwrap_err();
+        assert!(error.message.starts_with("Data too short"), "{}", error);
+    }
+}
@@ -0,0 +1,709 @@
+//! Instruction decoders
+//!
+//! Every [`InstructionDecoder`] understands the instruction data of one
+//! program and turns it into a named instruction with labeled fields and
+//! account roles. Decoders are looked up by program id in a
+//! [`DecoderRegistry`]; instructions of programs without a decoder are shown
+//! as raw data.
+
+pub mod address_lookup_table;
+pub mod anchor;
+pub mod compute_budget;
+pub mod stake;
+pub mod system;
+pub mod token;
+pub mod vote;
+
+use std::collections::HashMap;
+use std::fmt;
+use std::rc::Rc;
+
+use crate::utils::api::{AccountKey, TransactionDetails, TransactionInstruction};
+
+/// Instruction as it appears in a transaction, with its accounts resolved
+#[derive(Debug, Clone, PartialEq)]
+pub struct RawInstruction {
+    pub program_id: String,
+    pub accounts: Vec<AccountKey>,
+    pub data: Vec<u8>,
+}
+
+impl RawInstruction {
+    /// Resolve a top-level instruction against the transaction's account keys
+    ///
+    /// Instruction data that is not valid base58 is kept as its UTF-8 bytes so
+    /// it can still be shown raw.
+    pub fn from_transaction(
+        instruction: &TransactionInstruction,
+        account_keys: &[AccountKey],
+    ) -> Self {
+        let key = |index: u8| {
+            account_keys.get(index as usize).cloned().unwrap_or_else(|| AccountKey {
+                pubkey: format!("Unknown account #{}", index),
+                signer: false,
+                writable: false,
+            })
+        };
+
+        Self {
+            program_id: key(instruction.program_id_index).pubkey,
+            accounts: instruction.accounts.iter().map(|&index| key(index)).collect(),
+            data: bs58::decode(&instruction.data)
+                .into_vec()
+                .unwrap_or_else(|_| instruction.data.as_bytes().to_vec()),
+        }
+    }
+
+    /// All top-level instructions of a transaction
+    pub fn all_from_transaction(transaction: &TransactionDetails) -> Vec<Self> {
+        let account_keys = transaction.account_keys();
+        transaction
+            .transaction
+            .message
+            .instructions
+            .iter()
+            .map(|instruction| Self::from_transaction(instruction, &account_keys))
+            .collect()
+    }
+
+    /// Top-level instructions of a transaction with the instructions each
+    /// invoked, nested by stack height
+    ///
+    /// Inner instructions without a recorded stack height are treated as
+    /// direct invocations of their top-level instruction.
+    pub fn tree_from_transaction(transaction: &TransactionDetails) -> Vec<InstructionNode> {
+        let account_keys = transaction.account_keys();
+        let inner = transaction
+            .meta
+            .as_ref()
+            .and_then(|meta| meta.inner_instructions.as_ref());
+
+        Self::all_from_transaction(transaction)
+            .into_iter()
+            .enumerate()
+            .map(|(index, instruction)| {
+                // Path from the top-level instruction to the latest invocation
+                let mut stack = vec![InstructionNode::new(instruction, 1)];
+                let invoked = inner
+                    .into_iter()
+                    .flatten()
+                    .filter(|inner| inner.index as usize == index)
+                    .flat_map(|inner| &inner.instructions);
+                for inner in invoked {
+                    let height = inner.stack_height.unwrap_or(2).clamp(2, stack.len() as u32 + 1);
+                    while stack.len() as u32 >= height {
+                        InstructionNode::pop_into_parent(&mut stack);
+                    }
+                    let instruction = Self::from_transaction(&inner.instruction, &account_keys);
+                    stack.push(InstructionNode::new(instruction, height));
+                }
+                while stack.len() > 1 {
+                    InstructionNode::pop_into_parent(&mut stack);
+                }
+                stack.remove(0)
+            })
+            .collect()
+    }
+
+    /// Instruction data as base58, the way the RPC returns it
+    pub fn data_base58(&self) -> String {
+        bs58::encode(&self.data).into_string()
+    }
+}
+
+/// Instruction with the instructions it invoked
+#[derive(Debug, Clone, PartialEq)]
+pub struct InstructionNode {
+    pub instruction: RawInstruction,
+    /// Invocation depth, 1 for top-level instructions
+    pub stack_height: u32,
+    /// Cross-program invocations in execution order
+    pub children: Vec<InstructionNode>,
+}
+
+impl InstructionNode {
+    fn new(instruction: RawInstruction, stack_height: u32) -> Self {
+        Self {
+            instruction,
+            stack_height,
+            children: Vec::new(),
+        }
+    }
+
+    /// Close the innermost invocation on `stack`, attaching it to its caller
+    fn pop_into_parent(stack: &mut Vec<InstructionNode>) {
+        if let Some(node) = stack.pop() {
+            if let Some(parent) = stack.last_mut() {
+                parent.children.push(node);
+            }
+        }
+    }
+
+    /// This instruction and all it invoked, depth first
+    pub fn iter(&self) -> Box<dyn Iterator<Item = &InstructionNode> + '_> {
+        Box::new(std::iter::once(self).chain(self.children.iter().flat_map(InstructionNode::iter)))
+    }
+}
+
+/// What a transaction reveals beyond its instructions that decoders can use
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct DecodeContext {
+    /// Decimals of each mint with a token balance in the transaction
+    pub mint_decimals: HashMap<String, u8>,
+    /// Mint of each token account with a token balance in the transaction
+    pub token_account_mints: HashMap<String, String>,
+}
+
+impl DecodeContext {
+    /// Collect mints and decimals from the transaction's token balances
+    pub fn from_transaction(transaction: &TransactionDetails) -> Self {
+        let mut context = Self::default();
+        let Some(meta) = &transaction.meta else {
+            return context;
+        };
+
+        let account_keys = transaction.account_keys();
+        let balances = meta.pre_token_balances.iter().chain(meta.post_token_balances.iter()).flatten();
+        for balance in balances {
+            context
+                .mint_decimals
+                .insert(balance.mint.clone(), balance.ui_token_amount.decimals);
+            if let Some(account) = account_keys.get(balance.account_index as usize) {
+                context
+                    .token_account_mints
+                    .insert(account.pubkey.clone(), balance.mint.clone());
+            }
+        }
+        context
+    }
+
+    /// Decimals of a mint, or of the mint of a token account
+    pub fn decimals(&self, mint_or_token_account: &str) -> Option<u8> {
+        let mint = self
+            .token_account_mints
+            .get(mint_or_token_account)
+            .map(String::as_str)
+            .unwrap_or(mint_or_token_account);
+        self.mint_decimals.get(mint).copied()
+    }
+}
+
+/// Value of a decoded instruction field
+#[derive(Debug, Clone, PartialEq)]
+pub enum FieldValue {
+    /// Base58 account address, shown as a link
+    Address(String),
+    /// Amount of lamports, shown in SOL
+    Lamports(u64),
+    /// Raw token amount, scaled by the mint's decimals when they are known
+    TokenAmount { amount: u64, decimals: Option<u8> },
+    Number(u128),
+    Text(String),
+}
+
+impl fmt::Display for FieldValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FieldValue::Address(address) => write!(f, "{}", address),
+            FieldValue::Lamports(lamports) => {
+                write!(f, "{} SOL ({} lamports)", format_sol(*lamports), lamports)
+            }
+            FieldValue::TokenAmount { amount, decimals: Some(decimals) } => {
+                write!(f, "{}", format_token_amount(*amount, *decimals))
+            }
+            FieldValue::TokenAmount { amount, decimals: None } => {
+                write!(f, "{} (raw, mint decimals unknown)", amount)
+            }
+            FieldValue::Number(value) => write!(f, "{}", value),
+            FieldValue::Text(text) => write!(f, "{}", text),
+        }
+    }
+}
+
+impl FieldValue {
+    /// Address, or "None" if an optional address is not set
+    pub fn optional_address(address: Option<String>) -> Self {
+        match address {
+            Some(address) => FieldValue::Address(address),
+            None => FieldValue::Text("None".to_string()),
+        }
+    }
+}
+
+/// Format lamports as SOL without trailing zeros
+fn format_sol(lamports: u64) -> String {
+    format_token_amount(lamports, 9)
+}
+
+/// Format a raw amount with `decimals` decimal places, without trailing zeros
+///
+/// Decimals come from instruction data and may be garbage in failed
+/// transactions; amounts with more decimals than a u128 scale can hold are
+/// shown raw.
+fn format_token_amount(amount: u64, decimals: u8) -> String {
+    let Some(scale) = 10u128.checked_pow(decimals as u32) else {
+        return format!("{} (raw, invalid decimals {})", amount, decimals);
+    };
+    let amount = amount as u128;
+    let formatted = format!(
+        "{}.{:0width$}",
+        amount / scale,
+        amount % scale,
+        width = decimals as usize
+    );
+    formatted.trim_end_matches('0').trim_end_matches('.').to_string()
+}
+
+/// Labeled field of a decoded instruction
+#[derive(Debug, Clone, PartialEq)]
+pub struct DecodedField {
+    pub label: String,
+    pub value: FieldValue,
+}
+
+/// Account passed to a decoded instruction and what the program uses it for
+#[derive(Debug, Clone, PartialEq)]
+pub struct AccountRole {
+    pub role: String,
+    pub account: AccountKey,
+}
+
+/// Instruction decoded by an [`InstructionDecoder`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct DecodedInstruction {
+    pub program_name: String,
+    /// Instruction name, e.g. "Transfer"
+    pub name: String,
+    pub fields: Vec<DecodedField>,
+    pub accounts: Vec<AccountRole>,
+}
+
+impl DecodedInstruction {
+    pub fn new(program_name: impl Into<String>, name: impl Into<String>) -> Self {
+        Self {
+            program_name: program_name.into(),
+            name: name.into(),
+            fields: Vec::new(),
+            accounts: Vec::new(),
+        }
+    }
+
+    /// Add a labeled field
+    pub fn field(mut self, label: impl Into<String>, value: FieldValue) -> Self {
+        self.fields.push(DecodedField { label: label.into(), value });
+        self
+    }
+
+    /// Name the instruction's accounts in order; accounts beyond `roles` are
+    /// labeled by position and missing optional accounts are skipped
+    pub fn roles(mut self, roles: &[&str], accounts: &[AccountKey]) -> Self {
+        self.accounts = accounts
+            .iter()
+            .enumerate()
+            .map(|(index, account)| AccountRole {
+                role: roles
+                    .get(index)
+                    .map(|role| role.to_string())
+                    .unwrap_or_else(|| format!("Account #{}", index + 1)),
+                account: account.clone(),
+            })
+            .collect();
+        self
+    }
+}
+
+/// Why instruction data could not be decoded
+#[derive(Debug, Clone, PartialEq)]
+pub struct DecodeError {
+    pub message: String,
+}
+
+impl DecodeError {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self { message: message.into() }
+    }
+
+    pub fn unknown_instruction(tag: impl fmt::Display) -> Self {
+        Self::new(format!("Unknown instruction {}", tag))
+    }
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for DecodeError {}
+
+/// Decodes the instructions of one program
+pub trait InstructionDecoder {
+    /// Program id this decoder handles
+    fn program_id(&self) -> &str;
+
+    /// Human-readable program name
+    fn program_name(&self) -> &str;
+
+    fn decode(
+        &self,
+        instruction: &RawInstruction,
+        context: &DecodeContext,
+    ) -> Result<DecodedInstruction, DecodeError>;
+}
+
+/// Instruction decoders keyed by program id
+#[derive(Default, Clone)]
+pub struct DecoderRegistry {
+    decoders: HashMap<String, Rc<dyn InstructionDecoder>>,
+}
+
+impl DecoderRegistry {
+    /// Registry with the decoders for built-in programs
+    pub fn builtin() -> Self {
+        let mut registry = Self::default();
+        registry.register(Rc::new(system::SystemDecoder));
+        registry.register(Rc::new(compute_budget::ComputeBudgetDecoder));
+        registry.register(Rc::new(stake::StakeDecoder));
+        registry.register(Rc::new(vote::VoteDecoder));
+        registry.register(Rc::new(address_lookup_table::AddressLookupTableDecoder));
+        registry.register(Rc::new(token::TokenDecoder::spl_token()));
+        registry.register(Rc::new(token::TokenDecoder::token_2022()));
+        registry
+    }
+
+    /// Registry shared by the UI
+    pub fn shared() -> Rc<DecoderRegistry> {
+        thread_local! {
+            static SHARED: Rc<DecoderRegistry> = Rc::new(DecoderRegistry::builtin());
+        }
+        SHARED.with(Rc::clone)
+    }
+
+    /// Add a decoder, replacing any registered for the same program
+    pub fn register(&mut self, decoder: Rc<dyn InstructionDecoder>) {
+        self.decoders.insert(decoder.program_id().to_string(), decoder);
+    }
+
+    pub fn get(&self, program_id: &str) -> Option<&dyn InstructionDecoder> {
+        self.decoders.get(program_id).map(|decoder| decoder.as_ref())
+    }
+
+    /// Name of a program with a registered decoder
+    pub fn program_name(&self, program_id: &str) -> Option<&str> {
+        self.get(program_id).map(|decoder| decoder.program_name())
+    }
+
+    /// Decode an instruction, or `None` if no decoder handles its program
+    pub fn decode(
+        &self,
+        instruction: &RawInstruction,
+        context: &DecodeContext,
+    ) -> Option<Result<DecodedInstruction, DecodeError>> {
+        self.get(&instruction.program_id)
+            .map(|decoder| decoder.decode(instruction, context))
+    }
+}
+
+/// Little-endian reader over instruction or account data
+pub struct DataReader<'a> {
+    data: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> DataReader<'a> {
+    pub fn new(data: &'a [u8]) -> Self {
+        Self { data, offset: 0 }
+    }
+
+    /// Next `len` bytes
+    pub fn bytes(&mut self, len: usize) -> Result<&'a [u8], DecodeError> {
+        let end = self.offset.checked_add(len).filter(|end| *end <= self.data.len()).ok_or_else(|| {
+            DecodeError::new(format!(
+                "Data too short: needed {} bytes at offset {}, {} available",
+                len,
+                self.offset,
+                self.data.len().saturating_sub(self.offset)
+            ))
+        })?;
+        let bytes = &self.data[self.offset..end];
+        self.offset = end;
+        Ok(bytes)
+    }
+
+    fn array<const N: usize>(&mut self) -> Result<[u8; N], DecodeError> {
+        let mut array = [0u8; N];
+        array.copy_from_slice(self.bytes(N)?);
+        Ok(array)
+    }
+
+    pub fn u8(&mut self) -> Result<u8, DecodeError> {
+        Ok(self.array::<1>()?[0])
+    }
+
+    pub fn i8(&mut self) -> Result<i8, DecodeError> {
+        Ok(i8::from_le_bytes(self.array()?))
+    }
+
+    pub fn u16(&mut self) -> Result<u16, DecodeError> {
+        Ok(u16::from_le_bytes(self.array()?))
+    }
+
+    pub fn i16(&mut self) -> Result<i16, DecodeError> {
+        Ok(i16::from_le_bytes(self.array()?))
+    }
+
+    pub fn u32(&mut self) -> Result<u32, DecodeError> {
+        Ok(u32::from_le_bytes(self.array()?))
+    }
+
+    pub fn i32(&mut self) -> Result<i32, DecodeError> {
+        Ok(i32::from_le_bytes(self.array()?))
+    }
+
+    pub fn u64(&mut self) -> Result<u64, DecodeError> {
+        Ok(u64::from_le_bytes(self.array()?))
+    }
+
+    pub fn i64(&mut self) -> Result<i64, DecodeError> {
+        Ok(i64::from_le_bytes(self.array()?))
+    }
+
+    pub fn u128(&mut self) -> Result<u128, DecodeError> {
+        Ok(u128::from_le_bytes(self.array()?))
+    }
+
+    pub fn i128(&mut self) -> Result<i128, DecodeError> {
+        Ok(i128::from_le_bytes(self.array()?))
+    }
+
+    pub fn f32(&mut self) -> Result<f32, DecodeError> {
+        Ok(f32::from_le_bytes(self.array()?))
+    }
+
+    pub fn f64(&mut self) -> Result<f64, DecodeError> {
+        Ok(f64::from_le_bytes(self.array()?))
+    }
+
+    /// 32-byte public key as base58
+    pub fn pubkey(&mut self) -> Result<String, DecodeError> {
+        Ok(bs58::encode(self.bytes(32)?).into_string())
+    }
+
+    /// Bincode `Option`: a one-byte tag, followed by the value if the tag is 1
+    pub fn option<T>(
+        &mut self,
+        read: impl FnOnce(&mut Self) -> Result<T, DecodeError>,
+    ) -> Result<Option<T>, DecodeError> {
+        match self.u8()? {
+            0 => Ok(None),
+            1 => read(self).map(Some),
+            tag => Err(DecodeError::new(format!("Invalid option tag {}", tag))),
+        }
+    }
+
+    /// LEB128 variable-length integer, as used by `serde_varint`
+    pub fn varint(&mut self) -> Result<u64, DecodeError> {
+        let mut value = 0u64;
+        for shift in (0..64).step_by(7) {
+            let byte = self.u8()?;
+            value |= u64::from(byte & 0x7f) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err(DecodeError::new("Variable-length integer overflows"))
+    }
+
+    /// Length prefix of a `short_vec` (compact-u16)
+    pub fn short_vec_len(&mut self) -> Result<usize, DecodeError> {
+        let len = self.varint()?;
+        usize::try_from(len)
+            .ok()
+            .filter(|len| *len <= u16::MAX as usize)
+            .ok_or_else(|| DecodeError::new("Vector length overflows"))
+    }
+
+    /// 32-byte hash as base58
+    pub fn hash(&mut self) -> Result<String, DecodeError> {
+        self.pubkey()
+    }
+
+    /// `COption<Pubkey>` as packed by the token programs: a one-byte tag,
+    /// followed by the key if the tag is 1
+    pub fn coption_pubkey(&mut self) -> Result<Option<String>, DecodeError> {
+        self.option(Self::pubkey)
+    }
+
+    /// `OptionalNonZeroPubkey`: 32 bytes, all zero for `None`
+    pub fn optional_nonzero_pubkey(&mut self) -> Result<Option<String>, DecodeError> {
+        let bytes = self.bytes(32)?;
+        if bytes.iter().all(|byte| *byte == 0) {
+            Ok(None)
+        } else {
+            Ok(Some(bs58::encode(bytes).into_string()))
+        }
+    }
+
+    /// Bincode string: u64 length followed by UTF-8 bytes
+    pub fn bincode_string(&mut self) -> Result<String, DecodeError> {
+        let len = self.u64()?;
+        let len = usize::try_from(len).map_err(|_| DecodeError::new("String length overflows"))?;
+        let bytes = self.bytes(len)?;
+        String::from_utf8(bytes.to_vec()).map_err(|_| DecodeError::new("String is not valid UTF-8"))
+    }
+
+    /// Borsh string: u32 length followed by UTF-8 bytes
+    pub fn borsh_string(&mut self) -> Result<String, DecodeError> {
+        let len = self.u32()? as usize;
+        let bytes = self.bytes(len)?;
+        String::from_utf8(bytes.to_vec()).map_err(|_| DecodeError::new("String is not valid UTF-8"))
+    }
+
+    /// Number of bytes not read yet
+    pub fn remaining(&self) -> usize {
+        self.data.len() - self.offset
+    }
+
+    /// Bytes not read yet
+    pub fn rest(&mut self) -> &'a [u8] {
+        let rest = &self.data[self.offset..];
+        self.offset = self.data.len();
+        rest
+    }
+}
+
+/// Instruction with the given data and writable, non-signer accounts
+#[cfg(test)]
+pub(crate) fn test_instruction(program_id: &str, data: Vec<u8>, accounts: &[&str]) -> RawInstruction {
+    RawInstruction {
+        program_id: program_id.to_string(),
+        accounts: accounts
+            .iter()
+            .map(|pubkey| AccountKey {
+                pubkey: pubkey.to_string(),
+                signer: false,
+                writable: true,
+            })
+            .collect(),
+        data,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Transaction calling program 1 and then program 2, in the JSON format of
+    /// `getTransaction`; each inner instruction's data is its program's index
+    fn transaction(inner_instructions: serde_json::Value) -> TransactionDetails {
+        let mut account_keys = vec!["Payer111111111111111111111111111111111111111".to_string()];
+        account_keys.extend((1..=5).map(|n| format!("Program{}11111111111111111111111111111111111", n)));
+        serde_json::from_value(serde_json::json!({
+            "slot": 1,
+            "transaction": {
+                "signatures": ["signature"],
+                "message": {
+                    "accountKeys": account_keys,
+                    "header": {
+                        "numRequiredSignatures": 1,
+                        "numReadonlySignedAccounts": 0,
+                        "numReadonlyUnsignedAccounts": 5
+                    },
+                    "instructions": [
+                        { "programIdIndex": 1, "accounts": [0], "data": "2" },
+                        { "programIdIndex": 2, "accounts": [0], "data": "3" }
+                    ],
+                    "recentBlockhash": "11111111111111111111111111111111"
+                }
+            },
+            "meta": {
+                "err": null,
+                "fee": 5000,
+                "innerInstructions": inner_instructions,
+                "preBalances": [],
+                "postBalances": []
+            }
+        }))
+        .unwrap()
+    }
+
+    fn inner(program_index: u8, stack_height: Option<u32>) -> serde_json::Value {
+        serde_json::json!({
+            "programIdIndex": program_index,
+            "accounts": [],
+            "data": bs58::encode([program_index]).into_string(),
+            "stackHeight": stack_height
+        })
+    }
+
+    /// Program indexes of a node's children
+    fn children(node: &InstructionNode) -> Vec<u8> {
+        node.children.iter().map(|child| child.instruction.data[0]).collect()
+    }
+
+    #[test]
+    fn inner_instructions_nest_by_stack_height() {
+        // Program 1 invokes 3, which invokes 4; then program 1 invokes 5
+        let tree = RawInstruction::tree_from_transaction(&transaction(serde_json::json!([
+            { "index": 0, "instructions": [inner(3, Some(2)), inner(4, Some(3)), inner(5, Some(2))] }
+        ])));
+
+        assert_eq!(tree.len(), 2);
+        assert_eq!(tree[0].stack_height, 1);
+        assert_eq!(children(&tree[0]), [3, 5]);
+        assert_eq!(children(&tree[0].children[0]), [4]);
+        assert_eq!(tree[0].children[0].children[0].stack_height, 3);
+        assert_eq!(
+            tree[0].children[0].children[0].instruction.program_id,
+            "Program411111111111111111111111111111111111"
+        );
+        assert!(tree[0].children[1].children.is_empty());
+        assert!(tree[1].children.is_empty());
+    }
+
+    #[test]
+    fn inner_instructions_without_stack_height_are_direct_invocations() {
+        let tree = RawInstruction::tree_from_transaction(&transaction(serde_json::json!([
+            { "index": 1, "instructions": [inner(3, None), inner(4, None)] }
+        ])));
+
+        assert!(tree[0].children.is_empty());
+        assert_eq!(children(&tree[1]), [3, 4]);
+        assert!(tree[1].children.iter().all(|child| child.stack_height == 2));
+    }
+
+    #[test]
+    fn data_reader_reports_truncated_data() {
+        let mut reader = DataReader::new(&[1, 2, 3]);
+        assert_eq!(reader.u16(), Ok(0x0201));
+        let error = reader.u32().unwrap_err();
+        assert_eq!(error.message, "Data too short: needed 4 bytes at offset 2, 1 available");
+        // A failed read consumes nothing
+        assert_eq!(reader.u8(), Ok(3));
+        assert_eq!(reader.remaining(), 0);
+    }
+
+    #[test]
+    fn data_reader_reads_varints_and_options() {
+        let mut reader = DataReader::new(&[0xac, 0x02, 1, 7, 0, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(reader.varint(), Ok(300));
+        assert_eq!(reader.option(DataReader::u64), Ok(Some(7)));
+        assert_eq!(reader.option(DataReader::u64), Ok(None));
+        assert!(DataReader::new(&[0xff; 10]).varint().is_err());
+    }
+
+    #[test]
+    fn format_token_amount_scales_by_decimals() {
+        assert_eq!(format_token_amount(1_500, 0), "1500");
+        assert_eq!(format_token_amount(1_500_000_000, 9), "1.5");
+        assert_eq!(format_token_amount(1, 9), "0.000000001");
+        assert_eq!(format_token_amount(u64::MAX, 19), "1.8446744073709551615");
+        assert_eq!(format_token_amount(0, 19), "0");
+    }
+
+    #[test]
+    fn format_token_amount_shows_invalid_decimals_raw() {
+        assert_eq!(format_token_amount(42, 255), "42 (raw, invalid decimals 255)");
+        assert_eq!(format_token_amount(42, 39), "42 (raw, invalid decimals 39)");
+    }
+}
@@ -0,0 +1,208 @@
+//! Stake program decoder
+//!
+//! Stake instructions are bincode-encoded like System instructions: a u32
+//! variant index, then the variant's fields. Options carry a one-byte tag.
+
+use super::{
+    DataReader, DecodeContext, DecodeError, DecodedInstruction, FieldValue, InstructionDecoder,
+    RawInstruction,
+};
+use crate::utils::address_utils::format_timestamp;
+use crate::utils::api::STAKE_PROGRAM_ID;
+
+pub struct StakeDecoder;
+
+impl InstructionDecoder for StakeDecoder {
+    fn program_id(&self) -> &str {
+        STAKE_PROGRAM_ID
+    }
+
+    fn program_name(&self) -> &str {
+        "Stake Program"
+    }
+
+    fn decode(
+        &self,
+        instruction: &RawInstruction,
+        _context: &DecodeContext,
+    ) -> Result<DecodedInstruction, DecodeError> {
+        let mut data = DataReader::new(&instruction.data);
+        let accounts = &instruction.accounts;
+        let decoded = |name: &str| DecodedInstruction::new(self.program_name(), name);
+
+        let decoded = match data.u32()? {
+            0 => decoded("Initialize")
+                .field("Staker", FieldValue::Address(data.pubkey()?))
+                .field("Withdrawer", FieldValue::Address(data.pubkey()?))
+                .field("Lockup Until", unix_timestamp(data.i64()?))
+                .field("Lockup Epoch", lockup_epoch(data.u64()?))
+                .field("Lockup Custodian", FieldValue::Address(data.pubkey()?))
+                .roles(&["Stake Account", "Rent Sysvar"], accounts),
+            1 => decoded("Authorize")
+                .field("New Authority", FieldValue::Address(data.pubkey()?))
+                .field("Authority Type", stake_authorize(data.u32()?))
+                .roles(&["Stake Account", "Clock Sysvar", "Authority", "Lockup Custodian"], accounts),
+            2 => decoded("Delegate Stake").roles(
+                &[
+                    "Stake Account",
+                    "Vote Account",
+                    "Clock Sysvar",
+                    "Stake History Sysvar",
+                    "Stake Config",
+                    "Stake Authority",
+                ],
+                accounts,
+            ),
+            3 => decoded("Split")
+                .field("Lamports", FieldValue::Lamports(data.u64()?))
+                .roles(&["Stake Account", "Split Stake Account", "Stake Authority"], accounts),
+            4 => decoded("Withdraw")
+                .field("Lamports", FieldValue::Lamports(data.u64()?))
+                .roles(
+                    &[
+                        "Stake Account",
+                        "Recipient",
+                        "Clock Sysvar",
+                        "Stake History Sysvar",
+                        "Withdraw Authority",
+                        "Lockup Custodian",
+                    ],
+                    accounts,
+                ),
+            5 => decoded("Deactivate")
+                .roles(&["Stake Account", "Clock Sysvar", "Stake Authority"], accounts),
+            6 => decoded("Set Lockup")
+                .field("Lockup Until", optional(data.option(DataReader::i64)?, unix_timestamp))
+                .field("Lockup Epoch", optional(data.option(DataReader::u64)?, lockup_epoch))
+                .field("Lockup Custodian", optional(data.option(DataReader::pubkey)?, FieldValue::Address))
+                .roles(&["Stake Account", "Lockup Authority"], accounts),
+            7 => decoded("Merge").roles(
+                &[
+                    "Destination Stake Account",
+                    "Source Stake Account",
+                    "Clock Sysvar",
+                    "Stake History Sysvar",
+                    "Stake Authority",
+                ],
+                accounts,
+            ),
+            8 => decoded("Authorize With Seed")
+                .field("New Authority", FieldValue::Address(data.pubkey()?))
+                .field("Authority Type", stake_authorize(data.u32()?))
+                .field("Authority Seed", FieldValue::Text(data.bincode_string()?))
+                .field("Authority Owner", FieldValue::Address(data.pubkey()?))
+                .roles(&["Stake Account", "Authority Base", "Clock Sysvar", "Lockup Custodian"], accounts),
+            9 => decoded("Initialize Checked")
+                .roles(&["Stake Account", "Rent Sysvar", "Staker", "Withdrawer"], accounts),
+            10 => decoded("Authorize Checked")
+                .field("Authority Type", stake_authorize(data.u32()?))
+                .roles(
+                    &["Stake Account", "Clock Sysvar", "Authority", "New Authority", "Lockup Custodian"],
+                    accounts,
+                ),
+            11 => decoded("Authorize Checked With Seed")
+                .field("Authority Type", stake_authorize(data.u32()?))
+                .field("Authority Seed", FieldValue::Text(data.bincode_string()?))
+                .field("Authority Owner", FieldValue::Address(data.pubkey()?))
+                .roles(
+                    &["Stake Account", "Authority Base", "Clock Sysvar", "New Authority", "Lockup Custodian"],
+                    accounts,
+                ),
+            12 => decoded("Set Lockup Checked")
+                .field("Lockup Until", optional(data.option(DataReader::i64)?, unix_timestamp))
+                .field("Lockup Epoch", optional(data.option(DataReader::u64)?, lockup_epoch))
+                .roles(&["Stake Account", "Lockup Authority", "New Lockup Custodian"], accounts),
+            13 => decoded("Get Minimum Delegation").roles(&[], accounts),
+            14 => decoded("Deactivate Delinquent").roles(
+                &["Stake Account", "Delinquent Vote Account", "Reference Vote Account"],
+                accounts,
+            ),
+            15 => decoded("Redelegate (deprecated)").roles(
+                &["Stake Account", "New Stake Account", "Vote Account", "Stake Config", "Stake Authority"],
+                accounts,
+            ),
+            16 => decoded("Move Stake")
+                .field("Lamports", FieldValue::Lamports(data.u64()?))
+                .roles(&["Source Stake Account", "Destination Stake Account", "Stake Authority"], accounts),
+            17 => decoded("Move Lamports")
+                .field("Lamports", FieldValue::Lamports(data.u64()?))
+                .roles(&["Source Stake Account", "Destination Stake Account", "Stake Authority"], accounts),
+            tag => return Err(DecodeError::unknown_instruction(tag)),
+        };
+
+        Ok(decoded)
+    }
+}
+
+/// `StakeAuthorize` value
+fn stake_authorize(value: u32) -> FieldValue {
+    FieldValue::Text(match value {
+        0 => "Staker".to_string(),
+        1 => "Withdrawer".to_string(),
+        value => format!("Unknown ({})", value),
+    })
+}
+
+/// Lockup end time; zero means no time lockup
+fn unix_timestamp(timestamp: i64) -> FieldValue {
+    if timestamp <= 0 {
+        FieldValue::Text("None".to_string())
+    } else {
+        FieldValue::Text(format!("{} UTC", format_timestamp(timestamp as u64)))
+    }
+}
+
+/// Lockup end epoch; zero means no epoch lockup
+fn lockup_epoch(epoch: u64) -> FieldValue {
+    if epoch == 0 {
+        FieldValue::Text("None".to_string())
+    } else {
+        FieldValue::Number(epoch.into())
+    }
+}
+
+/// Optional lockup argument; `None` leaves the current value unchanged
+fn optional<T>(value: Option<T>, field: impl FnOnce(T) -> FieldValue) -> FieldValue {
+    value
+        .map(field)
+        .unwrap_or_else(|| FieldValue::Text("Unchanged".to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::decoder::test_instruction;
+
+    #[test]
+    fn decodes_delegate_stake() {
+        // DelegateStake carries no arguments: its data is the u32 variant index
+        let data = bs58::decode("3xyZh").into_vec().unwrap();
+        let accounts = [
+            "StakeAccount1111111111111111111111111111111",
+            "VoteAccount11111111111111111111111111111111",
+            "SysvarC1ock11111111111111111111111111111111",
+            "SysvarStakeHistory1111111111111111111111111",
+            "StakeConfig11111111111111111111111111111111",
+            "StakeAuthority11111111111111111111111111111",
+        ];
+        let decoded = StakeDecoder
+            .decode(&test_instruction(STAKE_PROGRAM_ID, data, &accounts), &DecodeContext::default())
+            .unwrap();
+
+        assert_eq!(decoded.program_name, "Stake Program");
+        assert_eq!(decoded.name, "Delegate Stake");
+        assert!(decoded.fields.is_empty());
+        let roles: Vec<&str> = decoded.accounts.iter().map(|account| account.role.as_str()).collect();
+        assert_eq!(
+            roles,
+            [
+                "Stake Account",
+                "Vote Account",
+                "Clock Sysvar",
+                "Stake History Sysvar",
+                "Stake Config",
+                "Stake Authority"
+            ]
+        );
+    }
+}
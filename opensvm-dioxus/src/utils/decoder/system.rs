@@ -0,0 +1,142 @@
+//! System Program decoder
+//!
+//! System instructions are bincode-encoded: a u32 variant index followed by
+//! the variant's fields, with strings prefixed by a u64 length.
+
+use super::{
+    DataReader, DecodeContext, DecodeError, DecodedInstruction, FieldValue, InstructionDecoder,
+    RawInstruction,
+};
+
+/// Program id of the System Program
+pub const SYSTEM_PROGRAM_ID: &str = "11111111111111111111111111111111";
+
+pub struct SystemDecoder;
+
+impl InstructionDecoder for SystemDecoder {
+    fn program_id(&self) -> &str {
+        SYSTEM_PROGRAM_ID
+    }
+
+    fn program_name(&self) -> &str {
+        "System Program"
+    }
+
+    fn decode(
+        &self,
+        instruction: &RawInstruction,
+        _context: &DecodeContext,
+    ) -> Result<DecodedInstruction, DecodeError> {
+        let mut data = DataReader::new(&instruction.data);
+        let accounts = &instruction.accounts;
+        let tag = data.u32()?;
+        let decoded = |name: &str| DecodedInstruction::new(self.program_name(), name);
+
+        let decoded = match tag {
+            0 => decoded("Create Account")
+                .field("Lamports", FieldValue::Lamports(data.u64()?))
+                .field("Space", FieldValue::Number(data.u64()?.into()))
+                .field("Owner", FieldValue::Address(data.pubkey()?))
+                .roles(&["Funding Account", "New Account"], accounts),
+            1 => decoded("Assign")
+                .field("Owner", FieldValue::Address(data.pubkey()?))
+                .roles(&["Assigned Account"], accounts),
+            2 => decoded("Transfer")
+                .field("Lamports", FieldValue::Lamports(data.u64()?))
+                .roles(&["Source", "Destination"], accounts),
+            3 => decoded("Create Account With Seed")
+                .field("Base", FieldValue::Address(data.pubkey()?))
+                .field("Seed", FieldValue::Text(data.bincode_string()?))
+                .field("Lamports", FieldValue::Lamports(data.u64()?))
+                .field("Space", FieldValue::Number(data.u64()?.into()))
+                .field("Owner", FieldValue::Address(data.pubkey()?))
+                .roles(&["Funding Account", "Created Account", "Base Account"], accounts),
+            4 => decoded("Advance Nonce Account").roles(
+                &["Nonce Account", "Recent Blockhashes Sysvar", "Nonce Authority"],
+                accounts,
+            ),
+            5 => decoded("Withdraw Nonce Account")
+                .field("Lamports", FieldValue::Lamports(data.u64()?))
+                .roles(
+                    &["Nonce Account", "Recipient", "Recent Blockhashes Sysvar", "Rent Sysvar", "Nonce Authority"],
+                    accounts,
+                ),
+            6 => decoded("Initialize Nonce Account")
+                .field("Authority", FieldValue::Address(data.pubkey()?))
+                .roles(&["Nonce Account", "Recent Blockhashes Sysvar", "Rent Sysvar"], accounts),
+            7 => decoded("Authorize Nonce Account")
+                .field("New Authority", FieldValue::Address(data.pubkey()?))
+                .roles(&["Nonce Account", "Nonce Authority"], accounts),
+            8 => decoded("Allocate")
+                .field("Space", FieldValue::Number(data.u64()?.into()))
+                .roles(&["Allocated Account"], accounts),
+            9 => decoded("Allocate With Seed")
+                .field("Base", FieldValue::Address(data.pubkey()?))
+                .field("Seed", FieldValue::Text(data.bincode_string()?))
+                .field("Space", FieldValue::Number(data.u64()?.into()))
+                .field("Owner", FieldValue::Address(data.pubkey()?))
+                .roles(&["Allocated Account", "Base Account"], accounts),
+            10 => decoded("Assign With Seed")
+                .field("Base", FieldValue::Address(data.pubkey()?))
+                .field("Seed", FieldValue::Text(data.bincode_string()?))
+                .field("Owner", FieldValue::Address(data.pubkey()?))
+                .roles(&["Assigned Account", "Base Account"], accounts),
+            11 => decoded("Transfer With Seed")
+                .field("Lamports", FieldValue::Lamports(data.u64()?))
+                .field("From Seed", FieldValue::Text(data.bincode_string()?))
+                .field("From Owner", FieldValue::Address(data.pubkey()?))
+                .roles(&["Source", "Source Base", "Destination"], accounts),
+            12 => decoded("Upgrade Nonce Account").roles(&["Nonce Account"], accounts),
+            tag => return Err(DecodeError::unknown_instruction(tag)),
+        };
+
+        Ok(decoded)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::decoder::test_instruction;
+
+    const SOURCE: &str = "9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM";
+    const DESTINATION: &str = "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v";
+
+    fn decode(data: &str, accounts: &[&str]) -> Result<DecodedInstruction, DecodeError> {
+        let data = bs58::decode(data).into_vec().unwrap();
+        SystemDecoder.decode(&test_instruction(SYSTEM_PROGRAM_ID, data, accounts), &DecodeContext::default())
+    }
+
+    #[test]
+    fn decodes_transfer() {
+        // Transfer of 0.001 SOL, as returned by getTransaction
+        let decoded = decode("3Bxs4Bc3VYuGVB19", &[SOURCE, DESTINATION]).unwrap();
+
+        assert_eq!(decoded.name, "Transfer");
+        assert_eq!(decoded.fields[0].value, FieldValue::Lamports(1_000_000));
+        assert_eq!(decoded.fields[0].value.to_string(), "0.001 SOL (1000000 lamports)");
+        assert_eq!(decoded.accounts[0].role, "Source");
+        assert_eq!(decoded.accounts[1].role, "Destination");
+    }
+
+    #[test]
+    fn truncated_data_is_an_error() {
+        let data = bs58::decode("3Bxs4Bc3VYuGVB19").into_vec().unwrap();
+        let instruction = test_instruction(SYSTEM_PROGRAM_ID, data[..8].to_vec(), &[SOURCE, DESTINATION]);
+        let error = SystemDecoder.decode(&instruction, &DecodeContext::default()).unwrap_err();
+        assert!(error.message.starts_with("Data too short"), "{}", error);
+
+        let error = SystemDecoder
+            .decode(&test_instruction(SYSTEM_PROGRAM_ID, vec![2, 0], &[]), &DecodeContext::default())
+            .unwrap_err();
+        assert!(error.message.starts_with("Data too short"), "{}", error);
+    }
+
+    #[test]
+    fn unknown_variants_are_reported() {
+        let error = SystemDecoder
+            .decode(&test_instruction(SYSTEM_PROGRAM_ID, vec![99, 0, 0, 0], &[]), &DecodeContext::default())
+            .unwrap_err();
+        assert_eq!(error, DecodeError::unknown_instruction(99));
+    }
+}
@@ -0,0 +1,753 @@
+//! SPL Token and Token-2022 decoders
+//!
+//! Token instructions start with a one-byte tag followed by fixed-size
+//! little-endian fields. Token-2022 keeps the SPL Token instructions and adds
+//! its own from tag 25 on; extension instructions carry a second tag selecting
+//! the extension's instruction. Token-2022 also implements the Token Metadata
+//! and Token Group interfaces, whose instructions start with an 8-byte
+//! discriminator instead, the first bytes of
+//! `sha256("<interface>:<instruction>")`.
+//!
+//! Unchecked instructions do not carry the mint's decimals, so amounts are
+//! scaled with the decimals found in the transaction's token balances.
+
+use std::iter;
+
+use super::{
+    AccountKey, DataReader, DecodeContext, DecodeError, DecodedInstruction, FieldValue,
+    InstructionDecoder, RawInstruction,
+};
+
+/// Program id of the SPL Token program
+pub const TOKEN_PROGRAM_ID: &str = "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA";
+
+/// Program id of the Token-2022 program
+pub const TOKEN_2022_PROGRAM_ID: &str = "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb";
+
+/// Last instruction tag shared by both token programs
+const LAST_SPL_TOKEN_INSTRUCTION: u8 = 24;
+
+/// Role of accounts after the named ones, which sign for a multisig authority
+const MULTISIG_SIGNER: &str = "Multisig Signer";
+
+/// Token Metadata interface instructions, by discriminator
+const TOKEN_METADATA_INITIALIZE: [u8; 8] = [210, 225, 30, 162, 88, 184, 77, 141];
+const TOKEN_METADATA_UPDATE_FIELD: [u8; 8] = [221, 233, 49, 45, 181, 202, 220, 200];
+const TOKEN_METADATA_REMOVE_KEY: [u8; 8] = [234, 18, 32, 56, 89, 141, 37, 181];
+const TOKEN_METADATA_UPDATE_AUTHORITY: [u8; 8] = [215, 228, 166, 228, 84, 100, 86, 123];
+const TOKEN_METADATA_EMIT: [u8; 8] = [250, 166, 180, 250, 13, 12, 184, 70];
+
+/// Token Group interface instructions, by discriminator
+const TOKEN_GROUP_INITIALIZE_GROUP: [u8; 8] = [121, 113, 108, 39, 54, 51, 0, 4];
+const TOKEN_GROUP_UPDATE_MAX_SIZE: [u8; 8] = [108, 37, 171, 143, 248, 30, 18, 110];
+const TOKEN_GROUP_UPDATE_AUTHORITY: [u8; 8] = [161, 105, 88, 1, 237, 221, 216, 203];
+const TOKEN_GROUP_INITIALIZE_MEMBER: [u8; 8] = [152, 32, 222, 176, 223, 237, 116, 134];
+
+/// Names of the fields of the Token Metadata `Field` enum, by discriminant
+const METADATA_FIELDS: [&str; 3] = ["Name", "Symbol", "URI"];
+
+/// Names of `ExtensionType` values, by discriminant
+const EXTENSION_TYPES: [&str; 28] = [
+    "Uninitialized",
+    "Transfer Fee Config",
+    "Transfer Fee Amount",
+    "Mint Close Authority",
+    "Confidential Transfer Mint",
+    "Confidential Transfer Account",
+    "Default Account State",
+    "Immutable Owner",
+    "Memo Transfer",
+    "Non-Transferable",
+    "Interest Bearing Config",
+    "CPI Guard",
+    "Permanent Delegate",
+    "Non-Transferable Account",
+    "Transfer Hook",
+    "Transfer Hook Account",
+    "Confidential Transfer Fee Config",
+    "Confidential Transfer Fee Amount",
+    "Metadata Pointer",
+    "Token Metadata",
+    "Group Pointer",
+    "Token Group",
+    "Group Member Pointer",
+    "Token Group Member",
+    "Confidential Mint Burn",
+    "Scaled UI Amount",
+    "Pausable",
+    "Pausable Account",
+];
+
+/// Names of `AuthorityType` values, by discriminant
+const AUTHORITY_TYPES: [&str; 17] = [
+    "Mint Tokens",
+    "Freeze Account",
+    "Account Owner",
+    "Close Account",
+    "Transfer Fee Config",
+    "Withheld Withdraw",
+    "Close Mint",
+    "Interest Rate",
+    "Permanent Delegate",
+    "Confidential Transfer Mint",
+    "Transfer Hook Program Id",
+    "Confidential Transfer Fee Config",
+    "Metadata Pointer",
+    "Group Pointer",
+    "Group Member Pointer",
+    "Scaled UI Amount",
+    "Pause",
+];
+
+/// Names of `AccountState` values, by discriminant
+const ACCOUNT_STATES: [&str; 3] = ["Uninitialized", "Initialized", "Frozen"];
+
+/// Instructions of the confidential extensions, whose data is mostly
+/// zero-knowledge proof material and is not decoded further
+const CONFIDENTIAL_TRANSFER_INSTRUCTIONS: [&str; 15] = [
+    "Initialize Mint",
+    "Update Mint",
+    "Configure Account",
+    "Approve Account",
+    "Empty Account",
+    "Deposit",
+    "Withdraw",
+    "Transfer",
+    "Apply Pending Balance",
+    "Enable Confidential Credits",
+    "Disable Confidential Credits",
+    "Enable Non-Confidential Credits",
+    "Disable Non-Confidential Credits",
+    "Transfer With Fee",
+    "Configure Account With Registry",
+];
+const CONFIDENTIAL_TRANSFER_FEE_INSTRUCTIONS: [&str; 6] = [
+    "Initialize Config",
+    "Withdraw Withheld Tokens From Mint",
+    "Withdraw Withheld Tokens From Accounts",
+    "Harvest Withheld Tokens To Mint",
+    "Enable Harvest To Mint",
+    "Disable Harvest To Mint",
+];
+const CONFIDENTIAL_MINT_BURN_INSTRUCTIONS: [&str; 6] = [
+    "Initialize Mint",
+    "Rotate Supply ElGamal Pubkey",
+    "Update Decryptable Supply",
+    "Mint",
+    "Burn",
+    "Apply Pending Burn",
+];
+
+pub struct TokenDecoder {
+    program_id: &'static str,
+    program_name: &'static str,
+    /// Whether Token-2022 instructions are understood
+    extensions: bool,
+}
+
+impl TokenDecoder {
+    pub fn spl_token() -> Self {
+        Self {
+            program_id: TOKEN_PROGRAM_ID,
+            program_name: "Token Program",
+            extensions: false,
+        }
+    }
+
+    pub fn token_2022() -> Self {
+        Self {
+            program_id: TOKEN_2022_PROGRAM_ID,
+            program_name: "Token-2022 Program",
+            extensions: true,
+        }
+    }
+}
+
+impl InstructionDecoder for TokenDecoder {
+    fn program_id(&self) -> &str {
+        self.program_id
+    }
+
+    fn program_name(&self) -> &str {
+        self.program_name
+    }
+
+    fn decode(
+        &self,
+        instruction: &RawInstruction,
+        context: &DecodeContext,
+    ) -> Result<DecodedInstruction, DecodeError> {
+        if self.extensions {
+            if let Some(decoded) = self.decode_interface(instruction)? {
+                return Ok(decoded);
+            }
+        }
+
+        let mut data = DataReader::new(&instruction.data);
+        let accounts = &instruction.accounts;
+        let tag = data.u8()?;
+        if tag > LAST_SPL_TOKEN_INSTRUCTION && !self.extensions {
+            return Err(DecodeError::unknown_instruction(tag));
+        }
+
+        let decoded = |name: &str| DecodedInstruction::new(self.program_name, name);
+        let roles = |roles: &[&'static str]| with_signers(roles, accounts.len());
+        // Decimals of the mint or token account passed at `index`
+        let decimals_of = |index: usize| {
+            accounts
+                .get(index)
+                .and_then(|account| context.decimals(&account.pubkey))
+        };
+        let amount = |amount: u64, decimals: Option<u8>| FieldValue::TokenAmount { amount, decimals };
+
+        let decoded = match tag {
+            0 | 20 => {
+                let name = if tag == 0 { "Initialize Mint" } else { "Initialize Mint 2" };
+                let account_roles: &[&'static str] = if tag == 0 { &["Mint", "Rent Sysvar"] } else { &["Mint"] };
+                decoded(name)
+                    .field("Decimals", FieldValue::Number(data.u8()?.into()))
+                    .field("Mint Authority", FieldValue::Address(data.pubkey()?))
+                    .field("Freeze Authority", FieldValue::optional_address(data.coption_pubkey()?))
+                    .roles(account_roles, accounts)
+            }
+            1 => decoded("Initialize Account")
+                .roles(&["Account", "Mint", "Owner", "Rent Sysvar"], accounts),
+            2 | 19 => {
+                let name = if tag == 2 { "Initialize Multisig" } else { "Initialize Multisig 2" };
+                let account_roles: &[&'static str] = if tag == 2 { &["Multisig", "Rent Sysvar"] } else { &["Multisig"] };
+                decoded(name)
+                    .field("Required Signers", FieldValue::Number(data.u8()?.into()))
+                    .roles(&roles(account_roles), accounts)
+            }
+            3 => decoded("Transfer")
+                .field("Amount", amount(data.u64()?, decimals_of(0).or(decimals_of(1))))
+                .roles(&roles(&["Source", "Destination", "Authority"]), accounts),
+            4 => decoded("Approve")
+                .field("Amount", amount(data.u64()?, decimals_of(0)))
+                .roles(&roles(&["Source", "Delegate", "Owner"]), accounts),
+            5 => decoded("Revoke").roles(&roles(&["Source", "Owner"]), accounts),
+            6 => {
+                let authority_type = data.u8()?;
+                decoded("Set Authority")
+                    .field("Authority Type", named(&AUTHORITY_TYPES, authority_type))
+                    .field("New Authority", FieldValue::optional_address(data.coption_pubkey()?))
+                    .roles(&roles(&["Account", "Current Authority"]), accounts)
+            }
+            7 => decoded("Mint To")
+                .field("Amount", amount(data.u64()?, decimals_of(0).or(decimals_of(1))))
+                .roles(&roles(&["Mint", "Destination", "Mint Authority"]), accounts),
+            8 => decoded("Burn")
+                .field("Amount", amount(data.u64()?, decimals_of(1).or(decimals_of(0))))
+                .roles(&roles(&["Account", "Mint", "Owner"]), accounts),
+            9 => decoded("Close Account")
+                .roles(&roles(&["Account", "Destination", "Owner"]), accounts),
+            10 => decoded("Freeze Account")
+                .roles(&roles(&["Account", "Mint", "Freeze Authority"]), accounts),
+            11 => decoded("Thaw Account")
+                .roles(&roles(&["Account", "Mint", "Freeze Authority"]), accounts),
+            12..=15 => {
+                let (name, account_roles): (&str, &[&str]) = match tag {
+                    12 => ("Transfer Checked", &["Source", "Mint", "Destination", "Authority"]),
+                    13 => ("Approve Checked", &["Source", "Mint", "Delegate", "Owner"]),
+                    14 => ("Mint To Checked", &["Mint", "Destination", "Mint Authority"]),
+                    _ => ("Burn Checked", &["Account", "Mint", "Owner"]),
+                };
+                let raw_amount = data.u64()?;
+                let decimals = data.u8()?;
+                decoded(name)
+                    .field("Amount", amount(raw_amount, Some(decimals)))
+                    .field("Decimals", FieldValue::Number(decimals.into()))
+                    .roles(&roles(account_roles), accounts)
+            }
+            16 => decoded("Initialize Account 2")
+                .field("Owner", FieldValue::Address(data.pubkey()?))
+                .roles(&["Account", "Mint", "Rent Sysvar"], accounts),
+            17 => decoded("Sync Native").roles(&["Account"], accounts),
+            18 => decoded("Initialize Account 3")
+                .field("Owner", FieldValue::Address(data.pubkey()?))
+                .roles(&["Account", "Mint"], accounts),
+            21 => decoded("Get Account Data Size")
+                .field("Extensions", extension_types(data.rest())?)
+                .roles(&["Mint"], accounts),
+            22 => decoded("Initialize Immutable Owner").roles(&["Account"], accounts),
+            23 => decoded("Amount To UI Amount")
+                .field("Amount", amount(data.u64()?, decimals_of(0)))
+                .roles(&["Mint"], accounts),
+            24 => {
+                let ui_amount = String::from_utf8_lossy(data.rest()).into_owned();
+                decoded("UI Amount To Amount")
+                    .field("UI Amount", FieldValue::Text(ui_amount))
+                    .roles(&["Mint"], accounts)
+            }
+            25 => decoded("Initialize Mint Close Authority")
+                .field("Close Authority", FieldValue::optional_address(data.coption_pubkey()?))
+                .roles(&["Mint"], accounts),
+            26 => self.decode_transfer_fee(&mut data, instruction, context)?,
+            27 => confidential(decoded("Confidential Transfer"), &CONFIDENTIAL_TRANSFER_INSTRUCTIONS, &mut data, accounts)?,
+            28 => {
+                let (name, account_roles): (&str, &[&str]) = match data.u8()? {
+                    0 => ("Initialize", &["Mint"]),
+                    1 => ("Update", &["Mint", "Freeze Authority"]),
+                    sub => return Err(DecodeError::unknown_instruction(format!("{}/{}", tag, sub))),
+                };
+                let state = data.u8()?;
+                decoded(&format!("Default Account State: {}", name))
+                    .field("State", named(&ACCOUNT_STATES, state))
+                    .roles(&roles(account_roles), accounts)
+            }
+            29 => decoded("Reallocate")
+                .field("Extensions", extension_types(data.rest())?)
+                .roles(&roles(&["Account", "Payer", "System Program", "Owner"]), accounts),
+            30 | 34 => {
+                let extension = if tag == 30 { "Memo Transfer" } else { "CPI Guard" };
+                let action = match data.u8()? {
+                    0 => "Enable",
+                    1 => "Disable",
+                    sub => return Err(DecodeError::unknown_instruction(format!("{}/{}", tag, sub))),
+                };
+                decoded(&format!("{}: {}", extension, action))
+                    .roles(&roles(&["Account", "Owner"]), accounts)
+            }
+            31 => decoded("Create Native Mint")
+                .roles(&["Payer", "Native Mint", "System Program"], accounts),
+            32 => decoded("Initialize Non-Transferable Mint").roles(&["Mint"], accounts),
+            33 => match data.u8()? {
+                0 => decoded("Interest Bearing Mint: Initialize")
+                    .field("Rate Authority", FieldValue::optional_address(data.optional_nonzero_pubkey()?))
+                    .field("Rate", basis_points(data.i16()?.into()))
+                    .roles(&["Mint"], accounts),
+                1 => decoded("Interest Bearing Mint: Update Rate")
+                    .field("Rate", basis_points(data.i16()?.into()))
+                    .roles(&roles(&["Mint", "Rate Authority"]), accounts),
+                sub => return Err(DecodeError::unknown_instruction(format!("{}/{}", tag, sub))),
+            },
+            35 => decoded("Initialize Permanent Delegate")
+                .field("Delegate", FieldValue::Address(data.pubkey()?))
+                .roles(&["Mint"], accounts),
+            36 | 39 | 40 | 41 => {
+                let (extension, target) = match tag {
+                    36 => ("Transfer Hook", "Program Id"),
+                    39 => ("Metadata Pointer", "Metadata Address"),
+                    40 => ("Group Pointer", "Group Address"),
+                    _ => ("Group Member Pointer", "Member Address"),
+                };
+                match data.u8()? {
+                    0 => decoded(&format!("{}: Initialize", extension))
+                        .field("Authority", FieldValue::optional_address(data.optional_nonzero_pubkey()?))
+                        .field(target, FieldValue::optional_address(data.optional_nonzero_pubkey()?))
+                        .roles(&["Mint"], accounts),
+                    1 => decoded(&format!("{}: Update", extension))
+                        .field(target, FieldValue::optional_address(data.optional_nonzero_pubkey()?))
+                        .roles(&roles(&["Mint", "Authority"]), accounts),
+                    sub => return Err(DecodeError::unknown_instruction(format!("{}/{}", tag, sub))),
+                }
+            }
+            37 => confidential(
+                decoded("Confidential Transfer Fee"),
+                &CONFIDENTIAL_TRANSFER_FEE_INSTRUCTIONS,
+                &mut data,
+                accounts,
+            )?,
+            38 => decoded("Withdraw Excess Lamports")
+                .roles(&roles(&["Source", "Destination", "Authority"]), accounts),
+            42 => confidential(
+                decoded("Confidential Mint Burn"),
+                &CONFIDENTIAL_MINT_BURN_INSTRUCTIONS,
+                &mut data,
+                accounts,
+            )?,
+            43 => match data.u8()? {
+                0 => decoded("Scaled UI Amount: Initialize")
+                    .field("Authority", FieldValue::optional_address(data.optional_nonzero_pubkey()?))
+                    .field("Multiplier", FieldValue::Text(data.f64()?.to_string()))
+                    .roles(&["Mint"], accounts),
+                1 => decoded("Scaled UI Amount: Update Multiplier")
+                    .field("Multiplier", FieldValue::Text(data.f64()?.to_string()))
+                    .field("Effective Timestamp", FieldValue::Text(data.i64()?.to_string()))
+                    .roles(&roles(&["Mint", "Authority"]), accounts),
+                sub => return Err(DecodeError::unknown_instruction(format!("{}/{}", tag, sub))),
+            },
+            44 => match data.u8()? {
+                0 => decoded("Pausable: Initialize")
+                    .field("Authority", FieldValue::Address(data.pubkey()?))
+                    .roles(&["Mint"], accounts),
+                1 => decoded("Pausable: Pause").roles(&roles(&["Mint", "Pause Authority"]), accounts),
+                2 => decoded("Pausable: Resume").roles(&roles(&["Mint", "Pause Authority"]), accounts),
+                sub => return Err(DecodeError::unknown_instruction(format!("{}/{}", tag, sub))),
+            },
+            tag => return Err(DecodeError::unknown_instruction(tag)),
+        };
+
+        Ok(decoded)
+    }
+}
+
+impl TokenDecoder {
+    /// Token Metadata and Token Group interface instructions, or `None` if
+    /// the data does not start with one of their discriminators
+    fn decode_interface(&self, instruction: &RawInstruction) -> Result<Option<DecodedInstruction>, DecodeError> {
+        let Some((discriminator, rest)) = instruction.data.split_first_chunk::<8>() else {
+            return Ok(None);
+        };
+        let mut data = DataReader::new(rest);
+        let accounts = &instruction.accounts;
+        let decoded = |name: &str| DecodedInstruction::new(self.program_name, name);
+
+        let decoded = match *discriminator {
+            TOKEN_METADATA_INITIALIZE => decoded("Token Metadata: Initialize")
+                .field("Name", FieldValue::Text(data.borsh_string()?))
+                .field("Symbol", FieldValue::Text(data.borsh_string()?))
+                .field("URI", FieldValue::Text(data.borsh_string()?))
+                .roles(&["Metadata", "Update Authority", "Mint", "Mint Authority"], accounts),
+            TOKEN_METADATA_UPDATE_FIELD => {
+                let field = match data.u8()? {
+                    3 => format!("Key \"{}\"", data.borsh_string()?),
+                    field => match METADATA_FIELDS.get(field as usize) {
+                        Some(name) => name.to_string(),
+                        None => return Err(DecodeError::new(format!("Invalid metadata field {}", field))),
+                    },
+                };
+                decoded("Token Metadata: Update Field")
+                    .field("Field", FieldValue::Text(field))
+                    .field("Value", FieldValue::Text(data.borsh_string()?))
+                    .roles(&["Metadata", "Update Authority"], accounts)
+            }
+            TOKEN_METADATA_REMOVE_KEY => {
+                let idempotent = data.u8()? != 0;
+                decoded("Token Metadata: Remove Key")
+                    .field("Key", FieldValue::Text(data.borsh_string()?))
+                    .field("Idempotent", FieldValue::Text(idempotent.to_string()))
+                    .roles(&["Metadata", "Update Authority"], accounts)
+            }
+            TOKEN_METADATA_UPDATE_AUTHORITY => decoded("Token Metadata: Update Authority")
+                .field("New Authority", FieldValue::optional_address(data.optional_nonzero_pubkey()?))
+                .roles(&["Metadata", "Update Authority"], accounts),
+            TOKEN_METADATA_EMIT => {
+                let start = data.option(DataReader::u64)?;
+                let end = data.option(DataReader::u64)?;
+                let offset = |offset: Option<u64>, default: &str| {
+                    FieldValue::Text(offset.map_or_else(|| default.to_string(), |offset| offset.to_string()))
+                };
+                decoded("Token Metadata: Emit")
+                    .field("Start", offset(start, "Beginning"))
+                    .field("End", offset(end, "End"))
+                    .roles(&["Metadata"], accounts)
+            }
+            TOKEN_GROUP_INITIALIZE_GROUP => decoded("Token Group: Initialize Group")
+                .field("Update Authority", FieldValue::optional_address(data.optional_nonzero_pubkey()?))
+                .field("Max Size", FieldValue::Number(group_size(&mut data)?.into()))
+                .roles(&["Group", "Mint", "Mint Authority"], accounts),
+            TOKEN_GROUP_UPDATE_MAX_SIZE => decoded("Token Group: Update Max Size")
+                .field("Max Size", FieldValue::Number(group_size(&mut data)?.into()))
+                .roles(&["Group", "Update Authority"], accounts),
+            TOKEN_GROUP_UPDATE_AUTHORITY => decoded("Token Group: Update Authority")
+                .field("New Authority", FieldValue::optional_address(data.optional_nonzero_pubkey()?))
+                .roles(&["Group", "Update Authority"], accounts),
+            TOKEN_GROUP_INITIALIZE_MEMBER => decoded("Token Group: Initialize Member").roles(
+                &["Member", "Member Mint", "Member Mint Authority", "Group", "Group Update Authority"],
+                accounts,
+            ),
+            _ => return Ok(None),
+        };
+
+        Ok(Some(decoded))
+    }
+
+    /// Instructions of the transfer fee extension (tag 26)
+    fn decode_transfer_fee(
+        &self,
+        data: &mut DataReader,
+        instruction: &RawInstruction,
+        context: &DecodeContext,
+    ) -> Result<DecodedInstruction, DecodeError> {
+        let accounts = &instruction.accounts;
+        let decoded = |name: &str| DecodedInstruction::new(self.program_name, format!("Transfer Fee: {}", name));
+        let roles = |roles: &[&'static str]| with_signers(roles, accounts.len());
+        let mint_decimals = accounts.first().and_then(|mint| context.decimals(&mint.pubkey));
+
+        let decoded = match data.u8()? {
+            0 => decoded("Initialize Config")
+                .field("Config Authority", FieldValue::optional_address(data.coption_pubkey()?))
+                .field("Withdraw Authority", FieldValue::optional_address(data.coption_pubkey()?))
+                .field("Fee", basis_points(data.u16()?.into()))
+                .field(
+                    "Maximum Fee",
+                    FieldValue::TokenAmount { amount: data.u64()?, decimals: mint_decimals },
+                )
+                .roles(&["Mint"], accounts),
+            1 => {
+                let amount = data.u64()?;
+                let decimals = data.u8()?;
+                decoded("Transfer Checked With Fee")
+                    .field("Amount", FieldValue::TokenAmount { amount, decimals: Some(decimals) })
+                    .field("Decimals", FieldValue::Number(decimals.into()))
+                    .field("Fee", FieldValue::TokenAmount { amount: data.u64()?, decimals: Some(decimals) })
+                    .roles(&roles(&["Source", "Mint", "Destination", "Authority"]), accounts)
+            }
+            2 => decoded("Withdraw Withheld Tokens From Mint")
+                .roles(&roles(&["Mint", "Destination", "Withdraw Authority"]), accounts),
+            3 => {
+                let sources = data.u8()? as usize;
+                let named_roles = ["Mint", "Destination", "Withdraw Authority"];
+                // Multisig signers come before the source accounts
+                let signers = accounts.len().saturating_sub(named_roles.len() + sources);
+                let account_roles: Vec<&str> = named_roles
+                    .into_iter()
+                    .chain(iter::repeat_n(MULTISIG_SIGNER, signers))
+                    .chain(iter::repeat_n("Source Account", sources))
+                    .collect();
+                decoded("Withdraw Withheld Tokens From Accounts")
+                    .field("Source Accounts", FieldValue::Number(sources as u128))
+                    .roles(&account_roles, accounts)
+            }
+            4 => {
+                let account_roles: Vec<&str> = iter::once("Mint")
+                    .chain(iter::repeat("Source Account"))
+                    .take(accounts.len())
+                    .collect();
+                decoded("Harvest Withheld Tokens To Mint").roles(&account_roles, accounts)
+            }
+            5 => decoded("Set Transfer Fee")
+                .field("Fee", basis_points(data.u16()?.into()))
+                .field(
+                    "Maximum Fee",
+                    FieldValue::TokenAmount { amount: data.u64()?, decimals: mint_decimals },
+                )
+                .roles(&roles(&["Mint", "Config Authority"]), accounts),
+            sub => return Err(DecodeError::unknown_instruction(format!("26/{}", sub))),
+        };
+
+        Ok(decoded)
+    }
+}
+
+/// Name a confidential extension's instruction, leaving its proof data raw
+fn confidential(
+    extension: DecodedInstruction,
+    instructions: &[&str],
+    data: &mut DataReader,
+    accounts: &[AccountKey],
+) -> Result<DecodedInstruction, DecodeError> {
+    let sub = data.u8()?;
+    let name = instructions
+        .get(sub as usize)
+        .ok_or_else(|| DecodeError::unknown_instruction(format!("{}/{}", extension.name, sub)))?;
+
+    Ok(DecodedInstruction {
+        name: format!("{}: {}", extension.name, name),
+        ..extension
+    }
+    .roles(&[], accounts))
+}
+
+/// Group size: a u64, or a u32 in earlier versions of the interface
+fn group_size(data: &mut DataReader) -> Result<u64, DecodeError> {
+    if data.remaining() >= 8 {
+        data.u64()
+    } else {
+        data.u32().map(u64::from)
+    }
+}
+
+/// Named roles followed by multisig signers for the remaining accounts
+fn with_signers(roles: &[&'static str], accounts: usize) -> Vec<&'static str> {
+    roles
+        .iter()
+        .copied()
+        .chain(iter::repeat(MULTISIG_SIGNER))
+        .take(accounts.max(roles.len()))
+        .collect()
+}
+
+/// Name of an enum value, or its discriminant if it is not known
+fn named(names: &[&str], value: u8) -> FieldValue {
+    FieldValue::Text(
+        names
+            .get(value as usize)
+            .map(|name| name.to_string())
+            .unwrap_or_else(|| format!("Unknown ({})", value)),
+    )
+}
+
+/// Rate in basis points, shown as a percentage
+fn basis_points(value: i32) -> FieldValue {
+    FieldValue::Text(format!("{}% ({} bps)", value as f64 / 100.0, value))
+}
+
+/// List of u16 extension types
+fn extension_types(data: &[u8]) -> Result<FieldValue, DecodeError> {
+    if !data.len().is_multiple_of(2) {
+        return Err(DecodeError::new("Extension list has an odd length"));
+    }
+    if data.is_empty() {
+        return Ok(FieldValue::Text("None".to_string()));
+    }
+
+    let names: Vec<String> = data
+        .chunks_exact(2)
+        .map(|chunk| {
+            let value = u16::from_le_bytes([chunk[0], chunk[1]]);
+            EXTENSION_TYPES
+                .get(value as usize)
+                .map(|name| name.to_string())
+                .unwrap_or_else(|| format!("Unknown ({})", value))
+        })
+        .collect();
+    Ok(FieldValue::Text(names.join(", ")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::decoder::test_instruction;
+
+    const MINT: &str = "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v";
+    const AUTHORITY: &str = "9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM";
+
+    fn decode(data: Vec<u8>, accounts: &[&str]) -> Result<DecodedInstruction, DecodeError> {
+        let instruction = test_instruction(TOKEN_2022_PROGRAM_ID, data, accounts);
+        TokenDecoder::token_2022().decode(&instruction, &DecodeContext::default())
+    }
+
+    fn borsh_string(text: &str) -> Vec<u8> {
+        let mut bytes = (text.len() as u32).to_le_bytes().to_vec();
+        bytes.extend_from_slice(text.as_bytes());
+        bytes
+    }
+
+    fn field<'a>(decoded: &'a DecodedInstruction, label: &str) -> &'a FieldValue {
+        &decoded.fields.iter().find(|field| field.label == label).unwrap().value
+    }
+
+    #[test]
+    fn decodes_transfer_checked() {
+        // 1 USDC, as returned by getTransaction
+        let data = bs58::decode("gvPShZQhKrzGM").into_vec().unwrap();
+        let instruction = test_instruction(TOKEN_PROGRAM_ID, data, &[AUTHORITY, MINT, AUTHORITY, AUTHORITY]);
+        let decoded = TokenDecoder::spl_token()
+            .decode(&instruction, &DecodeContext::default())
+            .unwrap();
+
+        assert_eq!(decoded.program_name, "Token Program");
+        assert_eq!(decoded.name, "Transfer Checked");
+        assert_eq!(field(&decoded, "Amount").to_string(), "1");
+        assert_eq!(field(&decoded, "Decimals"), &FieldValue::Number(6));
+        let roles: Vec<&str> = decoded.accounts.iter().map(|account| account.role.as_str()).collect();
+        assert_eq!(roles, ["Source", "Mint", "Destination", "Authority"]);
+    }
+
+    #[test]
+    fn unchecked_transfers_use_decimals_from_token_balances() {
+        let mut data = vec![3];
+        data.extend(2_500_000u64.to_le_bytes());
+        let instruction = test_instruction(TOKEN_PROGRAM_ID, data, &["source", "destination", AUTHORITY, "signer"]);
+        let mut context = DecodeContext::default();
+        context.mint_decimals.insert(MINT.to_string(), 6);
+        context.token_account_mints.insert("source".to_string(), MINT.to_string());
+
+        let decoded = TokenDecoder::spl_token().decode(&instruction, &context).unwrap();
+        assert_eq!(field(&decoded, "Amount").to_string(), "2.5");
+        assert_eq!(decoded.accounts[3].role, MULTISIG_SIGNER);
+
+        let decoded = TokenDecoder::spl_token()
+            .decode(&instruction, &DecodeContext::default())
+            .unwrap();
+        assert_eq!(field(&decoded, "Amount").to_string(), "2500000 (raw, mint decimals unknown)");
+    }
+
+    #[test]
+    fn truncated_token_data_is_an_error() {
+        let data = bs58::decode("gvPShZQhKrzGM").into_vec().unwrap();
+        let instruction = test_instruction(TOKEN_PROGRAM_ID, data[..9].to_vec(), &[]);
+        let error = TokenDecoder::spl_token()
+            .decode(&instruction, &DecodeContext::default())
+            .unwrap_err();
+        assert!(error.message.starts_with("Data too short"), "{}", error);
+    }
+
+    #[test]
+    fn interface_discriminators_match_their_preimages() {
+        use sha2::{Digest, Sha256};
+
+        let discriminators = [
+            ("spl_token_metadata_interface:initialize_account", TOKEN_METADATA_INITIALIZE),
+            ("spl_token_metadata_interface:updating_field", TOKEN_METADATA_UPDATE_FIELD),
+            ("spl_token_metadata_interface:remove_key_ix", TOKEN_METADATA_REMOVE_KEY),
+            ("spl_token_metadata_interface:update_the_authority", TOKEN_METADATA_UPDATE_AUTHORITY),
+            ("spl_token_metadata_interface:emitter", TOKEN_METADATA_EMIT),
+            ("spl_token_group_interface:initialize_token_group", TOKEN_GROUP_INITIALIZE_GROUP),
+            ("spl_token_group_interface:update_group_max_size", TOKEN_GROUP_UPDATE_MAX_SIZE),
+            ("spl_token_group_interface:update_authority", TOKEN_GROUP_UPDATE_AUTHORITY),
+            ("spl_token_group_interface:initialize_member", TOKEN_GROUP_INITIALIZE_MEMBER),
+        ];
+        for (preimage, discriminator) in discriminators {
+            assert_eq!(Sha256::digest(preimage)[..8], discriminator, "{}", preimage);
+        }
+    }
+
+    #[test]
+    fn decodes_token_metadata_initialize() {
+        let mut data = TOKEN_METADATA_INITIALIZE.to_vec();
+        data.extend(borsh_string("OpenSVM"));
+        data.extend(borsh_string("OSVM"));
+        data.extend(borsh_string("https://opensvm.com/token.json"));
+        let decoded = decode(data, &[MINT, AUTHORITY, MINT, AUTHORITY]).unwrap();
+
+        assert_eq!(decoded.name, "Token Metadata: Initialize");
+        assert_eq!(field(&decoded, "Name"), &FieldValue::Text("OpenSVM".to_string()));
+        assert_eq!(field(&decoded, "Symbol"), &FieldValue::Text("OSVM".to_string()));
+        assert_eq!(field(&decoded, "URI"), &FieldValue::Text("https://opensvm.com/token.json".to_string()));
+        assert_eq!(decoded.accounts[3].role, "Mint Authority");
+    }
+
+    #[test]
+    fn decodes_token_metadata_update_field_with_custom_key() {
+        let mut data = TOKEN_METADATA_UPDATE_FIELD.to_vec();
+        data.push(3);
+        data.extend(borsh_string("website"));
+        data.extend(borsh_string("https://opensvm.com"));
+        let decoded = decode(data, &[MINT, AUTHORITY]).unwrap();
+
+        assert_eq!(decoded.name, "Token Metadata: Update Field");
+        assert_eq!(field(&decoded, "Field"), &FieldValue::Text("Key \"website\"".to_string()));
+        assert_eq!(field(&decoded, "Value"), &FieldValue::Text("https://opensvm.com".to_string()));
+    }
+
+    #[test]
+    fn decodes_token_metadata_emit_and_authority() {
+        let mut data = TOKEN_METADATA_EMIT.to_vec();
+        data.extend([1, 8, 0, 0, 0, 0, 0, 0, 0, 0]);
+        let decoded = decode(data, &[MINT]).unwrap();
+        assert_eq!(field(&decoded, "Start"), &FieldValue::Text("8".to_string()));
+        assert_eq!(field(&decoded, "End"), &FieldValue::Text("End".to_string()));
+
+        let mut data = TOKEN_METADATA_UPDATE_AUTHORITY.to_vec();
+        data.extend([0; 32]);
+        let decoded = decode(data, &[MINT, AUTHORITY]).unwrap();
+        assert_eq!(field(&decoded, "New Authority"), &FieldValue::Text("None".to_string()));
+    }
+
+    #[test]
+    fn decodes_token_group_instructions() {
+        let mut data = TOKEN_GROUP_INITIALIZE_GROUP.to_vec();
+        data.extend(bs58::decode(AUTHORITY).into_vec().unwrap());
+        data.extend(100u64.to_le_bytes());
+        let decoded = decode(data, &[MINT, MINT, AUTHORITY]).unwrap();
+        assert_eq!(decoded.name, "Token Group: Initialize Group");
+        assert_eq!(field(&decoded, "Update Authority"), &FieldValue::Address(AUTHORITY.to_string()));
+        assert_eq!(field(&decoded, "Max Size"), &FieldValue::Number(100));
+
+        let decoded = decode(TOKEN_GROUP_INITIALIZE_MEMBER.to_vec(), &[MINT; 5]).unwrap();
+        assert_eq!(decoded.name, "Token Group: Initialize Member");
+        assert_eq!(decoded.accounts[4].role, "Group Update Authority");
+    }
+
+    #[test]
+    fn interface_discriminators_are_not_read_by_spl_token() {
+        let instruction = test_instruction(TOKEN_PROGRAM_ID, TOKEN_METADATA_INITIALIZE.to_vec(), &[]);
+        let error = TokenDecoder::spl_token()
+            .decode(&instruction, &DecodeContext::default())
+            .unwrap_err();
+        assert_eq!(error, DecodeError::unknown_instruction(210));
+    }
+}
@@ -0,0 +1,303 @@
+//! Vote program decoder
+//!
+//! Vote instructions are bincode-encoded with a u32 variant index. Tower
+//! updates sent since the compact format was introduced store the voted
+//! slots as varint offsets from the root instead of full slot numbers.
+
+use super::{
+    DataReader, DecodeContext, DecodeError, DecodedInstruction, FieldValue, InstructionDecoder,
+    RawInstruction,
+};
+use crate::utils::address_utils::format_timestamp;
+use crate::utils::api::VOTE_PROGRAM_ID;
+
+/// Voted slots listed individually; longer towers are summarized
+const LISTED_SLOTS: usize = 8;
+
+const VOTE_ROLES: [&str; 4] = ["Vote Account", "Slot Hashes Sysvar", "Clock Sysvar", "Vote Authority"];
+const TOWER_ROLES: [&str; 2] = ["Vote Account", "Vote Authority"];
+
+pub struct VoteDecoder;
+
+impl InstructionDecoder for VoteDecoder {
+    fn program_id(&self) -> &str {
+        VOTE_PROGRAM_ID
+    }
+
+    fn program_name(&self) -> &str {
+        "Vote Program"
+    }
+
+    fn decode(
+        &self,
+        instruction: &RawInstruction,
+        _context: &DecodeContext,
+    ) -> Result<DecodedInstruction, DecodeError> {
+        let mut data = DataReader::new(&instruction.data);
+        let accounts = &instruction.accounts;
+        let decoded = |name: &str| DecodedInstruction::new(self.program_name(), name);
+
+        let decoded = match data.u32()? {
+            0 => decoded("Initialize Account")
+                .field("Validator Identity", FieldValue::Address(data.pubkey()?))
+                .field("Authorized Voter", FieldValue::Address(data.pubkey()?))
+                .field("Authorized Withdrawer", FieldValue::Address(data.pubkey()?))
+                .field("Commission", FieldValue::Text(format!("{}%", data.u8()?)))
+                .roles(&["Vote Account", "Rent Sysvar", "Clock Sysvar", "Validator Identity"], accounts),
+            1 => decoded("Authorize")
+                .field("New Authority", FieldValue::Address(data.pubkey()?))
+                .field("Authority Type", vote_authorize(data.u32()?))
+                .roles(&["Vote Account", "Clock Sysvar", "Authority"], accounts),
+            tag @ (2 | 6) => {
+                let name = if tag == 2 { "Vote" } else { "Vote Switch" };
+                let vote = Tower::vote(&mut data)?;
+                switch_proof(vote.fields(decoded(name)), tag == 6, &mut data)?.roles(&VOTE_ROLES, accounts)
+            }
+            3 => decoded("Withdraw")
+                .field("Lamports", FieldValue::Lamports(data.u64()?))
+                .roles(&["Vote Account", "Recipient", "Withdraw Authority"], accounts),
+            4 => decoded("Update Validator Identity")
+                .roles(&["Vote Account", "New Validator Identity", "Withdraw Authority"], accounts),
+            5 => decoded("Update Commission")
+                .field("Commission", FieldValue::Text(format!("{}%", data.u8()?)))
+                .roles(&["Vote Account", "Withdraw Authority"], accounts),
+            7 => decoded("Authorize Checked")
+                .field("Authority Type", vote_authorize(data.u32()?))
+                .roles(&["Vote Account", "Clock Sysvar", "Authority", "New Authority"], accounts),
+            tag @ (8 | 9) => {
+                let name = if tag == 8 { "Update Vote State" } else { "Update Vote State Switch" };
+                let tower = Tower::vote_state_update(&mut data)?;
+                switch_proof(tower.fields(decoded(name)), tag == 9, &mut data)?.roles(&TOWER_ROLES, accounts)
+            }
+            10 => decoded("Authorize With Seed")
+                .field("Authority Type", vote_authorize(data.u32()?))
+                .field("Authority Owner", FieldValue::Address(data.pubkey()?))
+                .field("Authority Seed", FieldValue::Text(data.bincode_string()?))
+                .field("New Authority", FieldValue::Address(data.pubkey()?))
+                .roles(&["Vote Account", "Clock Sysvar", "Authority Base"], accounts),
+            11 => decoded("Authorize Checked With Seed")
+                .field("Authority Type", vote_authorize(data.u32()?))
+                .field("Authority Owner", FieldValue::Address(data.pubkey()?))
+                .field("Authority Seed", FieldValue::Text(data.bincode_string()?))
+                .roles(&["Vote Account", "Clock Sysvar", "Authority Base", "New Authority"], accounts),
+            tag @ (12 | 13) => {
+                let name = if tag == 12 {
+                    "Compact Update Vote State"
+                } else {
+                    "Compact Update Vote State Switch"
+                };
+                let tower = Tower::compact(&mut data, false)?;
+                switch_proof(tower.fields(decoded(name)), tag == 13, &mut data)?.roles(&TOWER_ROLES, accounts)
+            }
+            tag @ (14 | 15) => {
+                let name = if tag == 14 { "Tower Sync" } else { "Tower Sync Switch" };
+                let tower = Tower::compact(&mut data, true)?;
+                switch_proof(tower.fields(decoded(name)), tag == 15, &mut data)?.roles(&TOWER_ROLES, accounts)
+            }
+            tag => return Err(DecodeError::unknown_instruction(tag)),
+        };
+
+        Ok(decoded)
+    }
+}
+
+/// Votes carried by a vote or tower update instruction
+struct Tower {
+    root: Option<u64>,
+    /// Voted slots, oldest first
+    slots: Vec<u64>,
+    hash: String,
+    timestamp: Option<i64>,
+    block_id: Option<String>,
+}
+
+impl Tower {
+    /// `Vote`: slots, bank hash and timestamp
+    fn vote(data: &mut DataReader) -> Result<Self, DecodeError> {
+        let len = data.u64()?;
+        let slots = (0..len).map(|_| data.u64()).collect::<Result<_, _>>()?;
+        Ok(Self {
+            root: None,
+            slots,
+            hash: data.hash()?,
+            timestamp: data.option(DataReader::i64)?,
+            block_id: None,
+        })
+    }
+
+    /// `VoteStateUpdate`: lockouts with full slots, then root, hash and timestamp
+    fn vote_state_update(data: &mut DataReader) -> Result<Self, DecodeError> {
+        let len = data.u64()?;
+        let mut slots = Vec::new();
+        for _ in 0..len {
+            slots.push(data.u64()?);
+            data.u32()?; // confirmation count
+        }
+        Ok(Self {
+            root: data.option(DataReader::u64)?,
+            slots,
+            hash: data.hash()?,
+            timestamp: data.option(DataReader::i64)?,
+            block_id: None,
+        })
+    }
+
+    /// Compact `VoteStateUpdate` or `TowerSync`: root, then slots as offsets
+    /// from the previous slot, hash, timestamp and, for `TowerSync`, block id
+    fn compact(data: &mut DataReader, with_block_id: bool) -> Result<Self, DecodeError> {
+        let root = Some(data.u64()?).filter(|root| *root != u64::MAX);
+        let len = data.short_vec_len()?;
+        let mut slots = Vec::with_capacity(len);
+        let mut slot = root.unwrap_or(0);
+        for _ in 0..len {
+            slot = slot
+                .checked_add(data.varint()?)
+                .ok_or_else(|| DecodeError::new("Slot offset overflows"))?;
+            slots.push(slot);
+            data.u8()?; // confirmation count
+        }
+        Ok(Self {
+            root,
+            slots,
+            hash: data.hash()?,
+            timestamp: data.option(DataReader::i64)?,
+            block_id: if with_block_id { Some(data.hash()?) } else { None },
+        })
+    }
+
+    fn fields(self, decoded: DecodedInstruction) -> DecodedInstruction {
+        let slots = match (self.slots.first(), self.slots.last()) {
+            (Some(first), Some(last)) if self.slots.len() > LISTED_SLOTS => {
+                format!("{} slots, {} to {}", self.slots.len(), first, last)
+            }
+            (Some(_), _) => self
+                .slots
+                .iter()
+                .map(u64::to_string)
+                .collect::<Vec<_>>()
+                .join(", "),
+            _ => "None".to_string(),
+        };
+        let timestamp = match self.timestamp {
+            Some(timestamp) if timestamp > 0 => format!("{} UTC", format_timestamp(timestamp as u64)),
+            Some(timestamp) => timestamp.to_string(),
+            None => "None".to_string(),
+        };
+
+        let mut decoded = decoded.field("Voted Slots", FieldValue::Text(slots));
+        if let Some(root) = self.root {
+            decoded = decoded.field("Root", FieldValue::Number(root.into()));
+        }
+        decoded = decoded
+            .field("Bank Hash", FieldValue::Text(self.hash))
+            .field("Timestamp", FieldValue::Text(timestamp));
+        if let Some(block_id) = self.block_id {
+            decoded = decoded.field("Block Id", FieldValue::Text(block_id));
+        }
+        decoded
+    }
+}
+
+/// Hash of the switching proof trailing the `*Switch` variants
+fn switch_proof(
+    decoded: DecodedInstruction,
+    switch: bool,
+    data: &mut DataReader,
+) -> Result<DecodedInstruction, DecodeError> {
+    if switch {
+        Ok(decoded.field("Switch Proof Hash", FieldValue::Text(data.hash()?)))
+    } else {
+        Ok(decoded)
+    }
+}
+
+/// `VoteAuthorize` value
+fn vote_authorize(value: u32) -> FieldValue {
+    FieldValue::Text(match value {
+        0 => "Voter".to_string(),
+        1 => "Withdrawer".to_string(),
+        value => format!("Unknown ({})", value),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::decoder::test_instruction;
+
+    const VOTE_ACCOUNT: &str = "Vote111111111111111111111111111111111111111";
+    const AUTHORITY: &str = "9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM";
+
+    /// `TowerSync` in the wire format: root, voted slots as varint offsets
+    /// with confirmation counts, bank hash, optional timestamp and block id
+    fn tower_sync(root: u64, offsets: &[&[u8]], timestamp: Option<i64>) -> Vec<u8> {
+        let mut data = 14u32.to_le_bytes().to_vec();
+        data.extend(root.to_le_bytes());
+        data.push(offsets.len() as u8);
+        for (i, offset) in offsets.iter().enumerate() {
+            data.extend(*offset);
+            data.push((offsets.len() - i) as u8);
+        }
+        data.extend([7; 32]);
+        match timestamp {
+            Some(timestamp) => {
+                data.push(1);
+                data.extend(timestamp.to_le_bytes());
+            }
+            None => data.push(0),
+        }
+        data.extend([9; 32]);
+        data
+    }
+
+    fn decode(data: Vec<u8>) -> Result<DecodedInstruction, DecodeError> {
+        let instruction = test_instruction(VOTE_PROGRAM_ID, data, &[VOTE_ACCOUNT, AUTHORITY]);
+        VoteDecoder.decode(&instruction, &DecodeContext::default())
+    }
+
+    fn field<'a>(decoded: &'a DecodedInstruction, label: &str) -> Option<&'a FieldValue> {
+        decoded.fields.iter().find(|field| field.label == label).map(|field| &field.value)
+    }
+
+    #[test]
+    fn decodes_tower_sync() {
+        // Offsets 1, 1 and 200, the last one taking two varint bytes
+        let data = tower_sync(300_000_000, &[&[1], &[1], &[0xc8, 0x01]], Some(1_700_000_000));
+        let decoded = decode(data).unwrap();
+
+        assert_eq!(decoded.name, "Tower Sync");
+        assert_eq!(
+            field(&decoded, "Voted Slots"),
+            Some(&FieldValue::Text("300000001, 300000002, 300000202".to_string()))
+        );
+        assert_eq!(field(&decoded, "Root"), Some(&FieldValue::Number(300_000_000)));
+        assert_eq!(
+            field(&decoded, "Bank Hash"),
+            Some(&FieldValue::Text(bs58::encode([7; 32]).into_string()))
+        );
+        let timestamp = field(&decoded, "Timestamp");
+        assert!(matches!(timestamp, Some(FieldValue::Text(text)) if text.ends_with(" UTC")));
+        assert_eq!(
+            field(&decoded, "Block Id"),
+            Some(&FieldValue::Text(bs58::encode([9; 32]).into_string()))
+        );
+        let roles: Vec<&str> = decoded.accounts.iter().map(|account| account.role.as_str()).collect();
+        assert_eq!(roles, TOWER_ROLES);
+    }
+
+    #[test]
+    fn tower_sync_without_root_counts_from_slot_zero() {
+        let decoded = decode(tower_sync(u64::MAX, &[&[5]], None)).unwrap();
+        assert_eq!(field(&decoded, "Voted Slots"), Some(&FieldValue::Text("5".to_string())));
+        assert_eq!(field(&decoded, "Root"), None);
+        assert_eq!(field(&decoded, "Timestamp"), Some(&FieldValue::Text("None".to_string())));
+    }
+
+    #[test]
+    fn truncated_tower_sync_is_an_error() {
+        let mut data = tower_sync(300_000_000, &[&[1]], None);
+        data.truncate(data.len() - 1);
+        let error = decode(data).unwrap_err();
+        assert!(error.message.starts_with("Data too short"), "{}", error);
+    }
+}
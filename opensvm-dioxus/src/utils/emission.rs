@@ -0,0 +1,377 @@
+//! SOL emission models
+//!
+//! The fixed schedule the runtime uses today starts at an initial annual
+//! rate and shrinks by a constant taper every year until it reaches the
+//! terminal rate. SIMD-0228 proposes scaling that rate by how much of the
+//! supply is staked:
+//!
+//! ```text
+//! i(s) = r(1 - √s + c · max(1 - √(2s), 0))
+//! ```
+//!
+//! where `r` is the fixed-schedule rate, `s` the staked fraction of supply and
+//! `c` is chosen so that `i(1/3) = r`. Stakers share the issuance, so their
+//! nominal yield is `i(s) / s`.
+
+use crate::utils::api::InflationParameters;
+
+/// Length of a year as used by the runtime's inflation schedule
+pub const SECONDS_PER_YEAR: f64 = 365.242_199 * 24.0 * 60.0 * 60.0;
+
+/// Slot duration the runtime assumes when paying out inflation
+pub const NOMINAL_SLOT_SECONDS: f64 = 0.4;
+
+/// Slots per epoch on mainnet-beta
+pub const MAINNET_SLOTS_PER_EPOCH: u64 = 432_000;
+
+/// Mainnet-beta epoch in which inflation was activated
+pub const MAINNET_INFLATION_START_EPOCH: u64 = 150;
+
+/// SIMD-0228 constant that makes issuance equal the fixed rate at 1/3 staked
+pub const SIMD_0228_C: f64 = 3.14626436994;
+
+/// Fixed disinflation schedule, as described by `getInflationGovernor`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct InflationSchedule {
+    /// Annual rate in the first year (0.0 - 1.0)
+    pub initial: f64,
+    /// Rate the schedule settles at (0.0 - 1.0)
+    pub terminal: f64,
+    /// Yearly reduction of the rate (0.15 = 15% lower each year)
+    pub taper: f64,
+    /// Share of issuance paid to the foundation (0.0 - 1.0)
+    pub foundation: f64,
+    /// Years the foundation share is paid for
+    pub foundation_term: f64,
+    /// Epoch in which inflation started
+    pub start_epoch: u64,
+    pub slots_per_epoch: u64,
+}
+
+impl Default for InflationSchedule {
+    /// The mainnet-beta schedule
+    fn default() -> Self {
+        Self {
+            initial: 0.08,
+            terminal: 0.015,
+            taper: 0.15,
+            foundation: 0.05,
+            foundation_term: 7.0,
+            start_epoch: MAINNET_INFLATION_START_EPOCH,
+            slots_per_epoch: MAINNET_SLOTS_PER_EPOCH,
+        }
+    }
+}
+
+impl InflationSchedule {
+    /// Schedule described by the cluster's inflation governor
+    ///
+    /// The RPC does not report when inflation started, so the start epoch is
+    /// solved from the current rate. Once the terminal rate is reached any
+    /// start early enough gives the same rates, and the taper is assumed to
+    /// have just ended.
+    pub fn from_parameters(parameters: &InflationParameters) -> Self {
+        let governor = &parameters.governor;
+        let mut schedule = Self {
+            initial: governor.initial,
+            terminal: governor.terminal,
+            taper: governor.taper,
+            foundation: governor.foundation,
+            foundation_term: governor.foundation_term,
+            start_epoch: 0,
+            slots_per_epoch: parameters.epoch_schedule.slots_per_epoch,
+        };
+
+        let current = parameters.rate.total.max(schedule.terminal);
+        let years = if schedule.initial > current && schedule.taper > 0.0 && schedule.taper < 1.0 {
+            (current / schedule.initial).ln() / (1.0 - schedule.taper).ln()
+        } else {
+            0.0
+        };
+        let elapsed_epochs = (years / schedule.epoch_years()).round() as u64;
+        schedule.start_epoch = parameters.rate.epoch.saturating_sub(elapsed_epochs);
+        schedule
+    }
+
+    /// Share of a year covered by one epoch at the nominal slot time
+    pub fn epoch_years(&self) -> f64 {
+        self.slots_per_epoch as f64 * NOMINAL_SLOT_SECONDS / SECONDS_PER_YEAR
+    }
+
+    /// Years of inflation elapsed at the start of `epoch`
+    pub fn years_at_epoch(&self, epoch: u64) -> f64 {
+        epoch.saturating_sub(self.start_epoch) as f64 * self.epoch_years()
+    }
+
+    /// Fixed-schedule annual rate `years` after inflation started
+    pub fn rate_at_year(&self, years: f64) -> f64 {
+        (self.initial * (1.0 - self.taper).powf(years)).max(self.terminal)
+    }
+
+    /// Fixed-schedule annual rate during `epoch`
+    pub fn rate_at_epoch(&self, epoch: u64) -> f64 {
+        self.rate_at_year(self.years_at_epoch(epoch))
+    }
+
+    /// Share of supply paid to the foundation per year, `years` after
+    /// inflation started
+    pub fn foundation_rate_at_year(&self, years: f64) -> f64 {
+        if years < self.foundation_term {
+            self.rate_at_year(years) * self.foundation
+        } else {
+            0.0
+        }
+    }
+
+    /// Share of supply paid to validators and delegators per year
+    pub fn validator_rate_at_year(&self, years: f64) -> f64 {
+        self.rate_at_year(years) - self.foundation_rate_at_year(years)
+    }
+
+    /// Project supply and issuance year by year for `years` years from
+    /// `start_epoch`, starting at `start_supply` SOL
+    pub fn project(&self, start_epoch: u64, start_supply: f64, years: u64) -> Vec<YearProjection> {
+        let epoch_years = self.epoch_years();
+        let epochs_per_year = (1.0 / epoch_years).round().max(1.0) as u64;
+        let mut supply = start_supply;
+        let mut epoch = start_epoch;
+
+        (1..=years)
+            .map(|year| {
+                let start_rate = self.rate_at_epoch(epoch);
+                let mut validator_issuance = 0.0;
+                let mut foundation_issuance = 0.0;
+                for _ in 0..epochs_per_year {
+                    let elapsed = self.years_at_epoch(epoch);
+                    let validator = supply * self.validator_rate_at_year(elapsed) * epoch_years;
+                    let foundation = supply * self.foundation_rate_at_year(elapsed) * epoch_years;
+                    validator_issuance += validator;
+                    foundation_issuance += foundation;
+                    supply += validator + foundation;
+                    epoch += 1;
+                }
+
+                YearProjection {
+                    year,
+                    end_epoch: epoch,
+                    start_rate,
+                    validator_issuance,
+                    foundation_issuance,
+                    end_supply: supply,
+                }
+            })
+            .collect()
+    }
+
+    /// First epoch paying the terminal rate
+    pub fn terminal_epoch(&self) -> u64 {
+        if self.initial <= self.terminal || self.taper <= 0.0 {
+            return self.start_epoch;
+        }
+        let years = (self.terminal / self.initial).ln() / (1.0 - self.taper).ln();
+        self.start_epoch + (years / self.epoch_years()).ceil() as u64
+    }
+}
+
+/// One year of an [`InflationSchedule::project`] projection
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct YearProjection {
+    /// 1 for the first projected year
+    pub year: u64,
+    /// First epoch after the year
+    pub end_epoch: u64,
+    /// Annual rate at the start of the year
+    pub start_rate: f64,
+    /// SOL issued to validators and delegators during the year
+    pub validator_issuance: f64,
+    /// SOL issued to the foundation during the year
+    pub foundation_issuance: f64,
+    /// Supply at the end of the year, in SOL
+    pub end_supply: f64,
+}
+
+/// SIMD-0228 annual issuance rate for `staked` (0.0 - 1.0) of the supply,
+/// given the fixed-schedule rate `static_rate`
+pub fn simd0228_rate(static_rate: f64, staked: f64) -> f64 {
+    let staked = staked.clamp(0.0, 1.0);
+    let low_stake_boost = SIMD_0228_C * (1.0 - (2.0 * staked).sqrt()).max(0.0);
+    (static_rate * (1.0 - staked.sqrt() + low_stake_boost)).max(0.0)
+}
+
+/// Nominal yield of staked SOL when `rate` of the supply is issued to stakers
+pub fn nominal_staking_yield(rate: f64, staked: f64) -> f64 {
+    if staked <= 0.0 {
+        0.0
+    } else {
+        rate / staked
+    }
+}
+
+/// Which emission model a projection follows
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EmissionModel {
+    /// The fixed disinflation schedule
+    Static,
+    /// SIMD-0228 applied on top of the fixed schedule
+    Simd0228,
+}
+
+impl EmissionModel {
+    pub fn rate(self, schedule: &InflationSchedule, epoch: u64, staked: f64) -> f64 {
+        let static_rate = schedule.rate_at_epoch(epoch);
+        match self {
+            EmissionModel::Static => static_rate,
+            EmissionModel::Simd0228 => simd0228_rate(static_rate, staked),
+        }
+    }
+}
+
+/// One epoch of a supply projection
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ProjectionPoint {
+    pub epoch: u64,
+    /// Supply at the start of the epoch, in SOL
+    pub supply: f64,
+    /// Annual issuance rate during the epoch
+    pub rate: f64,
+    /// Nominal staking yield during the epoch
+    pub staking_yield: f64,
+}
+
+/// Project the supply for `epochs` epochs from `start_epoch`, assuming a
+/// constant `staked` share and that all issuance is added to the supply
+pub fn project_supply(
+    model: EmissionModel,
+    schedule: &InflationSchedule,
+    start_epoch: u64,
+    start_supply: f64,
+    staked: f64,
+    epochs: u64,
+) -> Vec<ProjectionPoint> {
+    let epoch_years = schedule.epoch_years();
+    let mut supply = start_supply;
+
+    (start_epoch..start_epoch + epochs)
+        .map(|epoch| {
+            let rate = model.rate(schedule, epoch, staked);
+            let point = ProjectionPoint {
+                epoch,
+                supply,
+                rate,
+                staking_yield: nominal_staking_yield(rate, staked),
+            };
+            supply *= 1.0 + rate * epoch_years;
+            point
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::api::{EpochSchedule, InflationGovernor, InflationRate};
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!((actual - expected).abs() < 1e-9, "{} != {}", actual, expected);
+    }
+
+    #[test]
+    fn simd0228_matches_the_fixed_rate_at_a_third_staked() {
+        assert_close(simd0228_rate(0.045, 1.0 / 3.0), 0.045);
+        assert_close(simd0228_rate(0.015, 1.0 / 3.0), 0.015);
+    }
+
+    #[test]
+    fn simd0228_rate_falls_as_stake_rises() {
+        // Nothing staked: the full low-stake boost
+        assert_close(simd0228_rate(0.04, 0.0), 0.04 * (1.0 + SIMD_0228_C));
+        // From half staked on, the boost is gone
+        assert_close(simd0228_rate(0.04, 0.64), 0.04 * 0.2);
+        assert_close(simd0228_rate(0.04, 1.0), 0.0);
+        // Staked shares outside 0..1 are clamped
+        assert_close(simd0228_rate(0.04, 1.5), 0.0);
+        assert!(simd0228_rate(0.04, 0.5) < simd0228_rate(0.04, 0.4));
+    }
+
+    #[test]
+    fn nominal_yield_divides_issuance_among_stakers() {
+        assert_close(nominal_staking_yield(0.04, 0.5), 0.08);
+        assert_eq!(nominal_staking_yield(0.04, 0.0), 0.0);
+    }
+
+    #[test]
+    fn supply_projection_compounds_each_epoch() {
+        let schedule = InflationSchedule::default();
+        let points = project_supply(EmissionModel::Static, &schedule, 800, 1_000.0, 0.65, 3);
+
+        assert_eq!(points.iter().map(|point| point.epoch).collect::<Vec<_>>(), [800, 801, 802]);
+        assert_eq!(points[0].supply, 1_000.0);
+        assert_close(points[1].supply, 1_000.0 * (1.0 + points[0].rate * schedule.epoch_years()));
+        assert_close(points[0].staking_yield, points[0].rate / 0.65);
+    }
+
+    /// `getInflationGovernor`, `getInflationRate` and `getEpochSchedule` of a
+    /// cluster paying `total` in `epoch`
+    fn parameters(total: f64, epoch: u64) -> InflationParameters {
+        InflationParameters {
+            governor: InflationGovernor {
+                initial: 0.08,
+                terminal: 0.015,
+                taper: 0.15,
+                foundation: 0.05,
+                foundation_term: 7.0,
+            },
+            rate: InflationRate {
+                total,
+                validator: total * 0.95,
+                foundation: total * 0.05,
+                epoch,
+            },
+            epoch_schedule: EpochSchedule {
+                slots_per_epoch: 432_000,
+                leader_schedule_slot_offset: 432_000,
+                warmup: false,
+                first_normal_epoch: 0,
+                first_normal_slot: 0,
+            },
+        }
+    }
+
+    #[test]
+    fn start_epoch_is_solved_from_the_current_rate() {
+        let mainnet = InflationSchedule::default();
+        let rate = mainnet.rate_at_epoch(800);
+        let schedule = InflationSchedule::from_parameters(&parameters(rate, 800));
+
+        assert_eq!(schedule.start_epoch, MAINNET_INFLATION_START_EPOCH);
+        assert_eq!(schedule, mainnet);
+    }
+
+    #[test]
+    fn schedules_at_the_terminal_rate_have_just_finished_tapering() {
+        let schedule = InflationSchedule::from_parameters(&parameters(0.015, 5_000));
+        assert!((5_000..=5_001).contains(&schedule.terminal_epoch()));
+        assert_eq!(schedule.rate_at_epoch(5_001), 0.015);
+    }
+
+    #[test]
+    fn terminal_epoch_is_the_first_at_the_terminal_rate() {
+        let schedule = InflationSchedule::default();
+        let terminal_epoch = schedule.terminal_epoch();
+
+        assert_eq!(schedule.rate_at_epoch(terminal_epoch), schedule.terminal);
+        assert!(schedule.rate_at_epoch(terminal_epoch - 1) > schedule.terminal);
+        // About 10.3 years of taper from 8% down to 1.5%
+        let years = schedule.years_at_epoch(terminal_epoch);
+        assert!((10.2..10.4).contains(&years), "{}", years);
+    }
+
+    #[test]
+    fn schedules_without_taper_start_terminal() {
+        let schedule = InflationSchedule {
+            taper: 0.0,
+            ..InflationSchedule::default()
+        };
+        assert_eq!(schedule.terminal_epoch(), schedule.start_epoch);
+    }
+}
@@ -0,0 +1,255 @@
+//! Epoch progress and slot/time conversions
+//!
+//! Times are estimates: the cluster only reports its current slot, so other
+//! slots are placed before or after it at the recently measured slot time.
+
+use crate::utils::api::{EpochOverview, EpochSchedule};
+use crate::utils::emission::NOMINAL_SLOT_SECONDS;
+
+/// Where an epoch stands relative to the current one
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EpochStatus {
+    Completed,
+    Current,
+    Upcoming,
+}
+
+impl EpochStatus {
+    pub fn label(self) -> &'static str {
+        match self {
+            EpochStatus::Completed => "Completed",
+            EpochStatus::Current => "In progress",
+            EpochStatus::Upcoming => "Upcoming",
+        }
+    }
+}
+
+/// Converts between slots, epochs and estimated wall-clock time
+#[derive(Debug, Clone, PartialEq)]
+pub struct EpochClock {
+    pub schedule: EpochSchedule,
+    pub epoch: u64,
+    /// Slot the cluster was at when `observed_at` was taken
+    pub current_slot: u64,
+    /// Unix time (seconds) of `current_slot`
+    pub observed_at: i64,
+    /// Measured seconds per slot
+    pub slot_seconds: f64,
+}
+
+impl EpochClock {
+    pub fn new(overview: &EpochOverview) -> Self {
+        let measured = overview.performance.avg_slot_time;
+        Self {
+            schedule: overview.epoch_schedule.clone(),
+            epoch: overview.epoch_info.epoch,
+            current_slot: overview.epoch_info.absolute_slot,
+            observed_at: overview.observed_at,
+            slot_seconds: if measured > 0.0 { measured } else { NOMINAL_SLOT_SECONDS },
+        }
+    }
+
+    /// Clock moved forward to a newer slot, e.g. from a slot subscription,
+    /// assuming it was reached just now
+    pub fn at_slot(&self, slot: u64, now: i64) -> Self {
+        if slot <= self.current_slot {
+            return self.clone();
+        }
+        Self {
+            epoch: self.schedule.epoch_and_slot_index(slot).0,
+            current_slot: slot,
+            observed_at: now,
+            ..self.clone()
+        }
+    }
+
+    pub fn status(&self, epoch: u64) -> EpochStatus {
+        match epoch.cmp(&self.epoch) {
+            std::cmp::Ordering::Less => EpochStatus::Completed,
+            std::cmp::Ordering::Equal => EpochStatus::Current,
+            std::cmp::Ordering::Greater => EpochStatus::Upcoming,
+        }
+    }
+
+    /// Slots elapsed and total slots of the current epoch
+    pub fn slot_progress(&self) -> (u64, u64) {
+        let (_, slot_index) = self.schedule.epoch_and_slot_index(self.current_slot);
+        (slot_index, self.schedule.slots_in_epoch(self.epoch))
+    }
+
+    /// Share of the current epoch elapsed (0.0 - 1.0)
+    pub fn progress(&self) -> f64 {
+        let (elapsed, total) = self.slot_progress();
+        if total == 0 {
+            0.0
+        } else {
+            elapsed as f64 / total as f64
+        }
+    }
+
+    /// First slot of the next epoch, or the last slot if the next epoch is
+    /// out of range
+    pub fn next_boundary_slot(&self) -> u64 {
+        self.epoch
+            .checked_add(1)
+            .and_then(|epoch| self.schedule.first_slot_in_epoch(epoch))
+            .unwrap_or(u64::MAX)
+    }
+
+    /// Estimated seconds until the next epoch starts
+    pub fn seconds_to_next_boundary(&self) -> i64 {
+        self.slot_time(self.next_boundary_slot()) - self.observed_at
+    }
+
+    /// Estimated unix time (seconds) of `slot`
+    pub fn slot_time(&self, slot: u64) -> i64 {
+        let offset = slot as f64 - self.current_slot as f64;
+        self.observed_at + (offset * self.slot_seconds).round() as i64
+    }
+
+    /// Estimated slot at unix time `time` (seconds)
+    pub fn slot_at(&self, time: i64) -> u64 {
+        let offset = ((time - self.observed_at) as f64 / self.slot_seconds).round();
+        (self.current_slot as f64 + offset).max(0.0) as u64
+    }
+
+    /// Estimated unix time (seconds) `epoch` starts, or `None` if the epoch
+    /// is out of range
+    pub fn epoch_start_time(&self, epoch: u64) -> Option<i64> {
+        Some(self.slot_time(self.schedule.first_slot_in_epoch(epoch)?))
+    }
+
+    /// Estimated unix time (seconds) `epoch` ends, i.e. the next one starts
+    pub fn epoch_end_time(&self, epoch: u64) -> Option<i64> {
+        self.epoch_start_time(epoch.checked_add(1)?)
+    }
+}
+
+/// Format a number of seconds as e.g. "1d 4h 12m", or "45s" under a minute
+pub fn format_duration(seconds: i64) -> String {
+    let seconds = seconds.unsigned_abs();
+    if seconds < 60 {
+        return format!("{}s", seconds);
+    }
+
+    let (days, hours, minutes) = (seconds / 86_400, seconds % 86_400 / 3_600, seconds % 3_600 / 60);
+    let parts = [(days, "d"), (hours, "h"), (minutes, "m")];
+    let first = parts.iter().position(|(value, _)| *value > 0).unwrap_or(2);
+    parts[first..]
+        .iter()
+        .map(|(value, unit)| format!("{}{}", value, unit))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `getEpochSchedule` on mainnet-beta, which never warmed up
+    fn mainnet() -> EpochSchedule {
+        EpochSchedule {
+            slots_per_epoch: 432_000,
+            leader_schedule_slot_offset: 432_000,
+            warmup: false,
+            first_normal_epoch: 0,
+            first_normal_slot: 0,
+        }
+    }
+
+    /// `getEpochSchedule` on devnet and testnet
+    fn warmup() -> EpochSchedule {
+        EpochSchedule {
+            slots_per_epoch: 432_000,
+            leader_schedule_slot_offset: 432_000,
+            warmup: true,
+            first_normal_epoch: 14,
+            first_normal_slot: 524_256,
+        }
+    }
+
+    #[test]
+    fn epoch_slots_without_warmup() {
+        let schedule = mainnet();
+        assert_eq!(schedule.epoch_and_slot_index(250_000_000), (578, 304_000));
+        assert_eq!(schedule.first_slot_in_epoch(578), Some(249_696_000));
+        assert_eq!(schedule.last_slot_in_epoch(578), Some(250_127_999));
+    }
+
+    #[test]
+    fn epoch_slots_during_warmup() {
+        let schedule = warmup();
+        assert_eq!(schedule.epoch_and_slot_index(0), (0, 0));
+        assert_eq!(schedule.epoch_and_slot_index(31), (0, 31));
+        assert_eq!(schedule.epoch_and_slot_index(32), (1, 0));
+        assert_eq!(schedule.epoch_and_slot_index(95), (1, 63));
+        assert_eq!(schedule.epoch_and_slot_index(524_255), (13, 262_143));
+        assert_eq!(schedule.epoch_and_slot_index(524_256), (14, 0));
+        assert_eq!(schedule.epoch_and_slot_index(956_256), (15, 0));
+        assert_eq!(schedule.first_slot_in_epoch(13), Some(262_112));
+        assert_eq!(schedule.last_slot_in_epoch(13), Some(524_255));
+        assert_eq!(schedule.first_slot_in_epoch(14), Some(524_256));
+        assert_eq!(schedule.first_slot_in_epoch(15), Some(956_256));
+    }
+
+    #[test]
+    fn epochs_past_the_last_slot_are_out_of_range() {
+        let schedule = mainnet();
+        let last_whole_epoch = u64::MAX / 432_000 - 1;
+        assert_eq!(schedule.last_slot_in_epoch(last_whole_epoch), Some(18_446_744_073_709_439_999));
+        assert_eq!(schedule.last_slot_in_epoch(last_whole_epoch + 1), None);
+        assert_eq!(schedule.first_slot_in_epoch(u64::MAX), None);
+
+        let schedule = EpochSchedule {
+            first_normal_epoch: u64::MAX,
+            ..warmup()
+        };
+        assert_eq!(schedule.first_slot_in_epoch(64), None);
+        assert_eq!(schedule.slots_in_epoch(58), 32 << 58);
+        assert_eq!(schedule.slots_in_epoch(59), 432_000);
+        assert_eq!(schedule.slots_in_epoch(64), 432_000);
+        assert_eq!(schedule.slots_in_epoch(u64::MAX - 1), 432_000);
+    }
+
+    #[test]
+    fn schedules_without_slots_per_epoch_do_not_divide_by_zero() {
+        let schedule = EpochSchedule {
+            slots_per_epoch: 0,
+            ..mainnet()
+        };
+        assert_eq!(schedule.epoch_and_slot_index(0), (0, 0));
+        assert_eq!(schedule.epoch_and_slot_index(1_000), (1_000, 0));
+        assert_eq!(schedule.slots_in_epoch(5), 0);
+    }
+
+    #[test]
+    fn epoch_end_time_of_the_last_epoch_is_out_of_range() {
+        let clock = EpochClock {
+            schedule: mainnet(),
+            epoch: 578,
+            current_slot: 250_000_000,
+            observed_at: 1_700_000_000,
+            slot_seconds: 0.4,
+        };
+        assert_eq!(clock.epoch_start_time(579), Some(1_700_000_000 + 51_200));
+        assert_eq!(clock.epoch_end_time(u64::MAX), None);
+        assert_eq!(clock.epoch_start_time(u64::MAX), None);
+    }
+
+    #[test]
+    fn durations_start_at_the_largest_unit() {
+        assert_eq!(format_duration(0), "0s");
+        assert_eq!(format_duration(45), "45s");
+        assert_eq!(format_duration(60), "1m");
+        assert_eq!(format_duration(3_600), "1h 0m");
+        assert_eq!(format_duration(86_400), "1d 0h 0m");
+        assert_eq!(format_duration(90_061), "1d 1h 1m");
+    }
+
+    #[test]
+    fn past_durations_are_formatted_by_magnitude() {
+        assert_eq!(format_duration(-45), "45s");
+        assert_eq!(format_duration(-3_700), "1h 1m");
+        assert_eq!(format_duration(i64::MIN), "106751991167300d 15h 30m");
+    }
+}
@@ -0,0 +1,414 @@
+//! IPv4 geolocation
+//!
+//! Gossip addresses are resolved against a CSV table of IPv4 ranges with rows
+//! of `start_ip,end_ip,country_code[,continent_code]`, the layout of DB-IP's
+//! IP-to-Country Lite file. A country-level table covering the networks most
+//! validators run in is compiled into the binary from `assets/geoip-ipv4.csv`;
+//! on desktop and mobile a full table can be dropped in next to the app as
+//! `geoip-ipv4.csv` (or pointed to with the `OPENSVM_GEOIP_DB` environment
+//! variable). Lookups run against the table locally; no address is sent
+//! anywhere.
+
+use std::cell::RefCell;
+use std::fmt;
+use std::net::{Ipv4Addr, SocketAddr};
+use std::rc::Rc;
+
+/// Table compiled into the binary, used when no local file is available
+const BUNDLED_DATABASE: &str = include_str!("../assets/geoip-ipv4.csv");
+
+/// File looked up in the working directory on native platforms
+#[cfg(any(feature = "desktop", feature = "android"))]
+const LOCAL_DATABASE_FILE: &str = "geoip-ipv4.csv";
+
+/// Environment variable that overrides the local database path
+#[cfg(any(feature = "desktop", feature = "android"))]
+const DATABASE_PATH_ENV: &str = "OPENSVM_GEOIP_DB";
+
+/// Country code used when a range is only known down to its continent
+pub const UNKNOWN_COUNTRY: &str = "ZZ";
+
+thread_local! {
+    static DATABASE: RefCell<Option<Rc<GeoIpDatabase>>> = const { RefCell::new(None) };
+}
+
+/// Where the loaded table came from, which bounds how precise lookups are
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GeoIpSource {
+    /// Table compiled into the binary
+    #[default]
+    Bundled,
+    /// Table read from a local file
+    LocalFile,
+}
+
+impl GeoIpSource {
+    /// How far results from this table can be trusted, for display
+    pub fn accuracy(self) -> &'static str {
+        match self {
+            GeoIpSource::Bundled => {
+                "Country level for the major hosting providers and single-owner legacy blocks; \
+                 other addresses only resolve to the continent of the internet registry that \
+                 administers them. Cloud addresses resolve to where they are registered, which \
+                 can differ from where the node runs."
+            }
+            GeoIpSource::LocalFile => "Resolved with a local IP table; accuracy depends on that table.",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Continent {
+    Africa,
+    Antarctica,
+    Asia,
+    Europe,
+    NorthAmerica,
+    Oceania,
+    SouthAmerica,
+}
+
+impl Continent {
+    /// Parse a two-letter continent code (`AF`, `AN`, `AS`, `EU`, `NA`, `OC`, `SA`)
+    pub fn from_code(code: &str) -> Option<Self> {
+        match code.to_ascii_uppercase().as_str() {
+            "AF" => Some(Continent::Africa),
+            "AN" => Some(Continent::Antarctica),
+            "AS" => Some(Continent::Asia),
+            "EU" => Some(Continent::Europe),
+            "NA" => Some(Continent::NorthAmerica),
+            "OC" => Some(Continent::Oceania),
+            "SA" => Some(Continent::SouthAmerica),
+            _ => None,
+        }
+    }
+
+    /// Continent a country belongs to, by ISO 3166-1 alpha-2 code
+    pub fn from_country(country_code: &str) -> Option<Self> {
+        let code = country_code.to_ascii_uppercase();
+        COUNTRY_CONTINENTS
+            .iter()
+            .find(|(_, countries)| countries.split(' ').any(|country| country == code))
+            .map(|(continent, _)| *continent)
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Continent::Africa => "Africa",
+            Continent::Antarctica => "Antarctica",
+            Continent::Asia => "Asia",
+            Continent::Europe => "Europe",
+            Continent::NorthAmerica => "North America",
+            Continent::Oceania => "Oceania",
+            Continent::SouthAmerica => "South America",
+        }
+    }
+}
+
+/// ISO 3166-1 alpha-2 codes grouped by continent; transcontinental countries
+/// are listed where their capital is
+const COUNTRY_CONTINENTS: &[(Continent, &str)] = &[
+    (
+        Continent::Africa,
+        "AO BF BI BJ BW CD CF CG CI CM CV DJ DZ EG EH ER ET GA GH GM GN GQ GW KE KM LR LS LY MA \
+         MG ML MR MU MW MZ NA NE NG RE RW SC SD SH SL SN SO SS ST SZ TD TG TN TZ UG YT ZA ZM ZW",
+    ),
+    (Continent::Antarctica, "AQ BV GS HM TF"),
+    (
+        Continent::Asia,
+        "AE AF AM AZ BD BH BN BT CC CN CX GE HK ID IL IN IO IQ IR JO JP KG KH KP KR KW KZ LA LB \
+         LK MM MN MO MV MY NP OM PH PK PS QA SA SG SY TH TJ TL TM TR TW UZ VN YE",
+    ),
+    (
+        Continent::Europe,
+        "AD AL AT AX BA BE BG BY CH CY CZ DE DK EE ES FI FO FR GB GG GI GR HR HU IE IM IS IT JE \
+         LI LT LU LV MC MD ME MK MT NL NO PL PT RO RS RU SE SI SJ SK SM UA VA XK",
+    ),
+    (
+        Continent::NorthAmerica,
+        "AG AI AW BB BL BM BQ BS BZ CA CR CU CW DM DO GD GL GP GT HN HT JM KN KY LC MF MQ MS MX \
+         NI PA PM PR SV SX TC TT US VC VG VI",
+    ),
+    (
+        Continent::Oceania,
+        "AS AU CK FJ FM GU KI MH MP NC NF NR NU NZ PF PG PN PW SB TK TO TV UM VU WF WS",
+    ),
+    (Continent::SouthAmerica, "AR BO BR CL CO EC FK GF GY PE PY SR UY VE"),
+];
+
+/// Where an address was resolved to
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GeoLocation {
+    /// ISO 3166-1 alpha-2 code, or [`UNKNOWN_COUNTRY`]
+    pub country_code: String,
+    pub continent: Continent,
+}
+
+impl GeoLocation {
+    pub fn country_known(&self) -> bool {
+        self.country_code != UNKNOWN_COUNTRY
+    }
+}
+
+/// Inclusive range of IPv4 addresses sharing a location
+#[derive(Debug, Clone)]
+struct IpRange {
+    start: u32,
+    end: u32,
+    location: GeoLocation,
+}
+
+/// A row of the table that could not be read
+#[derive(Debug, Clone, PartialEq)]
+pub struct GeoIpError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for GeoIpError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for GeoIpError {}
+
+/// Sorted, non-overlapping IPv4 ranges
+#[derive(Debug, Clone, Default)]
+pub struct GeoIpDatabase {
+    ranges: Vec<IpRange>,
+    source: GeoIpSource,
+}
+
+impl GeoIpDatabase {
+    /// Parse a range table. Blank lines, `#` comments, a header row and IPv6
+    /// rows are skipped.
+    pub fn parse(csv: &str) -> Result<Self, GeoIpError> {
+        let mut ranges = Vec::new();
+
+        for (index, line) in csv.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') || line.contains(':') {
+                continue;
+            }
+
+            let error = |message: String| GeoIpError {
+                line: index + 1,
+                message,
+            };
+            let fields: Vec<&str> = line.split(',').map(|field| field.trim().trim_matches('"')).collect();
+            if fields.len() < 3 {
+                return Err(error(format!("expected at least 3 fields, found {}", fields.len())));
+            }
+
+            let (start, end) = match (fields[0].parse::<Ipv4Addr>(), fields[1].parse::<Ipv4Addr>()) {
+                (Ok(start), Ok(end)) => (u32::from(start), u32::from(end)),
+                // Header rows such as `ip_start,ip_end,country`
+                _ if ranges.is_empty() && fields[0].parse::<Ipv4Addr>().is_err() => continue,
+                _ => return Err(error(format!("invalid address range {}-{}", fields[0], fields[1]))),
+            };
+            if start > end {
+                return Err(error(format!("range start {} is after its end", fields[0])));
+            }
+
+            let country_code = fields[2].to_ascii_uppercase();
+            let continent = fields
+                .get(3)
+                .and_then(|code| Continent::from_code(code))
+                .or_else(|| Continent::from_country(&country_code));
+            // Reserved and unassigned space has no continent; leave it out
+            let Some(continent) = continent else {
+                continue;
+            };
+
+            ranges.push(IpRange {
+                start,
+                end,
+                location: GeoLocation {
+                    country_code,
+                    continent,
+                },
+            });
+        }
+
+        ranges.sort_by_key(|range| range.start);
+        Ok(Self {
+            ranges,
+            source: GeoIpSource::default(),
+        })
+    }
+
+    /// The table compiled into the binary
+    pub fn bundled() -> Self {
+        Self::parse(BUNDLED_DATABASE).unwrap_or_else(|e| {
+            log::error!("Bundled geoip table is invalid: {}", e);
+            Self::default()
+        })
+    }
+
+    /// Shared database, parsed on first use. Parsing a full local table takes
+    /// a while, so call this from an effect rather than while rendering.
+    pub fn shared() -> Rc<Self> {
+        DATABASE.with(|database| {
+            database
+                .borrow_mut()
+                .get_or_insert_with(|| Rc::new(Self::load()))
+                .clone()
+        })
+    }
+
+    /// Prefer a local table file, falling back to the bundled one
+    #[cfg(any(feature = "desktop", feature = "android"))]
+    fn load() -> Self {
+        let path = std::env::var(DATABASE_PATH_ENV).unwrap_or_else(|_| LOCAL_DATABASE_FILE.to_string());
+        match std::fs::read_to_string(&path) {
+            Ok(content) => match Self::parse(&content) {
+                Ok(database) => {
+                    log::info!("Loaded {} geoip ranges from {}", database.len(), path);
+                    return Self {
+                        source: GeoIpSource::LocalFile,
+                        ..database
+                    };
+                }
+                Err(e) => log::warn!("Failed to parse geoip table {}: {}, using bundled table", path, e),
+            },
+            Err(_) => log::info!("No geoip table at {}, using bundled table", path),
+        }
+        Self::bundled()
+    }
+
+    #[cfg(not(any(feature = "desktop", feature = "android")))]
+    fn load() -> Self {
+        Self::bundled()
+    }
+
+    pub fn source(&self) -> GeoIpSource {
+        self.source
+    }
+
+    pub fn len(&self) -> usize {
+        self.ranges.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    pub fn lookup(&self, ip: Ipv4Addr) -> Option<&GeoLocation> {
+        let ip = u32::from(ip);
+        let index = self.ranges.partition_point(|range| range.start <= ip);
+        let range = self.ranges.get(index.checked_sub(1)?)?;
+        (ip <= range.end).then_some(&range.location)
+    }
+
+    /// Resolve a gossip address such as `141.98.217.57:8001`
+    pub fn lookup_socket(&self, address: &str) -> Option<&GeoLocation> {
+        match address.parse::<SocketAddr>().ok()? {
+            SocketAddr::V4(address) => self.lookup(*address.ip()),
+            SocketAddr::V6(address) => self.lookup(address.ip().to_ipv4_mapped()?),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TABLE: &str = "\
+# comment
+ip_start,ip_end,country
+10.0.0.0,10.0.0.255,DE
+
+10.0.2.0,10.0.2.255,ZZ,AS
+\"10.0.1.0\",\"10.0.1.255\",us
+2001:db8::,2001:db8::ffff,FR
+10.0.3.0,10.0.3.255,XX
+";
+
+    fn location(country_code: &str, continent: Continent) -> Option<GeoLocation> {
+        Some(GeoLocation {
+            country_code: country_code.to_string(),
+            continent,
+        })
+    }
+
+    #[test]
+    fn parses_ranges_skipping_headers_comments_and_ipv6() {
+        let database = GeoIpDatabase::parse(TABLE).unwrap();
+        // The XX row has no continent and is left out
+        assert_eq!(database.len(), 3);
+        assert_eq!(database.source(), GeoIpSource::Bundled);
+        assert_eq!(
+            database.lookup("10.0.1.7".parse().unwrap()).cloned(),
+            location("US", Continent::NorthAmerica)
+        );
+        assert_eq!(
+            database.lookup("10.0.2.7".parse().unwrap()).cloned(),
+            location(UNKNOWN_COUNTRY, Continent::Asia)
+        );
+    }
+
+    #[test]
+    fn invalid_rows_report_their_line() {
+        let error = GeoIpDatabase::parse("1.0.0.0,1.0.0.255,AU\n1.0.1.0,nope,AU").unwrap_err();
+        assert_eq!(error.to_string(), "line 2: invalid address range 1.0.1.0-nope");
+
+        let error = GeoIpDatabase::parse("1.0.1.0,1.0.0.0,AU").unwrap_err();
+        assert_eq!(error.message, "range start 1.0.1.0 is after its end");
+
+        let error = GeoIpDatabase::parse("1.0.0.0,1.0.0.255").unwrap_err();
+        assert_eq!(error.message, "expected at least 3 fields, found 2");
+    }
+
+    #[test]
+    fn lookups_include_both_range_ends() {
+        let database = GeoIpDatabase::parse(TABLE).unwrap();
+        let country = |ip: &str| {
+            database
+                .lookup(ip.parse().unwrap())
+                .map(|location| location.country_code.as_str())
+        };
+
+        assert_eq!(country("9.255.255.255"), None);
+        assert_eq!(country("10.0.0.0"), Some("DE"));
+        assert_eq!(country("10.0.0.255"), Some("DE"));
+        assert_eq!(country("10.0.1.0"), Some("US"));
+        assert_eq!(country("10.0.2.255"), Some(UNKNOWN_COUNTRY));
+        // Between and after the ranges
+        assert_eq!(country("10.0.3.0"), None);
+        assert_eq!(country("255.255.255.255"), None);
+    }
+
+    #[test]
+    fn gossip_addresses_resolve_ipv4_and_mapped_ipv6() {
+        let database = GeoIpDatabase::parse(TABLE).unwrap();
+        let country = |address: &str| {
+            database
+                .lookup_socket(address)
+                .map(|location| location.country_code.as_str())
+        };
+
+        assert_eq!(country("10.0.0.1:8001"), Some("DE"));
+        assert_eq!(country("[::ffff:10.0.1.1]:8001"), Some("US"));
+        // Native IPv6 is not in the table
+        assert_eq!(country("[2001:db8::1]:8001"), None);
+        // Unparseable gossip addresses
+        assert_eq!(country("10.0.0.1"), None);
+        assert_eq!(country("validator.example:8001"), None);
+        assert_eq!(country(""), None);
+    }
+
+    #[test]
+    fn bundled_table_is_sorted_and_has_countries() {
+        let database = GeoIpDatabase::bundled();
+        assert!(database.ranges.windows(2).all(|pair| pair[0].end < pair[1].start));
+        assert_eq!(
+            database.lookup("65.108.0.1".parse().unwrap()).cloned(),
+            location("FI", Continent::Europe)
+        );
+        assert_eq!(
+            database.lookup("1.1.1.1".parse().unwrap()).cloned(),
+            location(UNKNOWN_COUNTRY, Continent::Asia)
+        );
+    }
+}
@@ -1,7 +1,16 @@
 //! Utility functions for the application
 
 pub mod address_utils;
+pub mod anchor;
 pub mod api;
+pub mod decoder;
+pub mod emission;
+pub mod epoch;
+pub mod geoip;
+pub mod node_distribution;
+pub mod pubsub;
+pub mod stake_metrics;
+pub mod staking_yield;
 
 // Re-export commonly used functions
 // pub use address_utils::*;
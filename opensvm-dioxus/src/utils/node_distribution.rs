@@ -0,0 +1,403 @@
+//! Software version, node role and geographic distribution of the cluster
+//!
+//! Gossip nodes from `getClusterNodes` are joined with vote accounts by
+//! validator identity, so every version, feature set and region can be
+//! weighted by the stake behind it.
+
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+use crate::utils::api::{ClusterNode, VoteAccounts};
+use crate::utils::geoip::{Continent, GeoIpDatabase, GeoIpSource};
+
+/// Label used for nodes that do not report a version or feature set
+pub const UNKNOWN: &str = "unknown";
+
+/// Validator client, told apart by version scheme: Firedancer (including
+/// Frankendancer) reports 0.x versions, while Agave and its forks such as
+/// Jito-Solana report 1.x and up. Version numbers of different clients are
+/// unrelated and must not be ordered against each other.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ValidatorClient {
+    Agave,
+    Firedancer,
+    Unknown,
+}
+
+impl ValidatorClient {
+    pub fn from_version(version: &str) -> Self {
+        match version_parts(version).as_deref() {
+            Some([0, ..]) => ValidatorClient::Firedancer,
+            Some(_) => ValidatorClient::Agave,
+            None => ValidatorClient::Unknown,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            ValidatorClient::Agave => "Agave",
+            ValidatorClient::Firedancer => "Firedancer",
+            ValidatorClient::Unknown => "Unknown",
+        }
+    }
+}
+
+/// Nodes and stake running one software version or feature set
+#[derive(Debug, Clone, PartialEq)]
+pub struct SoftwareShare {
+    pub label: String,
+    /// Client of a software version; `None` for feature sets
+    pub client: Option<ValidatorClient>,
+    pub nodes: usize,
+    /// Active stake in lamports
+    pub stake: u64,
+    /// Share of staked nodes' stake (0.0 - 1.0)
+    pub stake_share: f64,
+    /// Share of stake on this entry and every entry listed before it; for
+    /// versions, only entries of the same client count (0.0 - 1.0)
+    pub cumulative_share: f64,
+}
+
+/// How the nodes in gossip are split by software and role
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct NodeDistribution {
+    /// Grouped by client, newest version first within each
+    pub by_version: Vec<SoftwareShare>,
+    /// Most staked feature set first; feature set ids are hashes with no order
+    pub by_feature_set: Vec<SoftwareShare>,
+    /// Nodes whose identity votes with stake
+    pub voting_nodes: usize,
+    /// Non-voting nodes that expose a public RPC port
+    pub rpc_nodes: usize,
+    /// Everything else (e.g. unstaked validators, gossip-only nodes)
+    pub other_nodes: usize,
+    /// Stake of vote accounts whose identity is not visible in gossip
+    pub unseen_stake: u64,
+}
+
+impl NodeDistribution {
+    pub fn new(nodes: &[ClusterNode], vote_accounts: &VoteAccounts) -> Self {
+        let stake_by_identity = stake_by_identity(vote_accounts);
+        let mut versions: HashMap<String, (usize, u64)> = HashMap::new();
+        let mut feature_sets: HashMap<String, (usize, u64)> = HashMap::new();
+        let mut distribution = NodeDistribution::default();
+        let mut seen_stake = 0u64;
+
+        for node in nodes {
+            let stake = stake_by_identity.get(node.pubkey.as_str()).copied().unwrap_or(0);
+            seen_stake += stake;
+
+            if stake > 0 {
+                distribution.voting_nodes += 1;
+            } else if node.rpc.is_some() {
+                distribution.rpc_nodes += 1;
+            } else {
+                distribution.other_nodes += 1;
+            }
+
+            let version = node.version.clone().unwrap_or_else(|| UNKNOWN.to_string());
+            let entry = versions.entry(version).or_default();
+            entry.0 += 1;
+            entry.1 += stake;
+
+            let feature_set = node
+                .feature_set
+                .map(|feature_set| feature_set.to_string())
+                .unwrap_or_else(|| UNKNOWN.to_string());
+            let entry = feature_sets.entry(feature_set).or_default();
+            entry.0 += 1;
+            entry.1 += stake;
+        }
+
+        let total_stake: u64 = stake_by_identity.values().sum();
+        distribution.unseen_stake = total_stake.saturating_sub(seen_stake);
+        let mut versions: Vec<_> = versions.into_iter().collect();
+        versions.sort_by(|(a, _), (b, _)| {
+            ValidatorClient::from_version(a)
+                .cmp(&ValidatorClient::from_version(b))
+                .then_with(|| compare_versions(b, a))
+        });
+        let mut feature_sets: Vec<_> = feature_sets.into_iter().collect();
+        feature_sets.sort_by_key(|(_, (_, stake))| std::cmp::Reverse(*stake));
+
+        distribution.by_version = shares(versions, seen_stake, |version| Some(ValidatorClient::from_version(version)));
+        distribution.by_feature_set = shares(feature_sets, seen_stake, |_| None);
+        distribution
+    }
+
+    pub fn total_nodes(&self) -> usize {
+        self.voting_nodes + self.rpc_nodes + self.other_nodes
+    }
+}
+
+/// Nodes and stake located in one continent or country
+#[derive(Debug, Clone, PartialEq)]
+pub struct RegionShare {
+    pub label: String,
+    pub nodes: usize,
+    /// Active stake in lamports
+    pub stake: u64,
+    /// Share of located stake (0.0 - 1.0)
+    pub stake_share: f64,
+}
+
+/// Where the nodes in gossip are hosted
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct RegionDistribution {
+    /// Most staked continent first
+    pub by_continent: Vec<RegionShare>,
+    /// Most staked country first; empty when the table has no country data
+    pub by_country: Vec<RegionShare>,
+    /// Nodes whose gossip address is not in the table
+    pub unlocated_nodes: usize,
+    /// Stake of nodes whose gossip address is not in the table
+    pub unlocated_stake: u64,
+    /// Table the addresses were resolved with
+    pub source: GeoIpSource,
+}
+
+impl RegionDistribution {
+    pub fn new(nodes: &[ClusterNode], vote_accounts: &VoteAccounts, database: &GeoIpDatabase) -> Self {
+        let stake_by_identity = stake_by_identity(vote_accounts);
+        let mut continents: HashMap<Continent, (usize, u64)> = HashMap::new();
+        let mut countries: HashMap<String, (usize, u64)> = HashMap::new();
+        let mut distribution = RegionDistribution {
+            source: database.source(),
+            ..RegionDistribution::default()
+        };
+        let mut located_stake = 0u64;
+
+        for node in nodes {
+            let stake = stake_by_identity.get(node.pubkey.as_str()).copied().unwrap_or(0);
+            let location = node
+                .gossip
+                .as_deref()
+                .and_then(|address| database.lookup_socket(address));
+
+            let Some(location) = location else {
+                distribution.unlocated_nodes += 1;
+                distribution.unlocated_stake += stake;
+                continue;
+            };
+
+            located_stake += stake;
+            let entry = continents.entry(location.continent).or_default();
+            entry.0 += 1;
+            entry.1 += stake;
+
+            if location.country_known() {
+                let entry = countries.entry(location.country_code.clone()).or_default();
+                entry.0 += 1;
+                entry.1 += stake;
+            }
+        }
+
+        let continents = continents
+            .into_iter()
+            .map(|(continent, counts)| (continent.name().to_string(), counts))
+            .collect();
+        distribution.by_continent = region_shares(continents, located_stake);
+        distribution.by_country = region_shares(countries.into_iter().collect(), located_stake);
+        distribution
+    }
+}
+
+/// Identity -> total active stake of its vote accounts
+fn stake_by_identity(vote_accounts: &VoteAccounts) -> HashMap<&str, u64> {
+    let mut stake_by_identity: HashMap<&str, u64> = HashMap::new();
+    for account in vote_accounts.current.iter().chain(&vote_accounts.delinquent) {
+        *stake_by_identity.entry(account.node_pubkey.as_str()).or_default() += account.activated_stake;
+    }
+    stake_by_identity
+}
+
+/// Turn `(label, (nodes, stake))` groups into shares, most staked first
+fn region_shares(mut groups: Vec<(String, (usize, u64))>, total_stake: u64) -> Vec<RegionShare> {
+    // Ties (e.g. unstaked regions) fall back to node count, then name
+    groups.sort_by(|(a_label, (a_nodes, a_stake)), (b_label, (b_nodes, b_stake))| {
+        b_stake
+            .cmp(a_stake)
+            .then(b_nodes.cmp(a_nodes))
+            .then(a_label.cmp(b_label))
+    });
+
+    groups
+        .into_iter()
+        .map(|(label, (nodes, stake))| RegionShare {
+            label,
+            nodes,
+            stake,
+            stake_share: if total_stake == 0 {
+                0.0
+            } else {
+                stake as f64 / total_stake as f64
+            },
+        })
+        .collect()
+}
+
+/// Turn sorted `(label, (nodes, stake))` groups into shares; the cumulative
+/// share restarts whenever the client changes
+fn shares(
+    groups: Vec<(String, (usize, u64))>,
+    total_stake: u64,
+    client_of: impl Fn(&str) -> Option<ValidatorClient>,
+) -> Vec<SoftwareShare> {
+    let share = |stake: u64| {
+        if total_stake == 0 {
+            0.0
+        } else {
+            stake as f64 / total_stake as f64
+        }
+    };
+
+    let mut cumulative = 0u64;
+    let mut previous_client = None;
+    groups
+        .into_iter()
+        .map(|(label, (nodes, stake))| {
+            let client = client_of(&label);
+            if client != previous_client {
+                cumulative = 0;
+                previous_client = client;
+            }
+            cumulative += stake;
+            SoftwareShare {
+                label,
+                client,
+                nodes,
+                stake,
+                stake_share: share(stake),
+                cumulative_share: share(cumulative),
+            }
+        })
+        .collect()
+}
+
+/// Numeric components of a version such as `2.1.13` or `0.503.20214`
+fn version_parts(version: &str) -> Option<Vec<u64>> {
+    version
+        .split(['.', '-', '+'])
+        .take(3)
+        .map(|part| part.parse().ok())
+        .collect()
+}
+
+/// Order versions semantically; unparseable versions sort as oldest. Only
+/// meaningful for versions of the same [`ValidatorClient`].
+pub fn compare_versions(a: &str, b: &str) -> Ordering {
+    match (version_parts(a), version_parts(b)) {
+        (Some(a), Some(b)) => a.cmp(&b),
+        (Some(_), None) => Ordering::Greater,
+        (None, Some(_)) => Ordering::Less,
+        (None, None) => a.cmp(b),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::api::test_vote_account;
+
+    fn node(pubkey: &str, version: &str) -> ClusterNode {
+        ClusterNode {
+            pubkey: pubkey.to_string(),
+            gossip: None,
+            tpu: None,
+            rpc: None,
+            version: Some(version.to_string()),
+            feature_set: None,
+            shred_version: None,
+        }
+    }
+
+    #[test]
+    fn clients_are_told_apart_by_version_scheme() {
+        assert_eq!(ValidatorClient::from_version("0.503.20214"), ValidatorClient::Firedancer);
+        assert_eq!(ValidatorClient::from_version("2.2.14"), ValidatorClient::Agave);
+        assert_eq!(ValidatorClient::from_version("3.0.0"), ValidatorClient::Agave);
+        assert_eq!(ValidatorClient::from_version(UNKNOWN), ValidatorClient::Unknown);
+    }
+
+    #[test]
+    fn versions_are_ordered_and_accumulated_per_client() {
+        let nodes = [
+            node("a", "2.2.14"),
+            node("b", "2.3.1"),
+            node("c", "0.503.20214"),
+            node("d", "0.505.20216"),
+        ];
+        let vote_accounts = VoteAccounts {
+            current: vec![
+                test_vote_account("a", 40),
+                test_vote_account("b", 30),
+                test_vote_account("c", 20),
+                test_vote_account("d", 10),
+            ],
+            delinquent: Vec::new(),
+        };
+
+        let distribution = NodeDistribution::new(&nodes, &vote_accounts);
+        let rows: Vec<(&str, f64)> = distribution
+            .by_version
+            .iter()
+            .map(|share| (share.label.as_str(), share.cumulative_share))
+            .collect();
+        assert_eq!(
+            rows,
+            [("2.3.1", 0.3), ("2.2.14", 0.7), ("0.505.20216", 0.1), ("0.503.20214", 0.3)]
+        );
+    }
+
+    #[test]
+    fn regions_group_located_stake_by_continent_and_country() {
+        let database = GeoIpDatabase::parse(
+            "10.0.0.0,10.0.0.255,DE\n10.0.1.0,10.0.1.255,FR\n10.0.2.0,10.0.2.255,ZZ,AS",
+        )
+        .unwrap();
+        let gossip = |pubkey: &str, address: Option<&str>| ClusterNode {
+            gossip: address.map(str::to_string),
+            ..node(pubkey, "2.2.14")
+        };
+        let nodes = [
+            gossip("de-1", Some("10.0.0.1:8001")),
+            gossip("de-2", Some("10.0.0.2:8001")),
+            gossip("fr", Some("[::ffff:10.0.1.1]:8001")),
+            gossip("asia", Some("10.0.2.1:8001")),
+            gossip("unlisted", Some("192.0.2.1:8001")),
+            gossip("no-gossip", None),
+            gossip("invalid", Some("not an address")),
+        ];
+        let vote_accounts = VoteAccounts {
+            current: vec![
+                test_vote_account("de-1", 30),
+                test_vote_account("de-2", 10),
+                test_vote_account("fr", 50),
+                test_vote_account("asia", 10),
+                test_vote_account("unlisted", 7),
+            ],
+            delinquent: vec![test_vote_account("no-gossip", 3)],
+        };
+
+        let regions = RegionDistribution::new(&nodes, &vote_accounts, &database);
+        let shares = |shares: &[RegionShare]| -> Vec<(String, usize, u64, f64)> {
+            shares
+                .iter()
+                .map(|share| (share.label.clone(), share.nodes, share.stake, share.stake_share))
+                .collect()
+        };
+
+        assert_eq!(
+            shares(&regions.by_continent),
+            [("Europe".to_string(), 3, 90, 0.9), ("Asia".to_string(), 1, 10, 0.1)]
+        );
+        // Ranges only known down to their continent are left out of countries
+        assert_eq!(
+            shares(&regions.by_country),
+            [("FR".to_string(), 1, 50, 0.5), ("DE".to_string(), 2, 40, 0.4)]
+        );
+        assert_eq!(regions.unlocated_nodes, 3);
+        assert_eq!(regions.unlocated_stake, 10);
+        assert_eq!(regions.source, GeoIpSource::Bundled);
+    }
+}
@@ -0,0 +1,542 @@
+//! WebSocket pubsub client for live Solana updates
+//!
+//! A single connection per app is driven by [`run`] inside a coroutine
+//! started by [`use_pubsub_provider`]. Components subscribe through
+//! [`use_subscription`] and receive every notification as a JSON value.
+//!
+//! The driver connects lazily once something is subscribed, reconnects with
+//! backoff when the socket drops, and re-sends every active subscription on
+//! each new connection. Switching cluster moves all subscriptions over to the
+//! new endpoint.
+
+pub mod socket;
+
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::time::Duration;
+
+use dioxus::prelude::*;
+use futures_channel::mpsc::{UnboundedReceiver, UnboundedSender};
+use futures_util::future::{self, Either};
+use futures_util::stream::{self, StreamExt};
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use crate::stores::cluster_store::use_cluster_store;
+use crate::utils::api::retry::{self, RetryPolicy};
+use crate::utils::api::RpcError;
+use socket::{SocketEvent, SocketSink};
+
+/// Something to subscribe to
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Subscription {
+    /// Every slot processed by the node
+    Slot,
+    /// Changes to one account's lamports or data
+    Account { pubkey: String },
+    /// Transaction logs, optionally only those mentioning an address
+    Logs { mentions: Option<String> },
+    /// Confirmation of a single transaction
+    Signature { signature: String },
+    /// Changes to any account owned by a program
+    Program { program_id: String },
+}
+
+impl Subscription {
+    fn method(&self) -> &'static str {
+        match self {
+            Subscription::Slot => "slotSubscribe",
+            Subscription::Account { .. } => "accountSubscribe",
+            Subscription::Logs { .. } => "logsSubscribe",
+            Subscription::Signature { .. } => "signatureSubscribe",
+            Subscription::Program { .. } => "programSubscribe",
+        }
+    }
+
+    fn unsubscribe_method(&self) -> &'static str {
+        match self {
+            Subscription::Slot => "slotUnsubscribe",
+            Subscription::Account { .. } => "accountUnsubscribe",
+            Subscription::Logs { .. } => "logsUnsubscribe",
+            Subscription::Signature { .. } => "signatureUnsubscribe",
+            Subscription::Program { .. } => "programUnsubscribe",
+        }
+    }
+
+    fn params(&self) -> Vec<Value> {
+        match self {
+            Subscription::Slot => vec![],
+            Subscription::Account { pubkey } => vec![
+                json!(pubkey),
+                json!({ "encoding": "base64", "commitment": "confirmed" }),
+            ],
+            Subscription::Logs { mentions } => {
+                let filter = match mentions {
+                    Some(address) => json!({ "mentions": [address] }),
+                    None => json!("all"),
+                };
+                vec![filter, json!({ "commitment": "confirmed" })]
+            }
+            Subscription::Signature { signature } => {
+                vec![json!(signature), json!({ "commitment": "confirmed" })]
+            }
+            Subscription::Program { program_id } => vec![
+                json!(program_id),
+                json!({ "encoding": "base64", "commitment": "confirmed" }),
+            ],
+        }
+    }
+}
+
+/// Payload of a `slotNotification`
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct SlotInfo {
+    pub parent: u64,
+    pub root: u64,
+    pub slot: u64,
+}
+
+/// Messages accepted by the pubsub driver
+#[derive(Debug)]
+pub enum PubsubCommand {
+    /// Start a subscription; notifications are sent to `sender`
+    Subscribe {
+        id: u64,
+        subscription: Subscription,
+        sender: UnboundedSender<Value>,
+    },
+    /// Stop the subscription started with the same `id`
+    Unsubscribe { id: u64 },
+    /// Connect to a different WebSocket endpoint
+    SetEndpoint(String),
+}
+
+thread_local! {
+    static NEXT_SUBSCRIPTION_ID: Cell<u64> = const { Cell::new(1) };
+}
+
+/// App-unique id for a new subscription
+fn next_subscription_id() -> u64 {
+    NEXT_SUBSCRIPTION_ID.with(|next| {
+        let id = next.get();
+        next.set(id + 1);
+        id
+    })
+}
+
+/// Backoff between reconnect attempts
+fn reconnect_policy() -> RetryPolicy {
+    RetryPolicy {
+        base_delay: Duration::from_millis(500),
+        max_delay: Duration::from_secs(30),
+        ..RetryPolicy::default()
+    }
+}
+
+struct ActiveSubscription {
+    subscription: Subscription,
+    sender: UnboundedSender<Value>,
+}
+
+/// State that survives reconnects
+#[derive(Default)]
+struct Driver {
+    endpoint: Option<String>,
+    subscriptions: HashMap<u64, ActiveSubscription>,
+}
+
+impl Driver {
+    /// Apply a command while disconnected; returns true if the endpoint changed
+    fn apply(&mut self, command: PubsubCommand) -> bool {
+        match command {
+            PubsubCommand::Subscribe {
+                id,
+                subscription,
+                sender,
+            } => {
+                self.subscriptions.insert(id, ActiveSubscription { subscription, sender });
+                false
+            }
+            PubsubCommand::Unsubscribe { id } => {
+                self.subscriptions.remove(&id);
+                false
+            }
+            PubsubCommand::SetEndpoint(url) => {
+                let changed = self.endpoint.as_ref() != Some(&url);
+                self.endpoint = Some(url);
+                changed
+            }
+        }
+    }
+
+    fn is_idle(&self) -> bool {
+        self.endpoint.is_none() || self.subscriptions.is_empty()
+    }
+}
+
+/// Why a connection stopped
+enum Disconnect {
+    /// The socket closed or failed
+    Closed { was_open: bool },
+    /// The endpoint changed, reconnect right away
+    EndpointChanged,
+    /// Nothing is subscribed any more
+    Idle,
+    /// The command channel is gone
+    Shutdown,
+}
+
+/// State of one WebSocket connection
+struct Connection {
+    sink: SocketSink,
+    open: bool,
+    next_request_id: u64,
+    /// Subscribe request id -> local subscription id and its unsubscribe method
+    pending: HashMap<u64, (u64, &'static str)>,
+    /// Server subscription id -> local subscription id
+    server_ids: HashMap<u64, u64>,
+}
+
+impl Connection {
+    async fn send(&mut self, method: &str, params: Vec<Value>) -> Result<u64, RpcError> {
+        let id = self.next_request_id;
+        self.next_request_id += 1;
+        let request = json!({ "jsonrpc": "2.0", "id": id, "method": method, "params": params });
+        self.sink.send(request.to_string()).await?;
+        Ok(id)
+    }
+
+    async fn subscribe(&mut self, id: u64, subscription: &Subscription) -> Result<(), RpcError> {
+        let request_id = self.send(subscription.method(), subscription.params()).await?;
+        self.pending
+            .insert(request_id, (id, subscription.unsubscribe_method()));
+        Ok(())
+    }
+
+    async fn unsubscribe(&mut self, id: u64, subscription: &Subscription) -> Result<(), RpcError> {
+        let server_id = self
+            .server_ids
+            .iter()
+            .find_map(|(server_id, local_id)| (*local_id == id).then_some(*server_id));
+        if let Some(server_id) = server_id {
+            self.server_ids.remove(&server_id);
+            self.send(subscription.unsubscribe_method(), vec![json!(server_id)])
+                .await?;
+        }
+        Ok(())
+    }
+}
+
+/// Run the pubsub driver until the command channel closes
+pub async fn run(mut commands: UnboundedReceiver<PubsubCommand>) {
+    let mut driver = Driver::default();
+    let policy = reconnect_policy();
+    let mut attempt = 0;
+
+    loop {
+        // Stay disconnected until there is an endpoint and something to watch
+        while driver.is_idle() {
+            match commands.next().await {
+                Some(command) => {
+                    driver.apply(command);
+                }
+                None => return,
+            }
+        }
+
+        let Some(url) = driver.endpoint.clone() else {
+            continue;
+        };
+
+        let disconnect = match socket::connect(&url).await {
+            Ok((sink, events)) => connection(&mut driver, sink, events, &mut commands).await,
+            Err(e) => {
+                log::warn!("pubsub: could not connect to {}: {}", url, e);
+                Disconnect::Closed { was_open: false }
+            }
+        };
+
+        match disconnect {
+            Disconnect::Closed { was_open } => {
+                if was_open {
+                    attempt = 0;
+                }
+                let delay = policy.delay_for(attempt, None);
+                attempt = attempt.saturating_add(1);
+                log::info!("pubsub: reconnecting to {} in {:?}", url, delay);
+                if !backoff(&mut driver, &mut commands, delay).await {
+                    return;
+                }
+            }
+            Disconnect::EndpointChanged | Disconnect::Idle => attempt = 0,
+            Disconnect::Shutdown => return,
+        }
+    }
+}
+
+/// Wait before reconnecting while still accepting commands
+///
+/// Returns early if the endpoint changes, and `false` if the command channel
+/// closed.
+async fn backoff(
+    driver: &mut Driver,
+    commands: &mut UnboundedReceiver<PubsubCommand>,
+    delay: Duration,
+) -> bool {
+    let mut sleep = Box::pin(retry::sleep(delay));
+    loop {
+        match future::select(sleep, commands.next()).await {
+            Either::Left(_) => return true,
+            Either::Right((Some(command), pending_sleep)) => {
+                if driver.apply(command) {
+                    return true;
+                }
+                sleep = pending_sleep;
+            }
+            Either::Right((None, _)) => return false,
+        }
+    }
+}
+
+enum Input {
+    Socket(SocketEvent),
+    Command(PubsubCommand),
+    CommandsClosed,
+}
+
+/// Drive one connection until it closes or is no longer needed
+async fn connection(
+    driver: &mut Driver,
+    sink: SocketSink,
+    events: stream::LocalBoxStream<'static, SocketEvent>,
+    commands: &mut UnboundedReceiver<PubsubCommand>,
+) -> Disconnect {
+    let mut connection = Connection {
+        sink,
+        open: false,
+        next_request_id: 1,
+        pending: HashMap::new(),
+        server_ids: HashMap::new(),
+    };
+
+    let socket_events = events.map(Input::Socket);
+    let command_events = commands
+        .by_ref()
+        .map(Input::Command)
+        .chain(stream::once(future::ready(Input::CommandsClosed)));
+    let mut inputs = stream::select(socket_events, command_events);
+
+    while let Some(input) = inputs.next().await {
+        let result = match input {
+            Input::Socket(SocketEvent::Open) => {
+                connection.open = true;
+                let mut result = Ok(());
+                for (id, active) in &driver.subscriptions {
+                    result = connection.subscribe(*id, &active.subscription).await;
+                    if result.is_err() {
+                        break;
+                    }
+                }
+                result
+            }
+            Input::Socket(SocketEvent::Message(text)) => {
+                handle_message(driver, &mut connection, &text).await
+            }
+            Input::Socket(SocketEvent::Closed) => break,
+            Input::CommandsClosed => return Disconnect::Shutdown,
+            Input::Command(PubsubCommand::Subscribe {
+                id,
+                subscription,
+                sender,
+            }) => {
+                let result = if connection.open {
+                    connection.subscribe(id, &subscription).await
+                } else {
+                    Ok(())
+                };
+                driver
+                    .subscriptions
+                    .insert(id, ActiveSubscription { subscription, sender });
+                result
+            }
+            Input::Command(PubsubCommand::Unsubscribe { id }) => {
+                let result = match driver.subscriptions.remove(&id) {
+                    Some(active) => connection.unsubscribe(id, &active.subscription).await,
+                    None => Ok(()),
+                };
+                if driver.subscriptions.is_empty() {
+                    return Disconnect::Idle;
+                }
+                result
+            }
+            Input::Command(command @ PubsubCommand::SetEndpoint(_)) => {
+                if driver.apply(command) {
+                    return Disconnect::EndpointChanged;
+                }
+                Ok(())
+            }
+        };
+
+        if let Err(e) = result {
+            log::warn!("pubsub: send failed: {}", e);
+            break;
+        }
+    }
+
+    Disconnect::Closed {
+        was_open: connection.open,
+    }
+}
+
+/// Route a subscription confirmation or notification
+async fn handle_message(
+    driver: &mut Driver,
+    connection: &mut Connection,
+    text: &str,
+) -> Result<(), RpcError> {
+    let message: Value = match serde_json::from_str(text) {
+        Ok(message) => message,
+        Err(e) => {
+            log::warn!("pubsub: invalid message: {}", e);
+            return Ok(());
+        }
+    };
+
+    // Reply to a subscribe request
+    if let Some(request_id) = message.get("id").and_then(Value::as_u64) {
+        let Some((id, unsubscribe_method)) = connection.pending.remove(&request_id) else {
+            return Ok(());
+        };
+        let Some(active) = driver.subscriptions.get(&id) else {
+            // Unsubscribed while the request was in flight
+            if let Some(server_id) = message.get("result").and_then(Value::as_u64) {
+                connection
+                    .send(unsubscribe_method, vec![json!(server_id)])
+                    .await?;
+            }
+            return Ok(());
+        };
+
+        match message.get("result").and_then(Value::as_u64) {
+            Some(server_id) => {
+                connection.server_ids.insert(server_id, id);
+            }
+            None => {
+                log::warn!(
+                    "pubsub: {} rejected: {}",
+                    active.subscription.method(),
+                    message.get("error").unwrap_or(&Value::Null)
+                );
+                // Dropping the sender ends the subscriber's stream
+                driver.subscriptions.remove(&id);
+            }
+        }
+        return Ok(());
+    }
+
+    // Notification for an active subscription
+    let Some(params) = message.get("params") else {
+        return Ok(());
+    };
+    let Some(server_id) = params.get("subscription").and_then(Value::as_u64) else {
+        return Ok(());
+    };
+    let Some(&id) = connection.server_ids.get(&server_id) else {
+        return Ok(());
+    };
+    let Some(active) = driver.subscriptions.get(&id) else {
+        return Ok(());
+    };
+
+    let result = params.get("result").cloned().unwrap_or(Value::Null);
+    if active.sender.unbounded_send(result).is_err() {
+        // The subscriber went away without unsubscribing
+        if let Some(active) = driver.subscriptions.remove(&id) {
+            connection.unsubscribe(id, &active.subscription).await?;
+        }
+    } else if message.get("method").and_then(Value::as_str) == Some("signatureNotification") {
+        // Signature subscriptions are closed by the server after one notification
+        connection.server_ids.remove(&server_id);
+        driver.subscriptions.remove(&id);
+    }
+
+    Ok(())
+}
+
+/// Start the pubsub driver and keep it pointed at the selected cluster
+///
+/// Call once from the root component, after the cluster store provider.
+pub fn use_pubsub_provider(cx: &ScopeState) {
+    let rpc_url = use_cluster_store(cx).read().cluster.rpc_url();
+    let pubsub = use_coroutine(cx, run);
+
+    use_effect(cx, (&rpc_url,), |(rpc_url,)| {
+        let pubsub = pubsub.to_owned();
+        async move {
+            match socket::websocket_url(&rpc_url) {
+                Ok(url) => pubsub.send(PubsubCommand::SetEndpoint(url)),
+                Err(e) => log::warn!("pubsub: {}", e),
+            }
+        }
+    });
+}
+
+/// Subscribe while the component is mounted and return the latest notification
+///
+/// Passing `None` unsubscribes. The value resets to `None` whenever the
+/// subscription changes.
+pub fn use_subscription(
+    cx: &ScopeState,
+    subscription: Option<Subscription>,
+) -> &UseState<Option<Value>> {
+    let latest = use_state(cx, || Option::<Value>::None);
+    let current = use_ref(cx, || Option::<u64>::None);
+    let pubsub = use_coroutine_handle::<PubsubCommand>(cx)
+        .expect("use_pubsub_provider must be called in the root component");
+
+    use_effect(cx, (&subscription,), |(subscription,)| {
+        let latest = latest.to_owned();
+        let current = current.to_owned();
+        let pubsub = pubsub.to_owned();
+
+        async move {
+            if let Some(id) = current.write_silent().take() {
+                pubsub.send(PubsubCommand::Unsubscribe { id });
+            }
+            latest.set(None);
+
+            let Some(subscription) = subscription else {
+                return;
+            };
+
+            let id = next_subscription_id();
+            let (sender, mut notifications) = futures_channel::mpsc::unbounded();
+            *current.write_silent() = Some(id);
+            pubsub.send(PubsubCommand::Subscribe {
+                id,
+                subscription,
+                sender,
+            });
+
+            while let Some(value) = notifications.next().await {
+                // A newer subscription replaced this one
+                if *current.read() != Some(id) {
+                    break;
+                }
+                latest.set(Some(value));
+            }
+        }
+    });
+
+    {
+        let current = current.to_owned();
+        let pubsub = pubsub.to_owned();
+        use_on_destroy(cx, move || {
+            if let Some(id) = current.write_silent().take() {
+                pubsub.send(PubsubCommand::Unsubscribe { id });
+            }
+        });
+    }
+
+    latest
+}
@@ -0,0 +1,257 @@
+//! Platform WebSocket backends used by the pubsub client
+//!
+//! Each backend turns a WebSocket into a [`SocketSink`] for outgoing text
+//! frames and a stream of [`SocketEvent`]s:
+//! - Web: `web_sys::WebSocket`
+//! - Desktop / Android: `tokio-tungstenite`
+
+use futures_util::stream::LocalBoxStream;
+
+use crate::utils::api::RpcError;
+
+/// Something that happened on the socket
+#[derive(Debug, Clone, PartialEq)]
+pub enum SocketEvent {
+    /// The connection is ready to send frames
+    Open,
+    /// A text frame arrived
+    Message(String),
+    /// The connection is gone; the stream ends after this event
+    Closed,
+}
+
+/// Open socket plus the stream of its events
+pub type Connection = (SocketSink, LocalBoxStream<'static, SocketEvent>);
+
+#[cfg(feature = "web")]
+pub use web_socket::{connect, SocketSink};
+
+#[cfg(all(not(feature = "web"), any(feature = "desktop", feature = "android")))]
+pub use native_socket::{connect, SocketSink};
+
+#[cfg(not(any(feature = "web", feature = "desktop", feature = "android")))]
+pub use unavailable_socket::{connect, SocketSink};
+
+#[cfg(feature = "web")]
+mod web_socket {
+    use futures_util::StreamExt;
+    use wasm_bindgen::closure::Closure;
+    use wasm_bindgen::JsCast;
+    use web_sys::{Event, MessageEvent, WebSocket};
+
+    use super::{Connection, SocketEvent};
+    use crate::utils::api::RpcError;
+
+    /// Outgoing half of a browser WebSocket
+    pub struct SocketSink {
+        socket: WebSocket,
+        // Keep the JS callbacks alive for as long as the socket is open
+        _on_open: Closure<dyn FnMut(Event)>,
+        _on_message: Closure<dyn FnMut(MessageEvent)>,
+        _on_close: Closure<dyn FnMut(Event)>,
+    }
+
+    impl SocketSink {
+        pub async fn send(&mut self, text: String) -> Result<(), RpcError> {
+            self.socket
+                .send_with_str(&text)
+                .map_err(|e| RpcError::Transport(format!("{:?}", e)))
+        }
+    }
+
+    impl Drop for SocketSink {
+        fn drop(&mut self) {
+            self.socket.set_onopen(None);
+            self.socket.set_onmessage(None);
+            self.socket.set_onclose(None);
+            self.socket.set_onerror(None);
+            let _ = self.socket.close();
+        }
+    }
+
+    pub async fn connect(url: &str) -> Result<Connection, RpcError> {
+        let socket = WebSocket::new(url).map_err(|e| RpcError::Transport(format!("{:?}", e)))?;
+        let (tx, rx) = futures_channel::mpsc::unbounded();
+
+        let open_tx = tx.clone();
+        let on_open = Closure::<dyn FnMut(Event)>::new(move |_| {
+            let _ = open_tx.unbounded_send(SocketEvent::Open);
+        });
+
+        let message_tx = tx.clone();
+        let on_message = Closure::<dyn FnMut(MessageEvent)>::new(move |event: MessageEvent| {
+            if let Some(text) = event.data().as_string() {
+                let _ = message_tx.unbounded_send(SocketEvent::Message(text));
+            }
+        });
+
+        // `error` is always followed by `close`, so both end the connection
+        let on_close = Closure::<dyn FnMut(Event)>::new(move |_| {
+            let _ = tx.unbounded_send(SocketEvent::Closed);
+            tx.close_channel();
+        });
+
+        socket.set_onopen(Some(on_open.as_ref().unchecked_ref()));
+        socket.set_onmessage(Some(on_message.as_ref().unchecked_ref()));
+        socket.set_onclose(Some(on_close.as_ref().unchecked_ref()));
+        socket.set_onerror(Some(on_close.as_ref().unchecked_ref()));
+
+        let sink = SocketSink {
+            socket,
+            _on_open: on_open,
+            _on_message: on_message,
+            _on_close: on_close,
+        };
+
+        Ok((sink, rx.boxed_local()))
+    }
+}
+
+#[cfg(any(feature = "desktop", feature = "android"))]
+mod native_socket {
+    use futures_util::stream::{self, SplitSink};
+    use futures_util::{SinkExt, StreamExt};
+    use tokio::net::TcpStream;
+    use tokio_tungstenite::tungstenite::Message;
+    use tokio_tungstenite::{MaybeTlsStream, WebSocketStream};
+
+    use super::{Connection, SocketEvent};
+    use crate::utils::api::RpcError;
+
+    type Socket = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+    /// Outgoing half of a tungstenite WebSocket
+    pub struct SocketSink {
+        sink: SplitSink<Socket, Message>,
+    }
+
+    impl SocketSink {
+        pub async fn send(&mut self, text: String) -> Result<(), RpcError> {
+            self.sink
+                .send(Message::Text(text))
+                .await
+                .map_err(|e| RpcError::Transport(e.to_string()))
+        }
+    }
+
+    pub async fn connect(url: &str) -> Result<Connection, RpcError> {
+        let (socket, _) = tokio_tungstenite::connect_async(url)
+            .await
+            .map_err(|e| RpcError::Transport(e.to_string()))?;
+        let (sink, source) = socket.split();
+
+        // The handshake has completed, so the socket is open right away
+        let messages = source
+            .take_while(|message| std::future::ready(message.is_ok()))
+            .filter_map(|message| {
+                std::future::ready(match message {
+                    Ok(Message::Text(text)) => Some(SocketEvent::Message(text)),
+                    _ => None,
+                })
+            });
+        let events = stream::once(std::future::ready(SocketEvent::Open))
+            .chain(messages)
+            .chain(stream::once(std::future::ready(SocketEvent::Closed)));
+
+        Ok((SocketSink { sink }, events.boxed_local()))
+    }
+}
+
+#[cfg(not(any(feature = "web", feature = "desktop", feature = "android")))]
+mod unavailable_socket {
+    use super::Connection;
+    use crate::utils::api::RpcError;
+
+    /// Placeholder sink for builds without a platform feature
+    pub struct SocketSink;
+
+    impl SocketSink {
+        pub async fn send(&mut self, _text: String) -> Result<(), RpcError> {
+            Err(RpcError::Transport(
+                "No WebSocket available for this platform".to_string(),
+            ))
+        }
+    }
+
+    pub async fn connect(_url: &str) -> Result<Connection, RpcError> {
+        Err(RpcError::Transport(
+            "No WebSocket available for this platform".to_string(),
+        ))
+    }
+}
+
+/// Convert an HTTP(S) RPC URL into the matching pubsub WebSocket URL
+///
+/// Follows the Solana convention: `http` becomes `ws`, `https` becomes `wss`
+/// and an explicit port is incremented by one (8899 -> 8900).
+pub fn websocket_url(rpc_url: &str) -> Result<String, RpcError> {
+    let (scheme, rest) = if let Some(rest) = rpc_url.strip_prefix("https://") {
+        ("wss", rest)
+    } else if let Some(rest) = rpc_url.strip_prefix("http://") {
+        ("ws", rest)
+    } else {
+        return Err(RpcError::Transport(format!("Unsupported RPC URL: {}", rpc_url)));
+    };
+
+    // The authority ends at the path, query or fragment, whichever comes first
+    let (authority, path) = match rest.find(['/', '?', '#']) {
+        Some(index) => rest.split_at(index),
+        None => (rest, ""),
+    };
+
+    // A bare IPv6 host like `[::1]` fails to parse as a port and is kept as is
+    let authority = match authority.rsplit_once(':') {
+        Some((host, port)) => match port.parse::<u16>() {
+            Ok(port) => format!("{}:{}", host, port.saturating_add(1)),
+            Err(_) => authority.to_string(),
+        },
+        None => authority.to_string(),
+    };
+
+    Ok(format!("{}://{}{}", scheme, authority, path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn schemes_map_to_websocket_schemes() {
+        assert_eq!(
+            websocket_url("https://api.mainnet-beta.solana.com").unwrap(),
+            "wss://api.mainnet-beta.solana.com"
+        );
+        assert_eq!(websocket_url("http://localhost").unwrap(), "ws://localhost");
+        assert!(websocket_url("wss://api.devnet.solana.com").is_err());
+        assert!(websocket_url("api.devnet.solana.com").is_err());
+    }
+
+    #[test]
+    fn only_explicit_ports_are_incremented() {
+        assert_eq!(websocket_url("http://127.0.0.1:8899").unwrap(), "ws://127.0.0.1:8900");
+        assert_eq!(websocket_url("https://rpc.example.com:443").unwrap(), "wss://rpc.example.com:444");
+        assert_eq!(websocket_url("https://rpc.example.com").unwrap(), "wss://rpc.example.com");
+        assert_eq!(websocket_url("http://[::1]:8899").unwrap(), "ws://[::1]:8900");
+        assert_eq!(websocket_url("http://[::1]").unwrap(), "ws://[::1]");
+    }
+
+    #[test]
+    fn paths_and_queries_are_kept() {
+        assert_eq!(
+            websocket_url("https://rpc.example.com/v1/abc:123").unwrap(),
+            "wss://rpc.example.com/v1/abc:123"
+        );
+        assert_eq!(
+            websocket_url("https://rpc.example.com:8899/?api-key=a:b").unwrap(),
+            "wss://rpc.example.com:8900/?api-key=a:b"
+        );
+        assert_eq!(
+            websocket_url("http://localhost:8899?api-key=secret").unwrap(),
+            "ws://localhost:8900?api-key=secret"
+        );
+        assert_eq!(
+            websocket_url("https://rpc.example.com?api-key=secret").unwrap(),
+            "wss://rpc.example.com?api-key=secret"
+        );
+    }
+}
@@ -0,0 +1,190 @@
+//! Stake decentralization metrics computed from vote accounts
+//!
+//! All metrics use the active stake of every staked vote account, including
+//! delinquent ones, since delinquent stake still counts towards consensus
+//! thresholds for the epoch.
+
+use crate::utils::api::{VoteAccountInfo, VoteAccounts};
+
+/// Points kept in [`DecentralizationMetrics::cumulative_curve`]
+const CURVE_POINTS: usize = 200;
+
+/// Summary of how stake is spread across validators
+#[derive(Debug, Clone, PartialEq)]
+pub struct DecentralizationMetrics {
+    /// Fewest validators that together hold more than 1/3 of the stake
+    pub nakamoto_coefficient: usize,
+    /// The validators counted by the Nakamoto coefficient, largest first
+    pub superminority: Vec<VoteAccountInfo>,
+    /// Share of stake held by the superminority (0.0 - 1.0)
+    pub superminority_stake: f64,
+    /// Gini coefficient of stake (0.0 = perfectly even, 1.0 = one validator)
+    pub gini: f64,
+    /// Cumulative share of stake (0.0 - 1.0) held by the top validators,
+    /// sampled at evenly spaced validator ranks from largest to smallest
+    pub cumulative_curve: Vec<f64>,
+    /// Number of validators with stake
+    pub staked_validators: usize,
+}
+
+impl DecentralizationMetrics {
+    pub fn from_vote_accounts(vote_accounts: &VoteAccounts) -> Self {
+        let mut accounts: Vec<&VoteAccountInfo> = vote_accounts
+            .current
+            .iter()
+            .chain(&vote_accounts.delinquent)
+            .filter(|account| account.activated_stake > 0)
+            .collect();
+        accounts.sort_by_key(|account| std::cmp::Reverse(account.activated_stake));
+
+        let stakes: Vec<u64> = accounts.iter().map(|account| account.activated_stake).collect();
+        let total: u128 = stakes.iter().map(|&stake| stake as u128).sum();
+
+        let nakamoto_coefficient = nakamoto_coefficient(&stakes);
+        let superminority_total: u128 = stakes[..nakamoto_coefficient]
+            .iter()
+            .map(|&stake| stake as u128)
+            .sum();
+
+        Self {
+            nakamoto_coefficient,
+            superminority: accounts[..nakamoto_coefficient]
+                .iter()
+                .map(|&account| account.clone())
+                .collect(),
+            superminority_stake: ratio(superminority_total, total),
+            gini: gini_coefficient(&stakes),
+            cumulative_curve: cumulative_curve(&stakes, CURVE_POINTS),
+            staked_validators: stakes.len(),
+        }
+    }
+}
+
+fn ratio(part: u128, total: u128) -> f64 {
+    if total == 0 {
+        0.0
+    } else {
+        part as f64 / total as f64
+    }
+}
+
+/// Fewest validators whose combined stake exceeds 1/3 of `stakes`
+///
+/// `stakes` must be sorted from largest to smallest.
+pub fn nakamoto_coefficient(stakes: &[u64]) -> usize {
+    let total: u128 = stakes.iter().map(|&stake| stake as u128).sum();
+    if total == 0 {
+        return 0;
+    }
+
+    let mut cumulative: u128 = 0;
+    for (index, &stake) in stakes.iter().enumerate() {
+        cumulative += stake as u128;
+        if cumulative * 3 > total {
+            return index + 1;
+        }
+    }
+    stakes.len()
+}
+
+/// Gini coefficient of `stakes` (any order)
+pub fn gini_coefficient(stakes: &[u64]) -> f64 {
+    let n = stakes.len();
+    let total: u128 = stakes.iter().map(|&stake| stake as u128).sum();
+    if n == 0 || total == 0 {
+        return 0.0;
+    }
+
+    let mut sorted = stakes.to_vec();
+    sorted.sort_unstable();
+
+    // G = sum((2i - n - 1) * x_i) / (n * sum(x)) with 1-based ranks, ascending
+    let weighted: f64 = sorted
+        .iter()
+        .enumerate()
+        .map(|(i, &stake)| (2.0 * (i + 1) as f64 - n as f64 - 1.0) * stake as f64)
+        .sum();
+
+    weighted / (n as f64 * total as f64)
+}
+
+/// Cumulative stake share of the top validators, downsampled to `points`
+///
+/// `stakes` must be sorted from largest to smallest. The last point is
+/// always 1.0 (all validators).
+pub fn cumulative_curve(stakes: &[u64], points: usize) -> Vec<f64> {
+    let total: u128 = stakes.iter().map(|&stake| stake as u128).sum();
+    if stakes.is_empty() || total == 0 || points == 0 {
+        return Vec::new();
+    }
+
+    let mut cumulative: u128 = 0;
+    let full: Vec<f64> = stakes
+        .iter()
+        .map(|&stake| {
+            cumulative += stake as u128;
+            ratio(cumulative, total)
+        })
+        .collect();
+
+    if full.len() <= points {
+        return full;
+    }
+
+    // Pick evenly spaced ranks, always including the last one
+    (1..=points)
+        .map(|point| full[point * full.len() / points - 1])
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::api::test_vote_account;
+
+    #[test]
+    fn nakamoto_coefficient_needs_strictly_more_than_a_third() {
+        assert_eq!(nakamoto_coefficient(&[40, 30, 20, 10]), 1);
+        assert_eq!(nakamoto_coefficient(&[30, 30, 30, 10]), 2);
+        // Exactly a third does not halt the cluster
+        assert_eq!(nakamoto_coefficient(&[1, 1, 1]), 2);
+        assert_eq!(nakamoto_coefficient(&[]), 0);
+        assert_eq!(nakamoto_coefficient(&[0, 0]), 0);
+    }
+
+    #[test]
+    fn gini_coefficient_ranges_from_even_to_concentrated() {
+        assert_eq!(gini_coefficient(&[5, 5, 5, 5]), 0.0);
+        // One holder among n gives (n - 1) / n
+        assert_eq!(gini_coefficient(&[0, 100, 0, 0]), 0.75);
+        assert!((gini_coefficient(&[1, 2, 3, 4]) - 0.25).abs() < 1e-12);
+        assert_eq!(gini_coefficient(&[]), 0.0);
+    }
+
+    #[test]
+    fn cumulative_curve_keeps_the_last_rank() {
+        assert_eq!(cumulative_curve(&[3, 1], 10), [0.75, 1.0]);
+        assert_eq!(cumulative_curve(&[1; 10], 5), [0.2, 0.4, 0.6, 0.8, 1.0]);
+        assert!(cumulative_curve(&[], 5).is_empty());
+    }
+
+    #[test]
+    fn superminority_includes_delinquent_stake() {
+        let vote_accounts = VoteAccounts {
+            current: vec![test_vote_account("a", 30), test_vote_account("unstaked", 0)],
+            delinquent: vec![test_vote_account("b", 30), test_vote_account("c", 30), test_vote_account("d", 10)],
+        };
+        let metrics = DecentralizationMetrics::from_vote_accounts(&vote_accounts);
+
+        assert_eq!(metrics.staked_validators, 4);
+        assert_eq!(metrics.nakamoto_coefficient, 2);
+        let superminority: Vec<&str> = metrics
+            .superminority
+            .iter()
+            .map(|account| account.node_pubkey.as_str())
+            .collect();
+        assert_eq!(superminority, ["a", "b"]);
+        assert!((metrics.superminority_stake - 0.6).abs() < 1e-12);
+        assert_eq!(metrics.cumulative_curve.len(), 4);
+    }
+}
@@ -0,0 +1,316 @@
+//! Staking APY estimates
+//!
+//! Validator inflation is split across vote accounts in proportion to their
+//! points (active stake x vote credits earned in the epoch), and each vote
+//! account keeps its commission. A delegator's yield therefore depends on the
+//! network's base rate, how well the validator voted relative to the cluster,
+//! and the commission it charges. Realized rewards from `getInflationReward`
+//! can be fed through [`realized_yield`] to check an estimate.
+
+use crate::utils::api::{InflationReward, InflationSnapshot, VoteAccountInfo, VoteAccounts};
+use crate::utils::emission::{NOMINAL_SLOT_SECONDS, SECONDS_PER_YEAR};
+
+/// Network-wide inputs for estimating a vote account's APY
+#[derive(Debug, Clone, PartialEq)]
+pub struct YieldModel {
+    /// Epoch whose vote credits are used, the last completed one
+    pub credits_epoch: u64,
+    /// Share of the stake's value paid out per epoch at average performance
+    /// and zero commission
+    pub base_epoch_rate: f64,
+    /// Epochs per year at the currently observed slot time
+    pub epochs_per_year: f64,
+    /// Stake-weighted mean of vote credits earned in `credits_epoch`
+    pub average_credits: f64,
+}
+
+impl YieldModel {
+    pub fn new(snapshot: &InflationSnapshot, vote_accounts: &VoteAccounts) -> Self {
+        let credits_epoch = snapshot.epoch_info.epoch.saturating_sub(1);
+        let slots_in_epoch = snapshot.epoch_info.slots_in_epoch as f64;
+
+        // Payouts use the nominal slot time, compounding uses the real one
+        let epoch_share_of_year = slots_in_epoch * NOMINAL_SLOT_SECONDS / SECONDS_PER_YEAR;
+        let slot_time = if snapshot.performance.avg_slot_time > 0.0 {
+            snapshot.performance.avg_slot_time
+        } else {
+            NOMINAL_SLOT_SECONDS
+        };
+        let epochs_per_year = SECONDS_PER_YEAR / (slots_in_epoch * slot_time).max(1.0);
+
+        let mut total_stake = 0f64;
+        let mut weighted_credits = 0f64;
+        for account in vote_accounts.current.iter().chain(&vote_accounts.delinquent) {
+            total_stake += account.activated_stake as f64;
+            let credits = earned_credits(account, credits_epoch).unwrap_or(0);
+            weighted_credits += account.activated_stake as f64 * credits as f64;
+        }
+
+        let base_epoch_rate = if total_stake == 0.0 {
+            0.0
+        } else {
+            let epoch_payout =
+                snapshot.inflation.validator * snapshot.total_supply as f64 * epoch_share_of_year;
+            epoch_payout / total_stake
+        };
+
+        Self {
+            credits_epoch,
+            base_epoch_rate,
+            epochs_per_year,
+            average_credits: if total_stake == 0.0 {
+                0.0
+            } else {
+                weighted_credits / total_stake
+            },
+        }
+    }
+
+    /// Estimated delegator APY (0.0 - 1.0) for stake on `account`, or `None`
+    /// if it has no vote credits for the reference epoch
+    pub fn estimate(&self, account: &VoteAccountInfo) -> Option<f64> {
+        if self.average_credits == 0.0 {
+            return None;
+        }
+
+        let credits = earned_credits(account, self.credits_epoch)? as f64;
+        let performance = credits / self.average_credits;
+        let delegator_share = 1.0 - account.commission.min(100) as f64 / 100.0;
+        Some(annualize(
+            self.base_epoch_rate * performance * delegator_share,
+            self.epochs_per_year,
+        ))
+    }
+
+    /// Stake-weighted APY across the current vote accounts
+    pub fn network_average(&self, vote_accounts: &VoteAccounts) -> Option<f64> {
+        let (weighted, stake) = vote_accounts
+            .current
+            .iter()
+            .filter_map(|account| {
+                let apy = self.estimate(account)?;
+                Some((apy * account.activated_stake as f64, account.activated_stake as f64))
+            })
+            .fold((0.0, 0.0), |(weighted, stake), (account_weighted, account_stake)| {
+                (weighted + account_weighted, stake + account_stake)
+            });
+
+        (stake > 0.0).then(|| weighted / stake)
+    }
+}
+
+/// APY realized by a sample of stake accounts in one epoch
+#[derive(Debug, Clone, PartialEq)]
+pub struct RealizedYield {
+    pub epoch: u64,
+    /// Rewards that could be converted to a rate
+    pub samples: usize,
+    /// Median APY (0.0 - 1.0) across the samples
+    pub median_apy: f64,
+}
+
+/// Annualize the rewards paid to stake accounts in their most common epoch.
+/// Rewards on accounts that held no stake before the payout are skipped.
+pub fn realized_yield(rewards: &[InflationReward], epochs_per_year: f64) -> Option<RealizedYield> {
+    let epoch = most_common_epoch(rewards)?;
+    let mut rates: Vec<f64> = rewards
+        .iter()
+        .filter(|reward| reward.epoch == epoch && reward.post_balance > reward.amount)
+        .map(|reward| {
+            let rate = reward.amount as f64 / (reward.post_balance - reward.amount) as f64;
+            annualize(rate, epochs_per_year)
+        })
+        .collect();
+    if rates.is_empty() {
+        return None;
+    }
+
+    rates.sort_by(f64::total_cmp);
+    let middle = rates.len() / 2;
+    let median_apy = if rates.len().is_multiple_of(2) {
+        (rates[middle - 1] + rates[middle]) / 2.0
+    } else {
+        rates[middle]
+    };
+
+    Some(RealizedYield {
+        epoch,
+        samples: rates.len(),
+        median_apy,
+    })
+}
+
+/// Compound a per-epoch rate over a year
+pub fn annualize(epoch_rate: f64, epochs_per_year: f64) -> f64 {
+    (1.0 + epoch_rate).powf(epochs_per_year) - 1.0
+}
+
+/// Vote credits `account` earned in `epoch`, if it voted then
+pub fn earned_credits(account: &VoteAccountInfo, epoch: u64) -> Option<u64> {
+    account
+        .epoch_credits
+        .iter()
+        .find(|(credits_epoch, _, _)| *credits_epoch == epoch)
+        .map(|(_, credits, previous_credits)| credits.saturating_sub(*previous_credits))
+}
+
+fn most_common_epoch(rewards: &[InflationReward]) -> Option<u64> {
+    let mut epochs: Vec<u64> = rewards.iter().map(|reward| reward.epoch).collect();
+    epochs.sort_unstable();
+    epochs
+        .chunk_by(|a, b| a == b)
+        .max_by_key(|run| run.len())
+        .map(|run| run[0])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::api::{test_vote_account, EpochInfo, InflationRate, PerformanceStats};
+
+    const EPOCH: u64 = 10;
+
+    fn snapshot() -> InflationSnapshot {
+        InflationSnapshot {
+            inflation: InflationRate {
+                total: 0.05,
+                validator: 0.0475,
+                foundation: 0.0025,
+                epoch: EPOCH,
+            },
+            total_supply: 600_000_000_000_000_000,
+            epoch_info: EpochInfo {
+                absolute_slot: 4_320_000,
+                block_height: 4_000_000,
+                epoch: EPOCH,
+                slot_index: 0,
+                slots_in_epoch: 432_000,
+                transaction_count: None,
+            },
+            performance: PerformanceStats::default(),
+        }
+    }
+
+    /// Vote account that earned `credits` in the last completed epoch
+    fn voter(identity: &str, stake: u64, credits: Option<u64>, commission: u8) -> VoteAccountInfo {
+        VoteAccountInfo {
+            commission,
+            epoch_credits: credits
+                .map(|credits| vec![(EPOCH - 1, 50_000 + credits, 50_000)])
+                .unwrap_or_default(),
+            ..test_vote_account(identity, stake)
+        }
+    }
+
+    fn vote_accounts() -> VoteAccounts {
+        VoteAccounts {
+            current: vec![
+                voter("average", 300_000_000_000_000_000, Some(6_000), 0),
+                voter("greedy", 100_000_000_000_000_000, Some(6_000), 100),
+                voter("unstaked", 0, Some(3_000), 0),
+                voter("new", 0, None, 5),
+            ],
+            delinquent: vec![voter("offline", 200_000_000_000_000_000, Some(0), 10)],
+        }
+    }
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!((actual - expected).abs() < 1e-9, "{} != {}", actual, expected);
+    }
+
+    #[test]
+    fn performance_is_relative_to_stake_weighted_credits() {
+        let model = YieldModel::new(&snapshot(), &vote_accounts());
+        assert_eq!(model.credits_epoch, EPOCH - 1);
+        // (300 * 6000 + 100 * 6000 + 200 * 0) / 600
+        assert_close(model.average_credits, 4_000.0);
+        // Payouts over the whole active stake, one nominal epoch of 5% x 95%
+        let epoch_share_of_year = 432_000.0 * NOMINAL_SLOT_SECONDS / SECONDS_PER_YEAR;
+        assert_close(model.base_epoch_rate, 0.0475 * epoch_share_of_year);
+
+        let accounts = vote_accounts();
+        let average = model.estimate(&accounts.current[0]).unwrap();
+        assert_close(average, annualize(model.base_epoch_rate * 1.5, model.epochs_per_year));
+    }
+
+    #[test]
+    fn full_commission_leaves_delegators_nothing() {
+        let model = YieldModel::new(&snapshot(), &vote_accounts());
+        assert_eq!(model.estimate(&vote_accounts().current[1]), Some(0.0));
+    }
+
+    #[test]
+    fn accounts_without_credits_have_no_estimate() {
+        let model = YieldModel::new(&snapshot(), &vote_accounts());
+        assert_eq!(model.estimate(&vote_accounts().current[3]), None);
+        // Voted in the epoch but earned nothing
+        assert_eq!(model.estimate(&vote_accounts().delinquent[0]), Some(0.0));
+    }
+
+    #[test]
+    fn zero_stake_validators_are_estimated_but_carry_no_weight() {
+        let model = YieldModel::new(&snapshot(), &vote_accounts());
+        let accounts = vote_accounts();
+        let unstaked = model.estimate(&accounts.current[2]).unwrap();
+        assert_close(unstaked, annualize(model.base_epoch_rate * 0.75, model.epochs_per_year));
+
+        // Stake-weighted over current accounts: 300 at the full rate, 100 at zero
+        let average = model.estimate(&accounts.current[0]).unwrap();
+        assert_close(model.network_average(&accounts).unwrap(), average * 0.75);
+    }
+
+    #[test]
+    fn clusters_without_stake_have_no_estimates() {
+        let vote_accounts = VoteAccounts {
+            current: vec![voter("unstaked", 0, Some(6_000), 0)],
+            delinquent: Vec::new(),
+        };
+        let model = YieldModel::new(&snapshot(), &vote_accounts);
+        assert_eq!(model.base_epoch_rate, 0.0);
+        assert_eq!(model.estimate(&vote_accounts.current[0]), None);
+        assert_eq!(model.network_average(&vote_accounts), None);
+    }
+
+    #[test]
+    fn realized_rewards_match_the_estimate() {
+        let model = YieldModel::new(&snapshot(), &vote_accounts());
+        let account = voter("taxed", 1, Some(4_000), 10);
+        let estimate = model.estimate(&account).unwrap();
+
+        // What a delegator of 1,000 SOL on that validator is paid for an epoch
+        let stake = 1_000_000_000_000u64;
+        let amount = (stake as f64 * model.base_epoch_rate * 0.9).round() as u64;
+        let reward = |epoch: u64, amount: u64, post_balance: u64| InflationReward {
+            epoch,
+            effective_slot: 0,
+            amount,
+            post_balance,
+            commission: Some(10),
+        };
+        let rewards = [
+            reward(EPOCH - 1, amount, stake + amount),
+            reward(EPOCH - 1, amount * 2, stake * 2 + amount * 2),
+            // Another epoch and an account first funded by the reward are skipped
+            reward(EPOCH - 2, amount * 5, stake + amount * 5),
+            reward(EPOCH - 1, amount, amount),
+        ];
+
+        let realized = realized_yield(&rewards, model.epochs_per_year).unwrap();
+        assert_eq!(realized.epoch, EPOCH - 1);
+        assert_eq!(realized.samples, 2);
+        assert!((realized.median_apy - estimate).abs() < estimate * 1e-6);
+    }
+
+    #[test]
+    fn realized_yield_needs_funded_rewards() {
+        assert_eq!(realized_yield(&[], 180.0), None);
+        let unfunded = InflationReward {
+            epoch: 1,
+            effective_slot: 0,
+            amount: 10,
+            post_balance: 10,
+            commission: None,
+        };
+        assert_eq!(realized_yield(&[unfunded], 180.0), None);
+    }
+}